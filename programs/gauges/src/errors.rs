@@ -2,16 +2,24 @@ use anchor_lang::error;
 
 #[error]
 pub enum ErrorCode {
-    #[msg("Insufficient amount")]
-    InsufficientAmount,
-    #[msg("Insufficient liquidity")]
-    InsufficientLiquidity,
-    #[msg("Insufficient liquidity minted")]
-    InsufficientLiquidityMinted,
-    #[msg("Insufficient input")]
-    InsufficientInput,
-    #[msg("Insufficient output")]
-    InsufficientOutput,
-    #[msg("Violated invariant K")]
-    InvariantK,
+    #[msg("Amount must be positive")]
+    ZeroAmount,
+    #[msg("Reward duration must be positive")]
+    InvalidDuration,
+    #[msg("Rewards account does not hold the notified rewards")]
+    RewardTooHigh,
+    #[msg("Given mint is not the liquidity mint of the gauge pair")]
+    GaugeMismatch,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("Feature is paused")]
+    Paused,
+    #[msg("Unknown or empty pause features")]
+    InvalidFeatures,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Amount exceeds the liquidity staked")]
+    InsufficientStake,
 }
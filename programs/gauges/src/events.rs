@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a gauge is created
+#[event]
+pub struct GaugeCreated {
+    /// The gauge
+    #[index]
+    pub gauge: Pubkey,
+
+    /// The pair associated with the gauge
+    pub pair: Pubkey,
+
+    /// The reward token for the gauge
+    pub mint_rewards: Pubkey,
+
+    /// The time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when liquidity tokens are deposited in a gauge
+#[event]
+pub struct LiquidityDeposited {
+    /// The gauge
+    #[index]
+    pub gauge: Pubkey,
+
+    /// The wallet depositing
+    pub liquidity_provider: Pubkey,
+
+    /// The amount of liquidity tokens deposited
+    pub amount: u64,
+
+    /// The time of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted when liquidity tokens are withdrawn from a gauge
+#[event]
+pub struct LiquidityWithdrawn {
+    /// The gauge
+    #[index]
+    pub gauge: Pubkey,
+
+    /// The wallet withdrawing
+    pub liquidity_provider: Pubkey,
+
+    /// The amount of liquidity tokens withdrawn
+    pub amount: u64,
+
+    /// The time of the withdrawal
+    pub timestamp: i64,
+}
+
+/// Emitted when a staker collects the rewards of a gauge
+#[event]
+pub struct RewardsCollected {
+    /// The gauge
+    #[index]
+    pub gauge: Pubkey,
+
+    /// The staker collecting
+    pub staker: Pubkey,
+
+    /// The mint of the rewards
+    pub mint: Pubkey,
+
+    /// The amount collected
+    pub amount: u64,
+
+    /// The time of the collect
+    pub timestamp: i64,
+}
+
+/// Emitted when rewards are added to the stream of a gauge
+#[event]
+pub struct RewardNotified {
+    /// The gauge
+    #[index]
+    pub gauge: Pubkey,
+
+    /// The amount of rewards added
+    pub amount: u64,
+
+    /// The new amount of rewards streamed per second
+    pub reward_rate: u64,
+
+    /// The end of the stream
+    pub period_finish: i64,
+}
+
+/// Emitted when the admin or guardian of the gauges changes
+#[event]
+pub struct ConfigUpdated {
+    /// The configuration
+    pub config: Pubkey,
+
+    /// The wallet allowed to unpause features
+    pub admin: Pubkey,
+
+    /// The wallet allowed to pause features
+    pub guardian: Pubkey,
+}
+
+/// Emitted when features of the gauges are paused or unpaused
+#[event]
+pub struct PauseUpdated {
+    /// The configuration
+    pub config: Pubkey,
+
+    /// The paused features after the update
+    pub paused: u64,
+
+    /// The wallet that updated the paused features
+    pub authority: Pubkey,
+
+    /// The time of the update
+    pub timestamp: i64,
+}
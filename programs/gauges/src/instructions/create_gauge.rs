@@ -2,10 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 use pools::state::Pair;
-use crate::state::{CreateGaugeBumps, Gauge};
+use crate::events::GaugeCreated;
+use crate::state::{CreateGaugeBumps, Gauge, Versioned};
 
 #[derive(Accounts)]
-#[instruction(bumps: CreateGaugeBumps)]
 pub struct CreateGauge<'info> {
     /// The pair associated with the gauge
     #[account(
@@ -30,7 +30,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.gauge
+        bump
     )]
     pub gauge: Box<Account<'info, Gauge>>,
 
@@ -43,7 +43,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.authority
+        bump
     )]
     pub authority: AccountInfo<'info>,
 
@@ -61,7 +61,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.mint,
+        bump,
         mint::decimals = 9,
         mint::authority = authority
     )]
@@ -86,7 +86,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.account_liquidity,
+        bump,
         token::mint = mint_liquidity,
         token::authority = authority
     )]
@@ -102,7 +102,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.account_rewards,
+        bump,
         token::mint = mint_rewards,
         token::authority = authority
     )]
@@ -118,7 +118,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.account_a,
+        bump,
         token::mint = mint_a,
         token::authority = authority
     )]
@@ -134,7 +134,7 @@ pub struct CreateGauge<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bumps.account_b,
+        bump,
         token::mint = mint_b,
         token::authority = authority
     )]
@@ -152,13 +152,26 @@ pub struct CreateGauge<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateGauge>, bumps: CreateGaugeBumps) -> ProgramResult {
+pub fn handler(ctx: Context<CreateGauge>) -> ProgramResult {
     let gauge = &mut ctx.accounts.gauge;
     gauge.pair = ctx.accounts.pair.key();
     gauge.mint_rewards = ctx.accounts.mint_rewards.key();
     gauge.gauge_mint = ctx.accounts.gauge_mint.key();
     gauge.authority = ctx.accounts.authority.key();
-    gauge.bumps = bumps;
+    gauge.bumps = CreateGaugeBumps::find(
+        &gauge.mint_rewards,
+        &ctx.accounts.pair.mint_a,
+        &ctx.accounts.pair.mint_b,
+        ctx.program_id,
+    );
+    gauge.version = Gauge::VERSION;
+
+    emit!(GaugeCreated {
+        gauge: gauge.key(),
+        pair: gauge.pair,
+        mint_rewards: gauge.mint_rewards,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Created gauge for pair {} and rewards={}", gauge.pair, gauge.mint_rewards);
 
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, MintTo};
-use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use pools::state::Pair;
-use crate::state::{Gauge, Staker};
+use crate::errors::ErrorCode;
+use crate::events::LiquidityDeposited;
+use crate::state::{pause, Config, Gauge, Staker, Versioned};
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct DepositLiquidity<'info> {
+    /// The configuration shared by every gauge
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The pair associated with the gauge
     #[account(
         seeds = [
@@ -33,36 +37,6 @@ pub struct DepositLiquidity<'info> {
     )]
     pub gauge: Box<Account<'info, Gauge>>,
 
-    /// The gauge authority
-    #[account(
-        mut,
-        seeds = [
-            b"authority",
-            gauge.mint_rewards.key().as_ref(),
-            pair.mint_a.as_ref(),
-            pair.mint_b.as_ref(),
-        ],
-        bump = gauge.bumps.authority
-    )]
-    pub authority: AccountInfo<'info>,
-
-    /// The mint of the liquidity tokens
-    #[account(constraint = mint_liquidity.key() == pair.pair_mint)]
-    pub mint_liquidity: AccountInfo<'info>,
-
-    /// The mint of the token representing liquidity
-    #[account(
-        mut,
-        seeds = [
-            b"mint",
-            gauge.mint_rewards.key().as_ref(),
-            pair.mint_a.as_ref(),
-            pair.mint_b.as_ref(),
-        ],
-        bump = gauge.bumps.mint,
-    )]
-    pub gauge_mint: Box<Account<'info, Mint>>,
-
     /// The gauge that will receive LP tokens
     #[account(
         mut,
@@ -77,13 +51,17 @@ pub struct DepositLiquidity<'info> {
     pub gauge_liquidity_account: Box<Account<'info, TokenAccount>>,
 
     /// The liquidity provider
-    #[account(mut)]
     pub liquidity_provider: Signer<'info>,
 
+    /// The wallet paying for the staker account.
+    /// Liquidity providers holding data, such as program treasuries, can't pay for it themselves.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// The staker account owner by the liquidity_provider
     #[account(
         init_if_needed,
-        payer = liquidity_provider,
+        payer = payer,
         seeds = [
             b"staker",
             gauge.mint_rewards.as_ref(),
@@ -91,27 +69,22 @@ pub struct DepositLiquidity<'info> {
             pair.mint_b.as_ref(),
             liquidity_provider.key().as_ref()
         ],
-        bump = bump
+        bump
     )]
     pub staker: Box<Account<'info, Staker>>,
 
     /// The account that holds LP tokens
     #[account(
-        init_if_needed,
-        payer = liquidity_provider,
-        associated_token::mint = mint_liquidity,
-        associated_token::authority = liquidity_provider
+        mut,
+        constraint = liquidity_provider_account.mint == pair.pair_mint @ ErrorCode::GaugeMismatch
     )]
     pub liquidity_provider_account: Box<Account<'info, TokenAccount>>,
 
-    /// The program for interacting with associated tokens accoutns.
-    #[account(address = associated_token::ID)]
-    pub associated_token_program: Program<'info, AssociatedToken>,
-
     /// The program for interacting with the token.
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
 
+    pub clock: Sysvar<'info, Clock>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
 }
@@ -127,33 +100,48 @@ impl<'info> DepositLiquidity<'info> {
             },
         )
     }
+}
 
-    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
-        CpiContext::new(
-            self.token_program.to_account_info(),
-            MintTo {
-                mint: self.gauge_mint.to_account_info(),
-                to: self.gauge_liquidity_account.to_account_info(),
-                authority: self.authority.to_account_info(),
-            },
+pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> ProgramResult {
+    ctx.accounts.config.check_active(pause::DEPOSITS)?;
+
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let staker = &mut ctx.accounts.staker;
+    // Uninitialized staker
+    if staker.owner != ctx.accounts.liquidity_provider.key() {
+        let pair = &ctx.accounts.pair;
+        staker.owner = ctx.accounts.liquidity_provider.key();
+        staker.bump = Pubkey::find_program_address(
+            &[
+                b"staker",
+                ctx.accounts.gauge.mint_rewards.as_ref(),
+                pair.mint_a.as_ref(),
+                pair.mint_b.as_ref(),
+                staker.owner.as_ref(),
+            ],
+            ctx.program_id,
         )
+        .1;
+        staker.version = Staker::VERSION;
     }
-}
 
-pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> ProgramResult {
+    let gauge = &mut ctx.accounts.gauge;
+    gauge.update_rewards(Some(staker), now)?;
+    staker.amount = staker.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    gauge.total_staked = gauge.total_staked.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
     token::transfer(ctx.accounts.transfer_context(), amount)?;
 
-    let pair = &ctx.accounts.pair;
-    let gauge = &ctx.accounts.gauge;
-    let seeds = &[
-        b"authority".as_ref(),
-        gauge.mint_rewards.as_ref(),
-        pair.mint_a.as_ref(),
-        pair.mint_b.as_ref(),
-        &[gauge.bumps.authority],
-    ];
-    let signer = &[&seeds[..]];
-    token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), amount)?;
+    emit!(LiquidityDeposited {
+        gauge: ctx.accounts.gauge.key(),
+        liquidity_provider: ctx.accounts.liquidity_provider.key(),
+        amount,
+        timestamp: now,
+    });
 
     msg!("Deposited {} tokens for gauge {}", amount, ctx.accounts.gauge.key());
 
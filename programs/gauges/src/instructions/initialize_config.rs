@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::ErrorCode;
+use crate::events::ConfigUpdated;
+use crate::state::{Config, Versioned};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// The configuration shared by every gauge
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The upgrade authority of the program, deploying the gauges
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The data account of the program, holding its upgrade authority
+    pub program_data: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Checks that `deployer` is the upgrade authority of the program.
+/// Localnet builds load the program without upgrade authority, and accept the localnet deployer instead.
+fn check_deployer(deployer: &Pubkey, program_data: &AccountInfo) -> ProgramResult {
+    #[cfg(feature = "localnet")]
+    if *deployer == crate::localnet_deployer::ID {
+        return Ok(());
+    }
+
+    let address =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID).0;
+    if program_data.key() != address || *program_data.owner != bpf_loader_upgradeable::ID {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+    let data = ProgramData::try_deserialize(&mut &program_data.try_borrow_data()?[..])
+        .map_err(|_| ErrorCode::Unauthorized)?;
+    if data.upgrade_authority_address != Some(*deployer) {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+/// Creates the configuration of the gauges.
+/// Only the upgrade authority can create it, so it cannot be front-run after the deployment.
+pub fn handler(ctx: Context<InitializeConfig>, admin: Pubkey, guardian: Pubkey) -> ProgramResult {
+    check_deployer(&ctx.accounts.payer.key(), &ctx.accounts.program_data)?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.guardian = guardian;
+    config.bump = Pubkey::find_program_address(&[b"config"], ctx.program_id).1;
+    config.version = Config::VERSION;
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        admin,
+        guardian,
+    });
+
+    msg!("Gauges administrated by {} and guarded by {}", admin, guardian);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke, system_instruction};
+
+use crate::{errors::ErrorCode, state::Migratable};
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// The account upgraded to the current layout
+    #[account(
+        mut,
+        constraint = *account.owner == crate::ID
+    )]
+    pub account: AccountInfo<'info>,
+
+    /// The wallet paying for the reserved space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Migrate<'info> {
+    /// Tops up the account so it stays rent exempt with `space` bytes
+    fn fund(&self, space: usize) -> ProgramResult {
+        let rent = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(self.account.lamports());
+        if rent > 0 {
+            invoke(
+                &system_instruction::transfer(&self.payer.key(), &self.account.key(), rent),
+                &[
+                    self.payer.to_account_info(),
+                    self.account.clone(),
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Upgrades an account of type `T` from its legacy layout
+pub fn handler<T: Migratable>(ctx: Context<Migrate>) -> ProgramResult {
+    let account = &ctx.accounts.account;
+    let legacy = {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != T::discriminator() {
+            return Err(anchor_lang::__private::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        if data.len() != T::legacy_space() {
+            return Err(ErrorCode::AlreadyMigrated.into());
+        }
+        T::Legacy::deserialize(&mut &data[8..])?
+    };
+
+    let space = T::space();
+    ctx.accounts.fund(space)?;
+    account.realloc(space, true)?;
+    T::from_legacy(legacy).try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Account {} migrated to version {}",
+        account.key(),
+        T::VERSION
+    );
+
+    Ok(())
+}
@@ -1,7 +1,21 @@
 pub mod create_gauge;
 pub mod deposit_liquidity;
 pub mod collect_rewards;
+pub mod notify_reward;
+pub mod migrate;
+pub mod initialize_config;
+pub mod update_config;
+pub mod pause;
+pub mod unpause;
+pub mod withdraw_liquidity;
 
 pub use create_gauge::*;
 pub use deposit_liquidity::*;
-pub use collect_rewards::*;
\ No newline at end of file
+pub use collect_rewards::*;
+pub use notify_reward::*;
+pub use migrate::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use pause::*;
+pub use unpause::*;
+pub use withdraw_liquidity::*;
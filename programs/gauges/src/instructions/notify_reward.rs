@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use pools::state::Pair;
+use crate::errors::ErrorCode;
+use crate::events::RewardNotified;
+use crate::state::Gauge;
+
+#[derive(Accounts)]
+pub struct NotifyReward<'info> {
+    /// The pair associated with the gauge
+    #[account(
+        seeds = [
+            b"pair",
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = pair.bumps.pair,
+    )]
+    pub pair: Account<'info, Pair>,
+
+    /// The gauge
+    #[account(
+        mut,
+        seeds = [
+            b"gauge",
+            gauge.mint_rewards.as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = gauge.bumps.gauge,
+        has_one = pair,
+        has_one = mint_rewards,
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    /// The mint of the gauge staking rewards
+    #[account(constraint = mint_rewards.mint_authority == COption::Some(notifier.key()))]
+    pub mint_rewards: Box<Account<'info, Mint>>,
+
+    /// The gauge account holding rewards tokens
+    #[account(
+        seeds = [
+            b"rewards_account",
+            gauge.mint_rewards.as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = gauge.bumps.account_rewards,
+    )]
+    pub rewards_account: Box<Account<'info, TokenAccount>>,
+
+    /// The mint authority of the rewards, which sent them to the rewards account
+    pub notifier: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<NotifyReward>, amount: u64, duration: u64) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+    if duration == 0 {
+        return Err(ErrorCode::InvalidDuration.into());
+    }
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let gauge = &mut ctx.accounts.gauge;
+    // Rewards streamed at the previous rate are accrued first
+    gauge.update_rewards(None, now)?;
+
+    // Rewards not yet streamed are added to the new stream
+    let leftover = if now < gauge.period_finish {
+        ((gauge.period_finish - now) as u64)
+            .checked_mul(gauge.reward_rate)
+            .ok_or(ErrorCode::MathOverflow)?
+    } else {
+        0
+    };
+    let reward_rate = amount.checked_add(leftover).ok_or(ErrorCode::MathOverflow)? / duration;
+    if reward_rate * duration > ctx.accounts.rewards_account.amount {
+        return Err(ErrorCode::RewardTooHigh.into());
+    }
+
+    gauge.reward_rate = reward_rate;
+    gauge.period_finish = now + duration as i64;
+
+    emit!(RewardNotified {
+        gauge: gauge.key(),
+        amount,
+        reward_rate,
+        period_finish: gauge.period_finish,
+    });
+
+    msg!("Streaming {} rewards per second until {}", reward_rate, gauge.period_finish);
+
+    Ok(())
+}
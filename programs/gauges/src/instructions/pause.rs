@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::events::PauseUpdated;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The configuration shared by every gauge
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint =
+            guardian.key() == config.guardian ||
+            guardian.key() == config.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The guardian of the gauges, or their admin
+    pub guardian: Signer<'info>,
+}
+
+/// Pauses features instantly, withdrawals are never paused
+pub fn handler(ctx: Context<Pause>, features: u64) -> ProgramResult {
+    Config::validate_features(features)?;
+
+    let config = &mut ctx.accounts.config;
+    config.paused |= features;
+
+    emit!(PauseUpdated {
+        config: config.key(),
+        paused: config.paused,
+        authority: ctx.accounts.guardian.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Paused features {:#b}", config.paused);
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use pools::state::Pair;
+use crate::errors::ErrorCode;
+use crate::events::LiquidityWithdrawn;
+use crate::state::{Gauge, Staker};
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    /// The pair associated with the gauge
+    #[account(
+        seeds = [
+            b"pair",
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = pair.bumps.pair,
+    )]
+    pub pair: Account<'info, Pair>,
+
+    /// The gauge
+    #[account(
+        mut,
+        seeds = [
+            b"gauge",
+            gauge.mint_rewards.key().as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = gauge.bumps.gauge,
+        has_one = pair,
+    )]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    /// The gauge authority
+    #[account(
+        seeds = [
+            b"authority",
+            gauge.mint_rewards.key().as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = gauge.bumps.authority
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// The gauge account holding LP tokens
+    #[account(
+        mut,
+        seeds = [
+            b"liquidity_account",
+            gauge.mint_rewards.as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+        ],
+        bump = gauge.bumps.account_liquidity,
+    )]
+    pub gauge_liquidity_account: Box<Account<'info, TokenAccount>>,
+
+    /// The liquidity provider
+    pub liquidity_provider: Signer<'info>,
+
+    /// The staker account owned by the liquidity_provider
+    #[account(
+        mut,
+        seeds = [
+            b"staker",
+            gauge.mint_rewards.as_ref(),
+            pair.mint_a.as_ref(),
+            pair.mint_b.as_ref(),
+            liquidity_provider.key().as_ref()
+        ],
+        bump = staker.bump
+    )]
+    pub staker: Box<Account<'info, Staker>>,
+
+    /// The account receiving LP tokens
+    #[account(
+        mut,
+        constraint = liquidity_provider_account.mint == pair.pair_mint @ ErrorCode::GaugeMismatch
+    )]
+    pub liquidity_provider_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> WithdrawLiquidity<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.gauge_liquidity_account.to_account_info(),
+                to: self.liquidity_provider_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawLiquidity>, amount: u64) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+    if amount > ctx.accounts.staker.amount {
+        return Err(ErrorCode::InsufficientStake.into());
+    }
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let gauge = &mut ctx.accounts.gauge;
+    let staker = &mut ctx.accounts.staker;
+    gauge.update_rewards(Some(staker), now)?;
+    staker.amount -= amount;
+    gauge.total_staked -= amount;
+
+    let pair = &ctx.accounts.pair;
+    let gauge = &ctx.accounts.gauge;
+    let seeds = &[
+        b"authority".as_ref(),
+        gauge.mint_rewards.as_ref(),
+        pair.mint_a.as_ref(),
+        pair.mint_b.as_ref(),
+        &[gauge.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+
+    emit!(LiquidityWithdrawn {
+        gauge: ctx.accounts.gauge.key(),
+        liquidity_provider: ctx.accounts.liquidity_provider.key(),
+        amount,
+        timestamp: now,
+    });
+
+    msg!("Withdrew {} tokens from gauge {}", amount, ctx.accounts.gauge.key());
+
+    Ok(())
+}
@@ -1,20 +1,76 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
-use state::gauge::*;
+use state::{gauge::*, staker::*};
 
 declare_id!("EVHqCRXSRufttxNFQTwD1mBvmnfBqhwBkfKxUSvAPr3m");
 
+/// The deployer of localnet builds, whose keypair is committed with the tests.
+/// Deployed builds only let the upgrade authority create the configuration of the gauges.
+#[cfg(feature = "localnet")]
+pub mod localnet_deployer {
+    anchor_lang::declare_id!("CKPHGgR3snABtcMQiqfJvNCx6UgDzFy5Mp3Maz3SP2ug");
+}
+
 #[program]
 pub mod gauges {
     use super::*;
 
-    pub fn create_gauge(ctx: Context<CreateGauge>, bumps: CreateGaugeBumps) -> ProgramResult {
-        instructions::create_gauge::handler(ctx, bumps)
+    pub fn create_gauge(ctx: Context<CreateGauge>) -> ProgramResult {
+        instructions::create_gauge::handler(ctx)
+    }
+
+    pub fn notify_reward(ctx: Context<NotifyReward>, amount: u64, duration: u64) -> ProgramResult {
+        instructions::notify_reward::handler(ctx, amount, duration)
+    }
+
+    pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, amount: u64) -> ProgramResult {
+        instructions::deposit_liquidity::handler(ctx, amount)
+    }
+
+    pub fn withdraw_liquidity(ctx: Context<WithdrawLiquidity>, amount: u64) -> ProgramResult {
+        instructions::withdraw_liquidity::handler(ctx, amount)
+    }
+
+    pub fn collect_rewards(ctx: Context<CollectRewards>) -> ProgramResult {
+        instructions::collect_rewards::handler(ctx)
+    }
+
+    pub fn migrate_gauge(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Gauge>(ctx)
+    }
+
+    pub fn migrate_staker(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Staker>(ctx)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        guardian: Pubkey,
+    ) -> ProgramResult {
+        instructions::initialize_config::handler(ctx, admin, guardian)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        admin: Pubkey,
+        guardian: Pubkey,
+    ) -> ProgramResult {
+        instructions::update_config::handler(ctx, admin, guardian)
+    }
+
+    pub fn pause(ctx: Context<Pause>, features: u64) -> ProgramResult {
+        instructions::pause::handler(ctx, features)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, features: u64) -> ProgramResult {
+        instructions::unpause::handler(ctx, features)
     }
 }
 
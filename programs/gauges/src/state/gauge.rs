@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::state::{LegacyGauge, Staker, Migratable, Versioned};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct CreateGaugeBumps {
     pub gauge: u8,
@@ -11,6 +14,39 @@ pub struct CreateGaugeBumps {
     pub account_b: u8,
 }
 
+impl CreateGaugeBumps {
+    /// Finds the canonical bumps of the PDAs of the gauge of `mint_rewards` on a pair
+    pub fn find(
+        mint_rewards: &Pubkey,
+        mint_a: &Pubkey,
+        mint_b: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    mint_rewards.as_ref(),
+                    mint_a.as_ref(),
+                    mint_b.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        CreateGaugeBumps {
+            gauge: find(b"gauge"),
+            authority: find(b"authority"),
+            mint: find(b"mint"),
+            account_liquidity: find(b"liquidity_account"),
+            account_rewards: find(b"rewards_account"),
+            account_a: find(b"account_a"),
+            account_b: find(b"account_b"),
+        }
+    }
+}
+
 /// A vesting locker account
 #[account]
 #[derive(Default)]
@@ -32,4 +68,108 @@ pub struct Gauge {
 
     /// The bump used to generate PDAs
     pub bumps: CreateGaugeBumps,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// The amount of rewards streamed to stakers per second
+    pub reward_rate: u64,
+
+    /// The end of the current reward stream
+    pub period_finish: i64,
+
+    /// The last time `reward_per_token_stored` was updated
+    pub last_update_time: i64,
+
+    /// The rewards earned per staked token since the creation, scaled by `PRECISION`
+    pub reward_per_token_stored: u128,
+
+    /// The amount of liquidity tokens staked
+    pub total_staked: u64,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 2],
+}
+
+impl Gauge {
+    /// The scale of `reward_per_token_stored`
+    pub const PRECISION: u128 = 1_000_000_000_000;
+
+    /// Computes the rewards earned per staked token at `now`
+    pub fn reward_per_token(&self, now: i64) -> Result<u128, ProgramError> {
+        let end = now.min(self.period_finish);
+        if self.total_staked == 0 || end <= self.last_update_time {
+            return Ok(self.reward_per_token_stored);
+        }
+
+        let streamed = ((end - self.last_update_time) as u128)
+            .checked_mul(self.reward_rate as u128)
+            .and_then(|r| r.checked_mul(Gauge::PRECISION))
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.reward_per_token_stored
+            .checked_add(streamed / self.total_staked as u128)
+            .ok_or_else(|| ErrorCode::MathOverflow.into())
+    }
+
+    /// Accrues the rewards streamed until `now`, and those earned by `staker` if any
+    pub fn update_rewards(&mut self, staker: Option<&mut Staker>, now: i64) -> ProgramResult {
+        self.reward_per_token_stored = self.reward_per_token(now)?;
+        self.last_update_time = now;
+
+        if let Some(staker) = staker {
+            let earned = (staker.amount as u128)
+                .checked_mul(self.reward_per_token_stored - staker.reward_per_token_paid)
+                .ok_or(ErrorCode::MathOverflow)?
+                / Gauge::PRECISION;
+            staker.rewards = staker
+                .rewards
+                .checked_add(earned as u64)
+                .ok_or(ErrorCode::MathOverflow)?;
+            staker.reward_per_token_paid = self.reward_per_token_stored;
+        }
+
+        Ok(())
+    }
+
+    /// Computes the address of the account holding the rewards of the gauge of a pair
+    pub fn rewards_account(&self, mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+        Pubkey::create_program_address(
+            &[
+                b"rewards_account",
+                self.mint_rewards.as_ref(),
+                mint_a.as_ref(),
+                mint_b.as_ref(),
+                &[self.bumps.account_rewards],
+            ],
+            &crate::ID,
+        )
+        .unwrap_or_default()
+    }
+}
+
+impl Versioned for Gauge {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Gauge {
+    type Legacy = LegacyGauge;
+
+    fn from_legacy(legacy: LegacyGauge) -> Self {
+        Gauge {
+            pair: legacy.pair,
+            gauge_mint: legacy.gauge_mint,
+            mint_rewards: legacy.mint_rewards,
+            authority: legacy.authority,
+            cumulative_fees: legacy.cumulative_fees,
+            bumps: legacy.bumps,
+            version: Gauge::VERSION,
+            // Legacy gauges could not stake liquidity nor stream rewards
+            reward_rate: 0,
+            period_finish: 0,
+            last_update_time: 0,
+            reward_per_token_stored: 0,
+            total_staked: 0,
+            ..Default::default()
+        }
+    }
 }
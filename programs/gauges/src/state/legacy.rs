@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CreateGaugeBumps;
+
+/// The layout of a gauge before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyGauge {
+    pub pair: Pubkey,
+    pub gauge_mint: Pubkey,
+    pub mint_rewards: Pubkey,
+    pub authority: Pubkey,
+    pub cumulative_fees: u64,
+    pub bumps: CreateGaugeBumps,
+}
+
+/// The layout of a staker before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyStaker {
+    pub owner: Pubkey,
+    pub last_collect: u64,
+    pub bump: u8,
+}
@@ -1,5 +1,11 @@
+pub mod config;
 pub mod gauge;
+pub mod legacy;
 pub mod staker;
+pub mod version;
 
+pub use config::*;
 pub use gauge::*;
-pub use staker::*;
\ No newline at end of file
+pub use legacy::*;
+pub use staker::*;
+pub use version::*;
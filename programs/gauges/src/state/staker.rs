@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{LegacyStaker, Migratable, Versioned};
+
 /// A staker account
 #[account]
 #[derive(Default)]
@@ -12,4 +14,41 @@ pub struct Staker {
 
     /// The bump used to generate PDAs
     pub bump: u8,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// The amount of liquidity tokens staked
+    pub amount: u64,
+
+    /// The rewards per staked token of the gauge when the rewards of the staker were last updated
+    pub reward_per_token_paid: u128,
+
+    /// The rewards earned and not collected yet
+    pub rewards: u64,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 4],
+}
+
+impl Versioned for Staker {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Staker {
+    type Legacy = LegacyStaker;
+
+    fn from_legacy(legacy: LegacyStaker) -> Self {
+        Staker {
+            owner: legacy.owner,
+            last_collect: legacy.last_collect,
+            bump: legacy.bump,
+            version: Staker::VERSION,
+            // Legacy stakers had nothing staked in the gauge
+            amount: 0,
+            reward_per_token_paid: 0,
+            rewards: 0,
+            ..Default::default()
+        }
+    }
 }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// An account whose layout is versioned, with space reserved for new fields
+pub trait Versioned: AccountSerialize + AccountDeserialize + AnchorSerialize + Default {
+    /// The layout version of new accounts
+    const VERSION: u8;
+
+    /// Computes the space needed by an account with the current layout
+    fn space() -> usize {
+        8 + Self::default().try_to_vec().unwrap().len()
+    }
+}
+
+/// A versioned account that existed before versioning, and can be upgraded from that layout
+pub trait Migratable: Versioned + Discriminator {
+    /// The layout of the account before it was versioned
+    type Legacy: AnchorSerialize + AnchorDeserialize + Default;
+
+    /// Converts an account with the legacy layout, filling new fields with their defaults
+    fn from_legacy(legacy: Self::Legacy) -> Self;
+
+    /// Computes the space used by an account with the legacy layout
+    fn legacy_space() -> usize {
+        8 + Self::Legacy::default().try_to_vec().unwrap().len()
+    }
+}
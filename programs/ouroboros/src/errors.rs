@@ -6,4 +6,102 @@ pub enum ErrorCode {
     UnclaimedIncentives,
     #[msg("Given snapshot is invalid")]
     InvalidSnapshot,
+    #[msg("Locker is still locked")]
+    LockerLocked,
+    #[msg("Given beneficiary is not the one of the locker")]
+    InvalidBeneficiary,
+    #[msg("Incentives of this beneficiary are compounded in a locker")]
+    CompoundingEnabled,
+    #[msg("Locker is expired")]
+    LockerExpired,
+    #[msg("Given merkle proof is invalid")]
+    InvalidProof,
+    #[msg("Allocation already claimed")]
+    AlreadyClaimed,
+    #[msg("Distribution has ended")]
+    DistributionEnded,
+    #[msg("Unclaimed tokens can't be clawed back yet")]
+    ClawbackNotStarted,
+    #[msg("Vesting dates are not ordered")]
+    InvalidSchedule,
+    #[msg("Vesting is not revocable")]
+    NotRevocable,
+    #[msg("Vesting tokens are locked in a locker")]
+    LockedVesting,
+    #[msg("Amount exceeds the unvested tokens")]
+    ExceedsUnvested,
+    #[msg("Asset mint is not allowed")]
+    MintNotAllowed,
+    #[msg("Amount is below the minimum deposit of this asset")]
+    AmountTooSmall,
+    #[msg("Locker already voted during this epoch")]
+    AlreadyVoted,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Metadata field is too long")]
+    MetadataTooLong,
+    #[msg("Incentives of this beneficiary are streamed by a gauge")]
+    GaugeBound,
+    #[msg("Locker or price changed since it was listed")]
+    ListingOutdated,
+    #[msg("Fee is above 100%")]
+    InvalidFee,
+    #[msg("Locker is already in this lock mode")]
+    SameLockMode,
+    #[msg("Locker is soulbound and can't be transferred")]
+    SoulboundLocker,
+    #[msg("Pair does not contain the native token")]
+    InvalidPair,
+    #[msg("Locker does not hold native tokens")]
+    InvalidCollateral,
+    #[msg("Signer is not the mint authority")]
+    InvalidMintAuthority,
+    #[msg("Share is above 100%")]
+    InvalidShare,
+    #[msg("The TWAP window of the buyback is not over")]
+    BuybackTooEarly,
+    #[msg("Buyback output is below the TWAP bound")]
+    SlippageExceeded,
+    #[msg("Too many recipients")]
+    TooManyRecipients,
+    #[msg("Recipient shares don't add up to 100%")]
+    InvalidShares,
+    #[msg("Recipient accounts don't match the recipients of the beneficiary")]
+    InvalidRecipients,
+    #[msg("Beneficiaries and their accounts must be passed in registry order")]
+    InvalidDistribution,
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("Amount must be positive")]
+    ZeroAmount,
+    #[msg("Period is zero or above the maximum")]
+    InvalidPeriod,
+    #[msg("Emission caps are null, above 100% or the minimum weight is above the maximum")]
+    InvalidCaps,
+    #[msg("Given gauge is not the one of the beneficiary")]
+    GaugeMismatch,
+    #[msg("Signer does not hold the receipt of the locker")]
+    InvalidReceipt,
+    #[msg("Clawback date is in the past")]
+    InvalidClawback,
+    #[msg("Feature is paused")]
+    Paused,
+    #[msg("Unknown or empty pause features")]
+    InvalidFeatures,
+    #[msg("Locker does not belong to this ouroboros")]
+    InvalidLocker,
+    #[msg("Beneficiary is already in the registry")]
+    AlreadyRegistered,
+    #[msg("Some beneficiaries are not in the registry yet")]
+    IncompleteRegistry,
+    #[msg("The TWAP window of the native pair is not over")]
+    LpPriceUnavailable,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Mints with a freeze authority can't be attached")]
+    FreezableMint,
+    #[msg("Incentives of the last ended epoch are already claimed")]
+    EpochNotFinished,
+    #[msg("Beneficiary must claim the last ended epoch before receiving votes")]
+    BeneficiaryInactive,
 }
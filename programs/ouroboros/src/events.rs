@@ -0,0 +1,799 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Recipient, VotingMode};
+
+/// Emitted when an ouroboros is initialized
+#[event]
+pub struct OuroborosInitialized {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The native mint
+    pub mint: Pubkey,
+
+    /// The supply minted to the creator
+    pub initial_supply: u64,
+
+    /// The reward period in seconds
+    pub period: u64,
+
+    /// The start of the first period
+    pub epoch: i64,
+}
+
+/// Emitted when a beneficiary is created
+#[event]
+pub struct BeneficiaryCreated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The account receiving incentives
+    pub account: Pubkey,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a locker is created
+#[event]
+pub struct LockerCreated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The receipt of the locker
+    pub receipt: Pubkey,
+
+    /// The wallet that created the locker
+    pub creator: Pubkey,
+
+    /// The amount of tokens locked
+    pub amount: u64,
+
+    /// Votes granted by the locker
+    pub votes: u64,
+
+    /// The unlock date of the locker
+    pub unlock_timestamp: i64,
+
+    /// Is the receipt frozen in the wallet of its creator
+    pub soulbound: bool,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a locker is withdrawn and closed
+#[event]
+pub struct LockerWithdrawn {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The holder of the receipt
+    pub owner: Pubkey,
+
+    /// The amount of tokens returned to the owner
+    pub amount: u64,
+
+    /// The amount of tokens distributed to remaining lockers
+    pub penalty: u64,
+
+    /// Votes removed
+    pub votes: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a locker votes for a beneficiary
+#[event]
+pub struct VoteCast {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker voting
+    pub locker: Pubkey,
+
+    /// The beneficiary receiving the votes
+    pub beneficiary: Pubkey,
+
+    /// The beneficiary losing the votes, default if it's the first vote
+    pub old_beneficiary: Pubkey,
+
+    /// The votes moved
+    pub votes: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a beneficiary claims its incentives
+#[event]
+pub struct IncentivesClaimed {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The account receiving incentives
+    pub account: Pubkey,
+
+    /// The amount of native tokens minted
+    pub amount: u64,
+
+    /// The weight of the beneficiary (BP)
+    pub weight: u16,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when the incentives of a beneficiary are locked in its locker
+#[event]
+pub struct IncentivesCompounded {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The locker receiving incentives
+    pub locker: Pubkey,
+
+    /// The amount of native tokens minted and locked
+    pub amount: u64,
+
+    /// The votes granted to the locker
+    pub votes: u64,
+
+    /// The weight of the beneficiary (BP)
+    pub weight: u16,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when the ouroboros receives an asset
+#[event]
+pub struct AssetReceived {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The mint of the asset
+    pub mint: Pubkey,
+
+    /// The wallet that sent the asset
+    pub sender: Pubkey,
+
+    /// The amount received
+    pub amount: u64,
+
+    /// The index of the snapshot the asset was added to
+    pub snapshot_index: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a locker collects its share of an asset
+#[event]
+pub struct FeesCollected {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker collecting
+    pub locker: Pubkey,
+
+    /// The mint of the asset
+    pub mint: Pubkey,
+
+    /// The account receiving the fees
+    pub holder_account: Pubkey,
+
+    /// The amount collected
+    pub amount: u64,
+
+    /// The index of the snapshot claimed
+    pub snapshot_index: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when a merkle distributor is created
+#[event]
+pub struct DistributorCreated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The distributor
+    pub distributor: Pubkey,
+
+    /// The root of the merkle tree
+    pub root: [u8; 32],
+
+    /// The number of leaves of the tree
+    pub num_leaves: u64,
+
+    /// The amount of tokens to distribute
+    pub total_amount: u64,
+
+    /// The date after which unclaimed tokens can be clawed back
+    pub clawback_timestamp: i64,
+}
+
+/// Emitted when an allocation of a distributor is claimed
+#[event]
+pub struct GenesisLockerClaimed {
+    /// The distributor
+    #[index]
+    pub distributor: Pubkey,
+
+    /// The locker created
+    pub locker: Pubkey,
+
+    /// The recipient of the allocation
+    pub recipient: Pubkey,
+
+    /// The index of the leaf
+    pub index: u64,
+
+    /// The amount of tokens locked
+    pub amount: u64,
+
+    /// The locking period
+    pub period: u64,
+}
+
+/// Emitted when unclaimed tokens of a distributor are clawed back
+#[event]
+pub struct DistributorClawedBack {
+    /// The distributor
+    #[index]
+    pub distributor: Pubkey,
+
+    /// The admin receiving tokens
+    pub admin: Pubkey,
+
+    /// The amount clawed back
+    pub amount: u64,
+}
+
+/// Emitted when a vesting is created
+#[event]
+pub struct VestingCreated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The vesting
+    pub vesting: Pubkey,
+
+    /// The wallet receiving vested tokens
+    pub recipient: Pubkey,
+
+    /// The amount of tokens vesting
+    pub amount: u64,
+
+    /// The start of the linear release
+    pub start_timestamp: i64,
+
+    /// The date before which nothing can be withdrawn
+    pub cliff_timestamp: i64,
+
+    /// The date at which all tokens are vested
+    pub end_timestamp: i64,
+
+    /// Can the admin take back unvested tokens
+    pub revocable: bool,
+}
+
+/// Emitted when the recipient of a vesting withdraws vested tokens
+#[event]
+pub struct VestedTokensWithdrawn {
+    /// The vesting
+    #[index]
+    pub vesting: Pubkey,
+
+    /// The wallet receiving vested tokens
+    pub recipient: Pubkey,
+
+    /// The amount withdrawn
+    pub amount: u64,
+
+    /// The total amount withdrawn so far
+    pub withdrawn_amount: u64,
+}
+
+/// Emitted when the admin of a vesting takes back unvested tokens
+#[event]
+pub struct VestingRevoked {
+    /// The vesting
+    #[index]
+    pub vesting: Pubkey,
+
+    /// The admin receiving unvested tokens
+    pub admin: Pubkey,
+
+    /// The amount left to the recipient
+    pub vested_amount: u64,
+
+    /// The amount sent back to the admin
+    pub revoked_amount: u64,
+}
+
+/// Emitted when unvested tokens are locked in a locker
+#[event]
+pub struct VestingLocked {
+    /// The vesting
+    #[index]
+    pub vesting: Pubkey,
+
+    /// The locker created
+    pub locker: Pubkey,
+
+    /// The amount of tokens locked
+    pub amount: u64,
+}
+
+/// Emitted when the locker of a vesting expires and its tokens return to the vesting
+#[event]
+pub struct VestingUnlocked {
+    /// The vesting
+    #[index]
+    pub vesting: Pubkey,
+
+    /// The closed locker
+    pub locker: Pubkey,
+
+    /// The amount of tokens returned
+    pub amount: u64,
+}
+
+/// Emitted when the admin of an ouroboros changes
+#[event]
+pub struct AdminChanged {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The previous admin
+    pub old_admin: Pubkey,
+
+    /// The new admin
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when a mint is proposed as an asset
+#[event]
+pub struct MintProposed {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The proposed mint
+    pub mint: Pubkey,
+
+    /// The wallet proposing the mint
+    pub proposer: Pubkey,
+}
+
+/// Emitted when the admin approves or rejects a mint
+#[event]
+pub struct AllowedMintUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The mint
+    pub mint: Pubkey,
+
+    /// Can the ouroboros receive this asset
+    pub approved: bool,
+
+    /// The minimum amount accepted
+    pub min_amount: u64,
+}
+
+/// Emitted when the metadata of a beneficiary is set
+#[event]
+pub struct BeneficiaryMetadataUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The display name
+    pub name: String,
+
+    /// A link to an off-chain description
+    pub uri: String,
+
+    /// The kind of service provided by the beneficiary
+    pub category: String,
+
+    /// The pair served by the beneficiary, default if none
+    pub pair: Pubkey,
+
+    /// The gauge served by the beneficiary, default if none
+    pub gauge: Pubkey,
+}
+
+/// Emitted when a locker is listed for sale
+#[event]
+pub struct LockerListed {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The wallet selling the locker
+    pub seller: Pubkey,
+
+    /// The mint in which the price is paid
+    pub price_mint: Pubkey,
+
+    /// The price of the locker
+    pub price: u64,
+}
+
+/// Emitted when a listed locker is bought
+#[event]
+pub struct LockerSold {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The wallet that sold the locker
+    pub seller: Pubkey,
+
+    /// The wallet that bought the locker
+    pub buyer: Pubkey,
+
+    /// The price paid
+    pub price: u64,
+
+    /// The part of the price sent to the treasury
+    pub fee: u64,
+}
+
+/// Emitted when the seller of a locker cancels its listing
+#[event]
+pub struct ListingCancelled {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The wallet that listed the locker
+    pub seller: Pubkey,
+}
+
+/// Emitted when the admin changes the fee taken on locker sales
+#[event]
+pub struct ListingFeeUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The fee in BP
+    pub listing_fee: u16,
+
+    /// The wallet receiving fees
+    pub treasury: Pubkey,
+}
+
+/// Emitted when a locker enters or leaves the permanent lock mode
+#[event]
+pub struct PermanentLockSet {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// Is the locker permanent
+    pub permanent: bool,
+
+    /// Votes of the locker
+    pub votes: u64,
+
+    /// The unlock date of the locker, ignored while permanent
+    pub unlock_timestamp: i64,
+}
+
+/// Emitted when the admin sets the pair whose LP tokens can be locked
+#[event]
+pub struct NativePairSet {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The pair
+    pub pair: Pubkey,
+
+    /// The mint of the LP tokens
+    pub pair_mint: Pubkey,
+}
+
+/// Emitted when the treasury of an ouroboros is created
+#[event]
+pub struct TreasuryCreated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The treasury
+    pub treasury: Pubkey,
+}
+
+/// Emitted when the treasury provides liquidity to a pair
+#[event]
+pub struct TreasuryLiquidityAdded {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The pair
+    pub pair: Pubkey,
+
+    /// The LP tokens received by the treasury
+    pub liquidity: u64,
+}
+
+/// Emitted when the treasury withdraws liquidity from a pair
+#[event]
+pub struct TreasuryLiquidityRemoved {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The pair
+    pub pair: Pubkey,
+
+    /// The LP tokens burned by the treasury
+    pub liquidity: u64,
+}
+
+/// Emitted when the treasury stakes LP tokens in a gauge
+#[event]
+pub struct TreasuryLiquidityStaked {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The gauge
+    pub gauge: Pubkey,
+
+    /// The LP tokens staked
+    pub amount: u64,
+}
+
+/// Emitted when the treasury withdraws LP tokens from a gauge
+#[event]
+pub struct TreasuryLiquidityUnstaked {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The gauge
+    pub gauge: Pubkey,
+
+    /// The LP tokens withdrawn
+    pub amount: u64,
+}
+
+/// Emitted when the admin changes the share of assets bought back
+#[event]
+pub struct BuybackUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The share of received assets bought back (BP)
+    pub buyback_share: u16,
+
+    /// The maximum deviation from the TWAP (BP)
+    pub buyback_slippage: u16,
+}
+
+/// Emitted when native tokens are bought back with an asset and burned
+#[event]
+pub struct BuybackBurned {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The mint of the asset sold
+    pub mint: Pubkey,
+
+    /// The amount of the asset sold
+    pub amount: u64,
+
+    /// The amount of native tokens burned
+    pub burned: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when the owner of a beneficiary sets the locker compounding its incentives
+#[event]
+pub struct CompoundingLockerSet {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The locker receiving incentives, default if compounding is disabled
+    pub locker: Pubkey,
+}
+
+/// Emitted when the admin changes how the votes of lockers count
+#[event]
+pub struct VotingModeSet {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// How the votes of a locker count towards its beneficiary
+    pub voting_mode: VotingMode,
+
+    /// The maximum effective votes of a locker in capped mode
+    pub vote_cap: u64,
+}
+
+/// Emitted when the owner of a beneficiary splits its incentives
+#[event]
+pub struct BeneficiaryRecipientsUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The accounts receiving incentives, empty if sent to the beneficiary account
+    pub recipients: Vec<Recipient>,
+}
+
+/// Emitted when the admin adds a beneficiary created before the registry
+#[event]
+pub struct BeneficiaryRegistered {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The beneficiary
+    pub beneficiary: Pubkey,
+
+    /// The position of the beneficiary in the registry plus one
+    pub slot: u64,
+}
+
+/// Emitted when the admin declares every beneficiary registered
+#[event]
+pub struct RegistryCompleted {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The number of beneficiaries in the registry
+    pub num_beneficiaries: u64,
+}
+
+/// Emitted when the admin changes the reward of the distribution crank
+#[event]
+pub struct KeeperRewardUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The share in BP of distributed incentives minted to the keeper
+    pub keeper_reward: u16,
+}
+
+/// Emitted when the crank distributes incentives to a page of beneficiaries
+#[event]
+pub struct IncentivesDistributed {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The number of beneficiaries processed
+    pub beneficiaries: u64,
+
+    /// The incentives minted to beneficiaries
+    pub amount: u64,
+
+    /// The wallet running the crank
+    pub keeper: Pubkey,
+
+    /// The incentives minted to the keeper
+    pub keeper_reward: u64,
+
+    /// The number of registered beneficiaries processed during this epoch
+    pub cursor: u64,
+
+    /// The start of the current period
+    pub epoch: i64,
+}
+
+/// Emitted when the guardian of an ouroboros changes
+#[event]
+pub struct GuardianChanged {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The previous guardian
+    pub old_guardian: Pubkey,
+
+    /// The new guardian
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when features of an ouroboros are paused or unpaused
+#[event]
+pub struct PauseUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The paused features after the update
+    pub paused: u64,
+
+    /// The wallet that updated the paused features
+    pub authority: Pubkey,
+}
+
+/// Emitted when the votes of a locker are counted again under the current voting mode
+#[event]
+pub struct LockerVotesUpdated {
+    /// The ouroboros
+    #[index]
+    pub ouroboros: Pubkey,
+
+    /// The locker
+    pub locker: Pubkey,
+
+    /// The beneficiary the locker voted for
+    pub beneficiary: Pubkey,
+
+    /// The effective votes of the locker before the update
+    pub old_votes: u64,
+
+    /// The effective votes of the locker after the update
+    pub new_votes: u64,
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Mint, SetAuthority, Token};
+use spl_token::instruction::AuthorityType;
+
+use crate::{
+    errors::ErrorCode,
+    events::OuroborosInitialized,
+    state::{flags, Ouroboros, OuroborosBumps, OuroborosParams, Versioned},
+};
+
+#[derive(Accounts)]
+#[instruction(ouroboros_id: u64)]
+pub struct AttachOuroboros<'info> {
+    /// The Ouroboros
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"ouroboros",
+            ouroboros_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub ouroboros: Account<'info, Ouroboros>,
+
+    /// The Ouroboros authority
+    #[account(
+        seeds = [
+            b"authority",
+            ouroboros_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub authority: AccountInfo<'info>,
+
+    /// The existing mint, whose authority is handed to the Ouroboros authority
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// The current authority of the mint
+    pub mint_authority: Signer<'info>,
+
+    /// The wallet creating the Ouroboros
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttachOuroboros<'info> {
+    fn set_authority_context(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: self.mint_authority.to_account_info(),
+                account_or_mint: self.mint.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<AttachOuroboros>,
+    ouroboros_id: u64,
+    params: OuroborosParams,
+) -> ProgramResult {
+    Ouroboros::validate_params(params.period, params.early_withdraw_penalty, &params.caps)?;
+    if ctx.accounts.mint.mint_authority != COption::Some(ctx.accounts.mint_authority.key()) {
+        return Err(ErrorCode::InvalidMintAuthority.into());
+    }
+    // A freeze authority could freeze lockers and beneficiaries
+    if ctx.accounts.mint.freeze_authority.is_some() {
+        return Err(ErrorCode::FreezableMint.into());
+    }
+
+    token::set_authority(
+        ctx.accounts.set_authority_context(),
+        AuthorityType::MintTokens,
+        Some(ctx.accounts.authority.key()),
+    )?;
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.id = ouroboros_id;
+    ouroboros.authority = ctx.accounts.authority.key();
+    ouroboros.admin = ctx.accounts.creator.key();
+    ouroboros.treasury = ctx.accounts.creator.key();
+    ouroboros.mint = ctx.accounts.mint.key();
+    ouroboros.period = params.period;
+    ouroboros.last_period = params.start_date;
+    ouroboros.expansion_factor = params.expansion_factor;
+    ouroboros.time_multiplier = params.time_multiplier;
+    ouroboros.early_withdraw_penalty = params.early_withdraw_penalty;
+    ouroboros.caps = params.caps;
+    ouroboros.bumps = OuroborosBumps::find_attached(ouroboros_id, ctx.program_id);
+    // Beneficiaries of new ouroboros are registered on creation
+    ouroboros.flags = flags::REGISTRY_COMPLETE;
+    ouroboros.version = Ouroboros::VERSION;
+
+    // Emissions expand the existing supply of the mint
+    emit!(OuroborosInitialized {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: ctx.accounts.mint.key(),
+        initial_supply: ctx.accounts.mint.supply,
+        period: params.period,
+        epoch: params.start_date,
+    });
+
+    msg!("Ouroboros attached to mint {}", ctx.accounts.mint.key());
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerSold,
+    state::{Listing, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct BuyLocker<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The locker being bought
+    #[account(
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The listing
+    #[account(
+        mut,
+        seeds = [
+            b"listing",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = listing.bumps.listing,
+        has_one = locker,
+        has_one = seller,
+        close = seller
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// The account holding the receipt
+    #[account(
+        mut,
+        seeds = [
+            b"listing_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = listing.bumps.escrow
+    )]
+    pub escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet that listed the locker, refunded for closed accounts
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+
+    /// The account of the seller receiving the price
+    #[account(
+        mut,
+        constraint =
+            seller_account.owner == seller.key() &&
+            seller_account.mint == listing.price_mint
+    )]
+    pub seller_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the treasury receiving the fee
+    #[account(
+        mut,
+        constraint =
+            treasury_account.owner == ouroboros.treasury &&
+            treasury_account.mint == listing.price_mint
+    )]
+    pub treasury_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet buying the locker
+    pub buyer: Signer<'info>,
+
+    /// The account of the buyer paying the price
+    #[account(mut)]
+    pub buyer_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the buyer receiving the receipt
+    #[account(mut)]
+    pub buyer_receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> BuyLocker<'info> {
+    fn pay_context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.buyer_account.to_account_info(),
+                to,
+                authority: self.buyer.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.escrow_account.to_account_info(),
+                to: self.buyer_receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_account.to_account_info(),
+                destination: self.seller.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<BuyLocker>, expected_price: u64, price_mint: Pubkey) -> ProgramResult {
+    let locker = &ctx.accounts.locker;
+    let listing = &ctx.accounts.listing;
+    // The seller can relist at another price between the buyer reading the listing and buying it
+    if !listing.matches(locker.amount, locker.votes, locker.unlock_timestamp)
+        || listing.price != expected_price
+        || listing.price_mint != price_mint
+    {
+        return Err(ErrorCode::ListingOutdated.into());
+    }
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let price = listing.price;
+    let fee = (price as u128 * ouroboros.listing_fee as u128 / 10000) as u64;
+
+    if fee > 0 {
+        token::transfer(
+            ctx.accounts
+                .pay_context(ctx.accounts.treasury_account.to_account_info()),
+            fee,
+        )?;
+    }
+    token::transfer(
+        ctx.accounts
+            .pay_context(ctx.accounts.seller_account.to_account_info()),
+        price - fee,
+    )?;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), 1)?;
+    token::close_account(ctx.accounts.close_context().with_signer(signer))?;
+
+    emit!(LockerSold {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        seller: ctx.accounts.seller.key(),
+        buyer: ctx.accounts.buyer.key(),
+        price,
+        fee,
+    });
+
+    msg!("Sold locker {} for {}", ctx.accounts.locker.key(), price);
+
+    Ok(())
+}
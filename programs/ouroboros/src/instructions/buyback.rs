@@ -0,0 +1,320 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use pools::{program::Pools, state::Pair};
+
+use crate::{
+    errors::ErrorCode,
+    events::BuybackBurned,
+    state::{Asset, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct Buyback<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority, swapping and burning tokens
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the Ouroboros token
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The asset bought back
+    #[account(
+        mut,
+        seeds = [
+            b"asset",
+            ouroboros.id.to_le_bytes().as_ref(),
+            asset.mint.as_ref()
+        ],
+        bump = asset.bumps.asset
+    )]
+    pub asset: Box<Account<'info, Asset>>,
+
+    /// The asset authority
+    #[account(
+        seeds = [
+            b"asset_authority",
+            ouroboros.id.to_le_bytes().as_ref(),
+            asset.mint.as_ref()
+        ],
+        bump = asset.bumps.authority
+    )]
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The mint of the asset
+    #[account(mut, address = asset.mint)]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    /// The account holding the asset
+    #[account(
+        mut,
+        seeds = [
+            b"asset_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            asset.mint.as_ref()
+        ],
+        bump = asset.bumps.account
+    )]
+    pub asset_account: Box<Account<'info, TokenAccount>>,
+
+    /// The configuration of the pools, checked by the pools program
+    pub pools_config: AccountInfo<'info>,
+
+    /// The pair of the native token and the asset
+    #[account(
+        mut,
+        constraint =
+            (pair.mint_a == mint.key() && pair.mint_b == asset.mint) ||
+            (pair.mint_a == asset.mint && pair.mint_b == mint.key())
+            @ ErrorCode::InvalidPair
+    )]
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The authority of the pair
+    #[account(mut)]
+    pub pair_authority: AccountInfo<'info>,
+
+    /// The mint of the LP tokens of the pair
+    #[account(mut)]
+    pub pair_mint: AccountInfo<'info>,
+
+    /// The pair account holding token A
+    #[account(mut)]
+    pub pair_account_a: Box<Account<'info, TokenAccount>>,
+
+    /// The pair account holding token B
+    #[account(mut)]
+    pub pair_account_b: Box<Account<'info, TokenAccount>>,
+
+    /// The pair account holding fees on token A
+    #[account(mut)]
+    pub fees_account_a: AccountInfo<'info>,
+
+    /// The pair account holding fees on token B
+    #[account(mut)]
+    pub fees_account_b: AccountInfo<'info>,
+
+    /// The account of the authority receiving bought native tokens
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+    )]
+    pub native_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the authority selling the asset
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = asset_mint,
+        associated_token::authority = authority,
+    )]
+    pub swap_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet running the buyback
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// The program of the pair
+    pub pools_program: Program<'info, Pools>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Buyback<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.asset_account.to_account_info(),
+                to: self.swap_account.to_account_info(),
+                authority: self.asset_authority.to_account_info(),
+            },
+        )
+    }
+
+    fn swap_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, pools::cpi::accounts::SwapExactInput<'info>> {
+        let (mint_a, mint_b, account_a, account_b) = if self.pair.mint_a == self.mint.key() {
+            (
+                &self.mint,
+                &self.asset_mint,
+                &self.native_account,
+                &self.swap_account,
+            )
+        } else {
+            (
+                &self.asset_mint,
+                &self.mint,
+                &self.swap_account,
+                &self.native_account,
+            )
+        };
+
+        CpiContext::new(
+            self.pools_program.to_account_info(),
+            pools::cpi::accounts::SwapExactInput {
+                config: self.pools_config.to_account_info(),
+                pair: self.pair.to_account_info(),
+                authority: self.pair_authority.to_account_info(),
+                pair_mint: self.pair_mint.to_account_info(),
+                mint_a: mint_a.to_account_info(),
+                mint_b: mint_b.to_account_info(),
+                pair_account_a: self.pair_account_a.to_account_info(),
+                pair_account_b: self.pair_account_b.to_account_info(),
+                fees_account_a: self.fees_account_a.to_account_info(),
+                fees_account_b: self.fees_account_b.to_account_info(),
+                swapper: self.authority.to_account_info(),
+                account_a: account_a.to_account_info(),
+                account_b: account_b.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.mint.to_account_info(),
+                to: self.native_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<Buyback>) -> ProgramResult {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let native_is_a = ctx.accounts.pair.mint_a == ctx.accounts.mint.key();
+    let (price_a_cumulative, price_b_cumulative) = ctx.accounts.pair.cumulative_prices(
+        ctx.accounts.pair_account_a.amount,
+        ctx.accounts.pair_account_b.amount,
+        now,
+    );
+    // The price of the asset in native tokens
+    let price_cumulative = if native_is_a {
+        price_b_cumulative
+    } else {
+        price_a_cumulative
+    };
+
+    // The first buyback only opens the TWAP window
+    let asset = &mut ctx.accounts.asset;
+    if asset.observation_timestamp == 0 {
+        asset.price_cumulative = price_cumulative;
+        asset.observation_timestamp = now;
+        msg!("Started the TWAP window of asset {}", asset.mint);
+        return Ok(());
+    }
+    if now < asset.observation_timestamp + ctx.accounts.ouroboros.period as i64 {
+        return Err(ErrorCode::BuybackTooEarly.into());
+    }
+
+    let twap = price_cumulative.wrapping_sub(asset.price_cumulative)
+        / (now - asset.observation_timestamp) as u128;
+    asset.price_cumulative = price_cumulative;
+    asset.observation_timestamp = now;
+
+    let amount = asset.buyback_amount;
+    if amount == 0 {
+        return Ok(());
+    }
+    asset.buyback_amount = 0;
+
+    let min_amount_out = (((amount as u128 * twap) >> 64)
+        * (10000 - ctx.accounts.ouroboros.buyback_slippage as u128)
+        / 10000) as u64;
+
+    let id_seed = ctx.accounts.ouroboros.id.to_le_bytes();
+    let mint_seed = ctx.accounts.asset.mint;
+    let asset_seeds = &[
+        b"asset_authority".as_ref(),
+        id_seed.as_ref(),
+        mint_seed.as_ref(),
+        &[ctx.accounts.asset.bumps.authority],
+    ];
+    token::transfer(
+        ctx.accounts
+            .transfer_context()
+            .with_signer(&[&asset_seeds[..]]),
+        amount,
+    )?;
+
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ctx.accounts.ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+    let balance = ctx.accounts.native_account.amount;
+    let (amount_in_a, amount_in_b) = if native_is_a {
+        (0, amount)
+    } else {
+        (amount, 0)
+    };
+    pools::cpi::swap_exact_input(
+        ctx.accounts.swap_context().with_signer(signer),
+        amount_in_a,
+        amount_in_b,
+        0,
+        0,
+        // The swap is made within this transaction
+        now,
+    )?;
+
+    ctx.accounts.native_account.reload()?;
+    let bought = ctx.accounts.native_account.amount - balance;
+    if bought < min_amount_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    token::burn(ctx.accounts.burn_context().with_signer(signer), bought)?;
+    ctx.accounts.ouroboros.record_burn(bought);
+
+    emit!(BuybackBurned {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: ctx.accounts.asset.mint,
+        amount,
+        burned: bought,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!(
+        "Bought back and burned {} native tokens with {} of asset {}",
+        bought,
+        amount,
+        ctx.accounts.asset.mint
+    );
+
+    Ok(())
+}
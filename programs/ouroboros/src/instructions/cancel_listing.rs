@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+use crate::{
+    events::ListingCancelled,
+    state::{Listing, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The listed locker
+    #[account(
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The listing
+    #[account(
+        mut,
+        seeds = [
+            b"listing",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = listing.bumps.listing,
+        has_one = locker,
+        has_one = seller,
+        close = seller
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// The account holding the receipt
+    #[account(
+        mut,
+        seeds = [
+            b"listing_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = listing.bumps.escrow
+    )]
+    pub escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet that listed the locker
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// The account of the seller receiving the receipt back
+    #[account(mut)]
+    pub seller_receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelListing<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.escrow_account.to_account_info(),
+                to: self.seller_receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_account.to_account_info(),
+                destination: self.seller.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<CancelListing>) -> ProgramResult {
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), 1)?;
+    token::close_account(ctx.accounts.close_context().with_signer(signer))?;
+
+    emit!(ListingCancelled {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        seller: ctx.accounts.seller.key(),
+    });
+
+    msg!("Cancelled listing of locker {}", ctx.accounts.locker.key());
+
+    Ok(())
+}
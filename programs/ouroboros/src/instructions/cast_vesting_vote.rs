@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::VoteCast,
+    instructions::cast_vote::move_votes,
+    state::{Beneficiary, Locker, Ouroboros, Vesting},
+};
+
+#[derive(Accounts)]
+pub struct CastVestingVote<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The beneficiary of the ouroboros incentives receiving votes
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The last beneficiary
+    /// Can be the same as beneficiary if it's the first vote
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            old_beneficiary.account.as_ref()
+        ],
+        bump = old_beneficiary.bump
+    )]
+    pub old_beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The vesting owning the locker
+    #[account(
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.vesting,
+        has_one = recipient,
+        has_one = locker
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The locker used to vote
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The wallet receiving vested tokens
+    pub recipient: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CastVestingVote>) -> ProgramResult {
+    let old_beneficiary_key = ctx.accounts.locker.beneficiary;
+    move_votes(
+        &mut ctx.accounts.ouroboros,
+        &mut ctx.accounts.locker,
+        &mut ctx.accounts.beneficiary,
+        &mut ctx.accounts.old_beneficiary,
+    )?;
+
+    emit!(VoteCast {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        old_beneficiary: old_beneficiary_key,
+        votes: ctx.accounts.locker.votes,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!(
+        "Cast vote of vesting locker {} for beneficiary {}",
+        ctx.accounts.locker.key(),
+        ctx.accounts.beneficiary.key()
+    );
+
+    Ok(())
+}
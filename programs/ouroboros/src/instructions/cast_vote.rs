@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
 
-use crate::{state::{Beneficiary, Locker, Ouroboros}, errors::ErrorCode};
+use crate::{
+    errors::ErrorCode,
+    events::VoteCast,
+    state::{Beneficiary, Locker, Ouroboros},
+};
 
 #[derive(Accounts)]
 pub struct CastVote<'info> {
     /// The Ouroboros
     #[account(
+        mut,
         seeds = [
             b"ouroboros",
             ouroboros.id.to_le_bytes().as_ref()
@@ -58,35 +63,78 @@ pub struct CastVote<'info> {
 
     /// The account holding the locker receipt
     #[account(
-        constraint = 
+        constraint =
             receipt_account.owner == voter.key() &&
+            receipt_account.mint == locker.receipt &&
             receipt_account.amount == 1
+            @ ErrorCode::InvalidReceipt
     )]
     pub receipt_account: Box<Account<'info, TokenAccount>>,
 }
 
-pub fn handler(ctx: Context<CastVote>) -> ProgramResult {
-    let locker = &mut ctx.accounts.locker;
-    let beneficiary = &mut ctx.accounts.beneficiary;
-
-    if beneficiary.last_update != ctx.accounts.ouroboros.last_period {
-        return Err(ErrorCode::UnclaimedIncentives.into());
+/// Moves the votes of a locker from its current beneficiary to a new one
+pub fn move_votes(
+    ouroboros: &mut Ouroboros,
+    locker: &mut Account<Locker>,
+    beneficiary: &mut Account<Beneficiary>,
+    old_beneficiary: &mut Account<Beneficiary>,
+) -> ProgramResult {
+    if locker.beneficiary != Pubkey::default() && locker.beneficiary != old_beneficiary.key() {
+        return Err(ErrorCode::InvalidBeneficiary.into());
     }
 
-    beneficiary.votes += locker.votes;
+    let epoch = ouroboros.last_period;
+    if locker.last_vote_epoch == epoch {
+        return Err(ErrorCode::AlreadyVoted.into());
+    }
+    if beneficiary.last_update != epoch {
+        return Err(ErrorCode::BeneficiaryInactive.into());
+    }
 
     if locker.beneficiary != Pubkey::default() {
-        let old_beneficiary = &mut ctx.accounts.old_beneficiary;
-
-        if old_beneficiary.last_update != ctx.accounts.ouroboros.last_period {
+        if old_beneficiary.last_update != epoch {
             return Err(ErrorCode::UnclaimedIncentives.into());
         }
         
-        old_beneficiary.votes -= locker.votes;
+        if old_beneficiary.key() == beneficiary.key() {
+            beneficiary.remove_votes(ouroboros, locker, locker.effective_votes);
+        } else {
+            old_beneficiary.remove_votes(ouroboros, locker, locker.effective_votes);
+        }
+    }
+
+    // The new votes only count from the next epoch, under the current voting mode
+    locker.last_vote_epoch = epoch;
+    locker.beneficiary = beneficiary.key();
+    locker.effective_votes = ouroboros.effective_votes(locker.votes);
+    beneficiary.add_votes(ouroboros, locker, locker.effective_votes);
+
+    // Both copies of an account passed twice are written back, the old beneficiary last
+    if old_beneficiary.key() == beneficiary.key() {
+        old_beneficiary.set_inner((**beneficiary).clone());
     }
 
-    locker.beneficiary = ctx.accounts.beneficiary.key();
-    
+    Ok(())
+}
+
+pub fn handler(ctx: Context<CastVote>) -> ProgramResult {
+    let old_beneficiary_key = ctx.accounts.locker.beneficiary;
+    move_votes(
+        &mut ctx.accounts.ouroboros,
+        &mut ctx.accounts.locker,
+        &mut ctx.accounts.beneficiary,
+        &mut ctx.accounts.old_beneficiary,
+    )?;
+
+    emit!(VoteCast {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        old_beneficiary: old_beneficiary_key,
+        votes: ctx.accounts.locker.votes,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
     msg!(
         "Cast vote of locker {} for beneficiary {} with {} votes",
         ctx.accounts.locker.key(),
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    events::IncentivesCompounded,
+    instructions::claim_incentives::update_incentives,
+    state::{pause, Beneficiary, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct ClaimCompoundedIncentives<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the Ouroboros token
+    #[account(
+        mut,
+        address = ouroboros.mint
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The beneficiary of the ouroboros incentives
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump,
+        has_one = account,
+        constraint = beneficiary.compounding_locker == locker.key()
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The account of the beneficiary
+    pub account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker receiving incentives
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account holding locked tokens
+    #[account(
+        mut,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.account
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account holding the locker receipt
+    /// Must be owned by the owner of the beneficiary account
+    #[account(
+        constraint =
+            receipt_account.owner == account.owner &&
+            receipt_account.mint == locker.receipt &&
+            receipt_account.amount == 1
+            @ ErrorCode::InvalidReceipt
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The beneficiary the locker voted for
+    /// Can be the same as beneficiary or anything if the locker never voted
+    #[account(mut)]
+    pub locker_beneficiary: AccountInfo<'info>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ClaimCompoundedIncentives<'info> {
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.mint.to_account_info(),
+                to: self.locker_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimCompoundedIncentives>) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::CLAIMS)?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    if ctx.accounts.locker.mint != ctx.accounts.ouroboros.mint {
+        return Err(ErrorCode::InvalidCollateral.into());
+    }
+    if !ctx.accounts.locker.permanent && ctx.accounts.locker.unlock_timestamp <= now {
+        return Err(ErrorCode::LockerExpired.into());
+    }
+
+    let amount = update_incentives(
+        &mut ctx.accounts.ouroboros,
+        &mut ctx.accounts.beneficiary,
+        ctx.accounts.mint.supply,
+        now,
+    )?;
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let locker = &mut ctx.accounts.locker;
+    let period = if locker.permanent {
+        Locker::MAX_PERIOD
+    } else {
+        (locker.unlock_timestamp - now) as u64
+    };
+    let votes = Locker::compute_votes(amount, period, ouroboros.time_multiplier);
+    locker.amount += amount;
+    locker.value += amount;
+    locker.votes += votes;
+    ouroboros.total_votes += amount;
+
+    // The votes of the locker follow its current vote
+    if locker.beneficiary == ctx.accounts.beneficiary.key() {
+        ctx.accounts.beneficiary.update_votes(ouroboros, locker);
+    } else if locker.beneficiary != Pubkey::default() {
+        if ctx.accounts.locker_beneficiary.key() != locker.beneficiary {
+            return Err(ErrorCode::InvalidBeneficiary.into());
+        }
+
+        let mut locker_beneficiary: Account<Beneficiary> =
+            Account::try_from(&ctx.accounts.locker_beneficiary)?;
+        if locker_beneficiary.last_update != ouroboros.last_period {
+            return Err(ErrorCode::UnclaimedIncentives.into());
+        }
+        locker_beneficiary.update_votes(ouroboros, locker);
+        locker_beneficiary.exit(ctx.program_id)?;
+    }
+
+    if amount > 0 {
+        let id_seed = ouroboros.id.to_le_bytes();
+        let seeds = &[
+            b"authority".as_ref(),
+            id_seed.as_ref(),
+            &[ouroboros.bumps.authority],
+        ];
+        let signer = &[&seeds[..]];
+        token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), amount)?;
+    }
+
+    emit!(IncentivesCompounded {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        locker: ctx.accounts.locker.key(),
+        amount,
+        votes,
+        weight: ctx.accounts.beneficiary.weight,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!(
+        "Locked {} incentives in locker {}",
+        amount,
+        ctx.accounts.locker.key(),
+    );
+
+    Ok(())
+}
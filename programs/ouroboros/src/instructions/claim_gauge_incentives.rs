@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use gauges::{program::Gauges, state::Gauge};
+use pools::state::Pair;
+
+use crate::{
+    errors::ErrorCode,
+    events::IncentivesClaimed,
+    instructions::claim_incentives::update_incentives,
+    state::{pause, Beneficiary, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct ClaimGaugeIncentives<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the Ouroboros token
+    #[account(
+        mut,
+        address = ouroboros.mint
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The beneficiary of the ouroboros incentives
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump,
+        has_one = account,
+        has_one = gauge @ ErrorCode::GaugeMismatch,
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The rewards account of the gauge
+    #[account(mut)]
+    pub account: Box<Account<'info, TokenAccount>>,
+
+    /// The pair of the gauge
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The gauge streaming incentives
+    #[account(mut)]
+    pub gauge: Box<Account<'info, Gauge>>,
+
+    /// The program of the gauge
+    pub gauges_program: Program<'info, Gauges>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ClaimGaugeIncentives<'info> {
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.mint.to_account_info(),
+                to: self.account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn notify_reward_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, gauges::cpi::accounts::NotifyReward<'info>> {
+        CpiContext::new(
+            self.gauges_program.to_account_info(),
+            gauges::cpi::accounts::NotifyReward {
+                pair: self.pair.to_account_info(),
+                gauge: self.gauge.to_account_info(),
+                mint_rewards: self.mint.to_account_info(),
+                rewards_account: self.account.to_account_info(),
+                notifier: self.authority.to_account_info(),
+                clock: self.clock.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimGaugeIncentives>) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::CLAIMS)?;
+
+    if ctx.accounts.beneficiary.compounding_locker != Pubkey::default() {
+        return Err(ErrorCode::CompoundingEnabled.into());
+    }
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let amount = update_incentives(
+        ouroboros,
+        &mut ctx.accounts.beneficiary,
+        ctx.accounts.mint.supply,
+        ctx.accounts.clock.unix_timestamp,
+    )?;
+
+    if amount > 0 {
+        let id_seed = ouroboros.id.to_le_bytes();
+        let seeds = &[
+            b"authority".as_ref(),
+            id_seed.as_ref(),
+            &[ouroboros.bumps.authority],
+        ];
+        let signer = &[&seeds[..]];
+        token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), amount)?;
+
+        // The gauge checks the account is its rewards account and streams over the next period
+        gauges::cpi::notify_reward(
+            ctx.accounts.notify_reward_context().with_signer(signer),
+            amount,
+            ctx.accounts.ouroboros.period,
+        )?;
+    }
+
+    emit!(IncentivesClaimed {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        account: ctx.accounts.account.key(),
+        amount,
+        weight: ctx.accounts.beneficiary.weight,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!(
+        "Streamed {} incentives through gauge {}",
+        amount,
+        ctx.accounts.gauge.key(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::{GenesisLockerClaimed, LockerCreated},
+    state::{pause, Distributor, Locker, LockerBumps, Ouroboros, Versioned},
+};
+
+#[derive(Accounts)]
+#[instruction(id: Pubkey)]
+pub struct ClaimGenesisLocker<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The distributor
+    #[account(
+        mut,
+        seeds = [
+            b"distributor",
+            ouroboros.id.to_le_bytes().as_ref(),
+            distributor.id.as_ref()
+        ],
+        bump = distributor.bumps.distributor
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    /// The account holding the tokens to distribute
+    #[account(
+        mut,
+        seeds = [
+            b"distributor_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            distributor.id.as_ref()
+        ],
+        bump = distributor.bumps.account
+    )]
+    pub distributor_account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker
+    #[account(
+        init,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account that will hold deposited tokens
+    #[account(
+        init,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The recipient of the allocation
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The receipt NFT used to redeem the locker
+    #[account(
+        init,
+        seeds = [
+            b"receipt",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient,
+        mint::decimals = 0,
+        mint::authority = authority
+    )]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account that will hold the receipt
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = receipt,
+        associated_token::authority = recipient,
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimGenesisLocker<'info> {
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.distributor_account.to_account_info(),
+                to: self.locker_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<ClaimGenesisLocker>,
+    id: Pubkey,
+    index: u64,
+    amount: u64,
+    period: u64,
+    proof: Vec<[u8; 32]>,
+) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::LOCKS)?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let distributor = &mut ctx.accounts.distributor;
+    if now >= distributor.clawback_timestamp {
+        return Err(ErrorCode::DistributionEnded.into());
+    }
+    if index >= distributor.num_leaves {
+        return Err(ErrorCode::InvalidProof.into());
+    }
+    if distributor.is_claimed(index) {
+        return Err(ErrorCode::AlreadyClaimed.into());
+    }
+    // A leaf committed with an invalid lock period could never be withdrawn
+    Locker::validate(amount, period)?;
+
+    let leaf = [
+        &index.to_le_bytes()[..],
+        ctx.accounts.recipient.key().as_ref(),
+        &amount.to_le_bytes(),
+        &period.to_le_bytes(),
+    ]
+    .concat();
+    if !distributor.verify(&leaf, &proof) {
+        return Err(ErrorCode::InvalidProof.into());
+    }
+
+    distributor.set_claimed(index);
+    distributor.claimed_amount += amount;
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.total_votes += amount;
+
+    let locker = &mut ctx.accounts.locker;
+    locker.id = id;
+    locker.receipt = ctx.accounts.receipt.key();
+    locker.beneficiary = Pubkey::default();
+    locker.mint = ouroboros.mint;
+    locker.amount = amount;
+    locker.value = amount;
+    locker.votes = Locker::compute_votes(amount, period, ouroboros.time_multiplier);
+    locker.creation_timestamp = now;
+    locker.unlock_timestamp = now + period as i64;
+    locker.bumps = LockerBumps::find(ouroboros.id, &id, ctx.program_id);
+    locker.version = Locker::VERSION;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+    token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), 1)?;
+
+    emit!(LockerCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        receipt: ctx.accounts.receipt.key(),
+        creator: ctx.accounts.recipient.key(),
+        amount,
+        votes: ctx.accounts.locker.votes,
+        unlock_timestamp: ctx.accounts.locker.unlock_timestamp,
+        soulbound: false,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+    emit!(GenesisLockerClaimed {
+        distributor: ctx.accounts.distributor.key(),
+        locker: ctx.accounts.locker.key(),
+        recipient: ctx.accounts.recipient.key(),
+        index,
+        amount,
+        period,
+    });
+
+    msg!("Genesis locker {} claimed", index);
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 
-use crate::state::{Beneficiary, Ouroboros};
+use crate::{
+    errors::ErrorCode,
+    events::IncentivesClaimed,
+    state::{pause, Beneficiary, BeneficiaryRecipients, Ouroboros},
+};
 
 #[derive(Accounts)]
 pub struct ClaimIncentives<'info> {
@@ -25,11 +29,7 @@ pub struct ClaimIncentives<'info> {
     /// The mint of the Ouroboros token
     #[account(
         mut,
-        seeds = [
-            b"mint",
-            ouroboros.id.to_le_bytes().as_ref()
-        ],
-        bump = ouroboros.bumps.mint
+        address = ouroboros.mint
     )]
     pub mint: Box<Account<'info, Mint>>,
 
@@ -58,54 +58,181 @@ pub struct ClaimIncentives<'info> {
 }
 
 impl<'info> ClaimIncentives<'info> {
-    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+    fn transfer_context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
         CpiContext::new(
             self.token_program.to_account_info(),
             MintTo {
                 mint: self.mint.to_account_info(),
-                to: self.account.to_account_info(),
+                to,
                 authority: self.authority.to_account_info(),
             },
         )
     }
 }
 
-pub fn handler(ctx: Context<ClaimIncentives>) -> ProgramResult {
-    let ouroboros = &mut ctx.accounts.ouroboros;
-    if ouroboros.last_period + ouroboros.period as i64 > ctx.accounts.clock.unix_timestamp {
+/// Ends the current period if needed
+pub fn end_period(ouroboros: &mut Ouroboros, now: i64) {
+    if ouroboros.last_period + ouroboros.period as i64 <= now {
         msg!(
             "Ending ouroboros period [{}, {}[",
             ouroboros.last_period,
             ouroboros.last_period + ouroboros.period as i64
         );
         ouroboros.last_period += ouroboros.period as i64;
-        ouroboros.last_period_votes = ouroboros.total_votes;
+        ouroboros.last_period_votes = ouroboros.period_votes();
+        ouroboros.redistributed_emissions = ouroboros.forfeited_emissions;
+        ouroboros.forfeited_emissions = 0;
     }
+}
 
-    let beneficiary = &mut ctx.accounts.beneficiary;
+/// Computes the incentives owed to a beneficiary for the last ended period.
+/// Returns 0 if the beneficiary already claimed this period.
+pub fn beneficiary_incentives(
+    ouroboros: &mut Ouroboros,
+    beneficiary: &mut Beneficiary,
+    supply: u64,
+) -> u64 {
     let mut amount: u64 = 0;
     if beneficiary.last_update < ouroboros.last_period {
+        // Votes cast before the ended period were active during it
+        beneficiary.activate_votes(ouroboros.last_period - ouroboros.period as i64);
+
+        let weight = (10000 * beneficiary.votes)
+            .checked_div(ouroboros.last_period_votes)
+            .unwrap_or(0) as u16;
         beneficiary.last_update = ouroboros.last_period;
-        beneficiary.weight = (10000 * beneficiary.votes / ouroboros.last_period_votes) as u16;
+        beneficiary.weight = ouroboros.caps.capped_weight(weight);
 
-        let total_emissions =
-            (ctx.accounts.mint.supply - ouroboros.total_votes) * ouroboros.expansion_factor / 10000;
+        // Tokens burned during the period are added back so every claim uses the same supply
+        let supply = supply + ouroboros.period_burned();
+        let total_emissions = (supply - ouroboros.total_votes) * ouroboros.expansion_factor
+            / 10000
+            + ouroboros.redistributed_emissions;
         amount = total_emissions * beneficiary.weight as u64 / 10000;
 
-        let id_seed = ouroboros.id.to_le_bytes();
-        let seeds = &[
-            b"authority".as_ref(),
-            id_seed.as_ref(),
-            &[ouroboros.bumps.authority],
-        ];
-        let signer = &[&seeds[..]];
-        token::mint_to(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+        if ouroboros.caps.redistribute {
+            ouroboros.forfeited_emissions += total_emissions * weight as u64 / 10000 - amount;
+        }
+
+        beneficiary.activate_votes(ouroboros.last_period);
     }
-    msg!(
-        "Sent {} incentives to {}",
+
+    amount
+}
+
+/// Ends the current period if needed and computes the incentives owed to a beneficiary.
+/// Fails if the beneficiary already claimed the last ended period.
+pub fn update_incentives(
+    ouroboros: &mut Ouroboros,
+    beneficiary: &mut Beneficiary,
+    supply: u64,
+    now: i64,
+) -> Result<u64, ProgramError> {
+    end_period(ouroboros, now);
+    if beneficiary.last_update == ouroboros.last_period {
+        return Err(ErrorCode::EpochNotFinished.into());
+    }
+
+    Ok(beneficiary_incentives(ouroboros, beneficiary, supply))
+}
+
+/// Emits the payout of `amount` incentives of the beneficiary to `account`
+fn emit_claim(accounts: &ClaimIncentives, account: &AccountInfo, amount: u64) {
+    emit!(IncentivesClaimed {
+        ouroboros: accounts.ouroboros.key(),
+        beneficiary: accounts.beneficiary.key(),
+        account: account.key(),
         amount,
-        ctx.accounts.account.key(),
-    );
+        weight: accounts.beneficiary.weight,
+        epoch: accounts.ouroboros.last_period,
+    });
+
+    msg!("Sent {} incentives to {}", amount, account.key());
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimIncentives<'info>>) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::CLAIMS)?;
+
+    if ctx.accounts.beneficiary.compounding_locker != Pubkey::default() {
+        return Err(ErrorCode::CompoundingEnabled.into());
+    }
+    if ctx.accounts.beneficiary.gauge != Pubkey::default() {
+        return Err(ErrorCode::GaugeBound.into());
+    }
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let amount = update_incentives(
+        ouroboros,
+        &mut ctx.accounts.beneficiary,
+        ctx.accounts.mint.supply,
+        ctx.accounts.clock.unix_timestamp,
+    )?;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    if amount == 0 || ctx.accounts.beneficiary.recipients == Pubkey::default() {
+        let account = ctx.accounts.account.to_account_info();
+        if amount > 0 {
+            token::mint_to(
+                ctx.accounts.transfer_context(account.clone()).with_signer(signer),
+                amount,
+            )?;
+        }
+        emit_claim(ctx.accounts, &account, amount);
+    } else {
+        // The recipients table is followed by the accounts it lists, in order
+        let (table, accounts) = ctx
+            .remaining_accounts
+            .split_first()
+            .ok_or(ErrorCode::InvalidRecipients)?;
+        if table.key() != ctx.accounts.beneficiary.recipients {
+            return Err(ErrorCode::InvalidRecipients.into());
+        }
+        let table: Account<BeneficiaryRecipients> = Account::try_from(table)?;
+        if !BeneficiaryRecipients::is_complete(&table.recipients) {
+            return Err(ErrorCode::InvalidShares.into());
+        }
+        if table.recipients.len() != accounts.len() {
+            return Err(ErrorCode::InvalidRecipients.into());
+        }
+
+        let mut remaining = amount;
+        let last = table.recipients.len() - 1;
+        for (i, (recipient, account)) in table.recipients.iter().zip(accounts).enumerate() {
+            if recipient.account != account.key() {
+                return Err(ErrorCode::InvalidRecipients.into());
+            }
+            // The last recipient receives the rounding remainder
+            let share = if i == last {
+                remaining
+            } else {
+                ((amount as u128)
+                    .checked_mul(recipient.share as u128)
+                    .ok_or(ErrorCode::MathOverflow)?
+                    / 10000) as u64
+            };
+            remaining = remaining
+                .checked_sub(share)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            token::mint_to(
+                ctx.accounts
+                    .transfer_context(account.clone())
+                    .with_signer(signer),
+                share,
+            )?;
+            emit_claim(ctx.accounts, account, share);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::DistributorClawedBack,
+    state::{Distributor, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct Clawback<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The distributor
+    #[account(
+        seeds = [
+            b"distributor",
+            ouroboros.id.to_le_bytes().as_ref(),
+            distributor.id.as_ref()
+        ],
+        bump = distributor.bumps.distributor,
+        has_one = admin
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    /// The account holding the tokens to distribute
+    #[account(
+        mut,
+        seeds = [
+            b"distributor_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            distributor.id.as_ref()
+        ],
+        bump = distributor.bumps.account
+    )]
+    pub distributor_account: Box<Account<'info, TokenAccount>>,
+
+    /// The admin of the distributor
+    pub admin: Signer<'info>,
+
+    /// The account receiving unclaimed tokens
+    #[account(mut)]
+    pub admin_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> Clawback<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.distributor_account.to_account_info(),
+                to: self.admin_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<Clawback>) -> ProgramResult {
+    if ctx.accounts.clock.unix_timestamp < ctx.accounts.distributor.clawback_timestamp {
+        return Err(ErrorCode::ClawbackNotStarted.into());
+    }
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    let amount = ctx.accounts.distributor_account.amount;
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+
+    emit!(DistributorClawedBack {
+        distributor: ctx.accounts.distributor.key(),
+        admin: ctx.accounts.admin.key(),
+        amount,
+    });
+
+    msg!("Clawed back {} tokens", amount);
+
+    Ok(())
+}
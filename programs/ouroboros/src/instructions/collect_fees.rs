@@ -4,10 +4,13 @@ use anchor_spl::{
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
 
-use crate::state::{Asset, Claimant, Locker, Ouroboros, Snapshot};
+use crate::{
+    errors::ErrorCode,
+    events::FeesCollected,
+    state::{pause, Asset, Claimant, Locker, Ouroboros, Snapshot, Versioned},
+};
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct CollectFees<'info> {
     /// The Ouroboros
     #[account(
@@ -72,6 +75,7 @@ pub struct CollectFees<'info> {
         constraint = 
             previous_snapshot.timestamp >= locker.creation_timestamp 
             || previous_snapshot.timestamp <= claimant.last_claim
+            @ ErrorCode::InvalidSnapshot
     )]
     pub previous_snapshot: Box<Account<'info, Snapshot>>,
 
@@ -90,6 +94,7 @@ pub struct CollectFees<'info> {
         constraint = 
             current_snapshot.timestamp > claimant.last_claim
             && current_snapshot.index == previous_snapshot.index + 1
+            @ ErrorCode::InvalidSnapshot
     )]
     pub current_snapshot: Box<Account<'info, Snapshot>>,
 
@@ -132,7 +137,7 @@ pub struct CollectFees<'info> {
             asset.mint.as_ref(),
             locker.key().as_ref()
         ],
-        bump = bump
+        bump
     )]
     pub claimant: Box<Account<'info, Claimant>>,
 
@@ -161,7 +166,9 @@ impl<'info> CollectFees<'info> {
     }
 }
 
-pub fn handler(ctx: Context<CollectFees>, bump: u8) -> ProgramResult {
+pub fn handler(ctx: Context<CollectFees>) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::CLAIMS)?;
+
     let ouroboros = &ctx.accounts.ouroboros;
     let claimant = &mut ctx.accounts.claimant;
 
@@ -173,11 +180,25 @@ pub fn handler(ctx: Context<CollectFees>, bump: u8) -> ProgramResult {
         claimant.owner = ctx.accounts.locker.key();
         claimant.mint = ctx.accounts.mint.key();
         claimant.last_claim = previous_snapshot.timestamp;
-        claimant.bump = bump;
+        claimant.bump = Pubkey::find_program_address(
+            &[
+                b"claimant",
+                ouroboros.id.to_le_bytes().as_ref(),
+                claimant.mint.as_ref(),
+                claimant.owner.as_ref(),
+            ],
+            ctx.program_id,
+        )
+        .1;
+        claimant.version = Claimant::VERSION;
     }
 
-    let collectible_rewards = previous_snapshot.rewards * 10_u64.pow(9) * 
-        (current_snapshot.timestamp - claimant.last_claim) as u64 / ouroboros.period / previous_snapshot.rewards;
+    // Nothing is collectible from a snapshot without rewards
+    let collectible_rewards = (previous_snapshot.rewards * 10_u64.pow(9)
+        * (current_snapshot.timestamp - claimant.last_claim) as u64
+        / ouroboros.period)
+        .checked_div(previous_snapshot.rewards)
+        .unwrap_or(0);
 
     claimant.last_claim = {
         if ctx.accounts.clock.unix_timestamp < current_snapshot.timestamp {
@@ -199,6 +220,16 @@ pub fn handler(ctx: Context<CollectFees>, bump: u8) -> ProgramResult {
     let signer = &[&seeds[..]];
     token::transfer(ctx.accounts.transfer_context().with_signer(signer), collectible_rewards)?;
 
+    emit!(FeesCollected {
+        ouroboros: ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        mint: ctx.accounts.mint.key(),
+        holder_account: ctx.accounts.holder_account.key(),
+        amount: collectible_rewards,
+        snapshot_index: ctx.accounts.current_snapshot.index,
+        epoch: ouroboros.last_period,
+    });
+
     msg!(
         "Sent {} fees of {} to {}",
         collectible_rewards,
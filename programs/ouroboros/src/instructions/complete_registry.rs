@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::RegistryCompleted,
+    state::{flags, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct CompleteRegistry<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CompleteRegistry>) -> ProgramResult {
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.flags |= flags::REGISTRY_COMPLETE;
+
+    emit!(RegistryCompleted {
+        ouroboros: ouroboros.key(),
+        num_beneficiaries: ouroboros.num_beneficiaries,
+    });
+
+    msg!("Registry completed with {} beneficiaries", ouroboros.num_beneficiaries);
+
+    Ok(())
+}
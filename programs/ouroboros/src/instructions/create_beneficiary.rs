@@ -1,12 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Beneficiary, Ouroboros};
+use crate::{
+    events::BeneficiaryCreated,
+    state::{Beneficiary, BeneficiaryRegistry, Ouroboros, Versioned},
+};
 
 #[derive(Accounts)]
-#[instruction(bump: u8, account: Pubkey)]
+#[instruction(account: Pubkey)]
 pub struct CreateBeneficiary<'info> {
     /// The Ouroboros
     #[account(
+        mut,
         seeds = [
             b"ouroboros",
             ouroboros.id.to_le_bytes().as_ref()
@@ -23,11 +27,25 @@ pub struct CreateBeneficiary<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             account.as_ref()
         ],
-        bump = bump,
+        bump,
         payer = creator
     )]
     pub beneficiary: Box<Account<'info, Beneficiary>>,
 
+    /// The last page of the registry of beneficiaries
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"registry",
+            ouroboros.id.to_le_bytes().as_ref(),
+            (ouroboros.num_beneficiaries / BeneficiaryRegistry::CAPACITY).to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = creator,
+        space = BeneficiaryRegistry::space()
+    )]
+    pub registry: Box<Account<'info, BeneficiaryRegistry>>,
+
     /// The wallet creating the beneficiary
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -36,11 +54,34 @@ pub struct CreateBeneficiary<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateBeneficiary>, bump: u8, account: Pubkey) -> ProgramResult {
+pub fn handler(ctx: Context<CreateBeneficiary>, account: Pubkey) -> ProgramResult {
     let beneficiary = &mut ctx.accounts.beneficiary;
     beneficiary.account = account;
     beneficiary.last_update = ctx.accounts.ouroboros.last_period;
-    beneficiary.bump = bump;
+    beneficiary.creator = ctx.accounts.creator.key();
+    beneficiary.bump = Pubkey::find_program_address(
+        &[
+            b"beneficiary",
+            ctx.accounts.ouroboros.id.to_le_bytes().as_ref(),
+            account.as_ref(),
+        ],
+        ctx.program_id,
+    )
+    .1;
+    beneficiary.version = Beneficiary::VERSION;
+
+    beneficiary.registry_slot = ctx.accounts.registry.register(
+        &mut ctx.accounts.ouroboros,
+        beneficiary.key(),
+        ctx.program_id,
+    );
+
+    emit!(BeneficiaryCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: beneficiary.key(),
+        account,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
 
     msg!("Beneficiary created");
 
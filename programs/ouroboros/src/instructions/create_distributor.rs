@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::DistributorCreated,
+    state::{Distributor, DistributorBumps, Ouroboros},
+};
+
+#[derive(Accounts)]
+#[instruction(id: Pubkey, root: [u8; 32], num_leaves: u64)]
+pub struct CreateDistributor<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The distributor
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            b"distributor",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        space = Distributor::space(num_leaves)
+    )]
+    pub distributor: Box<Account<'info, Distributor>>,
+
+    /// The account holding the tokens to distribute
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            b"distributor_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub distributor_account: Box<Account<'info, TokenAccount>>,
+
+    /// The admin of the ouroboros, funding the distributor
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The account funding the distributor
+    #[account(mut, constraint = admin_account.owner == admin.key())]
+    pub admin_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateDistributor<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.admin_account.to_account_info(),
+                to: self.distributor_account.to_account_info(),
+                authority: self.admin.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<CreateDistributor>,
+    id: Pubkey,
+    root: [u8; 32],
+    num_leaves: u64,
+    total_amount: u64,
+    clawback_timestamp: i64,
+) -> ProgramResult {
+    if num_leaves == 0 || total_amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+    if clawback_timestamp <= Clock::get()?.unix_timestamp {
+        return Err(ErrorCode::InvalidClawback.into());
+    }
+
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.id = id;
+    distributor.admin = ctx.accounts.admin.key();
+    distributor.root = root;
+    distributor.num_leaves = num_leaves;
+    distributor.total_amount = total_amount;
+    distributor.clawback_timestamp = clawback_timestamp;
+    distributor.bumps = DistributorBumps::find(ctx.accounts.ouroboros.id, &id, ctx.program_id);
+    distributor.claimed = vec![0; Distributor::bitmap_len(num_leaves)];
+
+    token::transfer(ctx.accounts.transfer_context(), total_amount)?;
+
+    emit!(DistributorCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        distributor: ctx.accounts.distributor.key(),
+        root,
+        num_leaves,
+        total_amount,
+        clawback_timestamp,
+    });
+
+    msg!("Distributor created with {} tokens", total_amount);
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, Token, TokenAccount, Transfer};
 
-use crate::state::{LockerBumps, Locker, Ouroboros};
+use crate::{
+    events::LockerCreated,
+    state::{pause, LockerBumps, Locker, Ouroboros, Versioned},
+};
 
 #[derive(Accounts)]
-#[instruction(bumps: LockerBumps, id: Pubkey)]
+#[instruction(id: Pubkey)]
 pub struct CreateLocker<'info> {
     /// The Ouroboros
     #[account(
@@ -27,11 +30,7 @@ pub struct CreateLocker<'info> {
     /// The mint of the native token
     #[account(
         mut,
-        seeds = [
-            b"mint",
-            ouroboros.id.to_le_bytes().as_ref()
-        ],
-        bump = ouroboros.bumps.mint
+        address = ouroboros.mint
     )]
     pub mint: AccountInfo<'info>,
 
@@ -43,7 +42,7 @@ pub struct CreateLocker<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             id.as_ref()
         ],
-        bump = bumps.locker,
+        bump,
         payer = creator
     )]
     pub locker: Box<Account<'info, Locker>>,
@@ -56,7 +55,7 @@ pub struct CreateLocker<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             id.as_ref()
         ],
-        bump = bumps.account,
+        bump,
         payer = creator,
         token::mint = mint,
         token::authority = authority
@@ -84,10 +83,11 @@ pub struct CreateLocker<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             id.as_ref()
         ],
-        bump = bumps.receipt,
+        bump,
         payer = creator,
         mint::decimals = 0,
-        mint::authority = authority
+        mint::authority = authority,
+        mint::freeze_authority = authority
     )]
     pub receipt: Box<Account<'info, Mint>>,
 
@@ -125,6 +125,17 @@ impl<'info> CreateLocker<'info> {
         )
     }
 
+    fn freeze_context(&self) -> CpiContext<'_, '_, '_, 'info, FreezeAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            FreezeAccount {
+                account: self.receipt_account.to_account_info(),
+                mint: self.receipt.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
     fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         CpiContext::new(
             self.token_program.to_account_info(),
@@ -139,11 +150,14 @@ impl<'info> CreateLocker<'info> {
 
 pub fn handler(
     ctx: Context<CreateLocker>,
-    bumps: LockerBumps,
     id: Pubkey,
     amount: u64,
     period: u64,
+    soulbound: bool,
 ) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::LOCKS)?;
+    Locker::validate(amount, period)?;
+
     let ouroboros = &mut ctx.accounts.ouroboros;
     ouroboros.total_votes += amount;
 
@@ -151,11 +165,15 @@ pub fn handler(
     locker.id = id;
     locker.receipt = ctx.accounts.receipt.key();
     locker.beneficiary = Pubkey::default();
+    locker.mint = ctx.accounts.mint.key();
     locker.amount = amount;
-    locker.votes = amount * period * ouroboros.time_multiplier / 604800 / 10000;
+    locker.value = amount;
+    locker.votes = Locker::compute_votes(amount, period, ouroboros.time_multiplier);
     locker.creation_timestamp = ctx.accounts.clock.unix_timestamp;
     locker.unlock_timestamp = ctx.accounts.clock.unix_timestamp + period as i64;
-    locker.bumps = bumps;
+    locker.soulbound = soulbound;
+    locker.bumps = LockerBumps::find(ouroboros.id, &id, ctx.program_id);
+    locker.version = Locker::VERSION;
 
     let id_seed = ouroboros.id.to_le_bytes();
     let seeds = &[
@@ -167,6 +185,21 @@ pub fn handler(
 
     token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
     token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), 1)?;
+    if soulbound {
+        token::freeze_account(ctx.accounts.freeze_context().with_signer(signer))?;
+    }
+
+    emit!(LockerCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        receipt: ctx.accounts.receipt.key(),
+        creator: ctx.accounts.creator.key(),
+        amount,
+        votes: ctx.accounts.locker.votes,
+        unlock_timestamp: ctx.accounts.locker.unlock_timestamp,
+        soulbound,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
 
     msg!("Locker created");
 
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use pools::state::Pair;
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerCreated,
+    state::{pause, Locker, LockerBumps, Ouroboros, Versioned},
+};
+
+#[derive(Accounts)]
+#[instruction(id: Pubkey)]
+pub struct CreateLpLocker<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        constraint = ouroboros.native_pair == pair.key() @ ErrorCode::InvalidPair
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The pair of the native token
+    #[account(has_one = pair_mint)]
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The mint of the LP tokens of the pair
+    pub pair_mint: Box<Account<'info, Mint>>,
+
+    /// The account of the pair holding tokens A
+    pub pair_account_a: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the pair holding tokens B
+    pub pair_account_b: Box<Account<'info, TokenAccount>>,
+
+    /// The locker
+    #[account(
+        init,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = creator
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account that will hold deposited LP tokens
+    #[account(
+        init,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = creator,
+        token::mint = pair_mint,
+        token::authority = authority
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The owner of the LP tokens
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The account holding the LP tokens of the creator
+    #[account(mut)]
+    pub creator_account: Box<Account<'info, TokenAccount>>,
+
+    /// The receipt NFT used to redeem the locker
+    #[account(
+        init,
+        seeds = [
+            b"receipt",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = creator,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority
+    )]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account that will hold the receipt
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = receipt,
+        associated_token::authority = creator,
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateLpLocker<'info> {
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.creator_account.to_account_info(),
+                to: self.locker_account.to_account_info(),
+                authority: self.creator.to_account_info(),
+            },
+        )
+    }
+
+    /// Checks that the given accounts hold the reserves of the pair
+    fn has_reserves(&self) -> bool {
+        self.pair.reserve_accounts() == (self.pair_account_a.key(), self.pair_account_b.key())
+    }
+}
+
+pub fn handler(
+    ctx: Context<CreateLpLocker>,
+    id: Pubkey,
+    amount: u64,
+    period: u64,
+) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::LOCKS)?;
+    Locker::validate(amount, period)?;
+    if !ctx.accounts.has_reserves() {
+        return Err(ErrorCode::InvalidPair.into());
+    }
+
+    // Votes are granted for the native value of the LP tokens at lock time,
+    // priced from the TWAP of the pair so a swap in the same transaction can't inflate it
+    let now = ctx.accounts.clock.unix_timestamp;
+    let pair = &ctx.accounts.pair;
+    let reserve_a = ctx.accounts.pair_account_a.amount;
+    let reserve_b = ctx.accounts.pair_account_b.amount;
+    let (price_a_cumulative, price_b_cumulative) = pair.cumulative_prices(reserve_a, reserve_b, now);
+    let (native_reserve, other_reserve, price_cumulative) =
+        if pair.mint_a == ctx.accounts.ouroboros.mint {
+            (reserve_a, reserve_b, price_b_cumulative)
+        } else {
+            (reserve_b, reserve_a, price_a_cumulative)
+        };
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let twap = ouroboros.native_twap(price_cumulative, now)?;
+    let value = Locker::lp_value(
+        amount,
+        native_reserve,
+        other_reserve,
+        twap,
+        ctx.accounts.pair_mint.supply,
+    )?;
+    // LP value is not part of the native supply, so it never reduces emissions
+    ouroboros.lp_votes += value;
+
+    let locker = &mut ctx.accounts.locker;
+    locker.id = id;
+    locker.receipt = ctx.accounts.receipt.key();
+    locker.beneficiary = Pubkey::default();
+    locker.mint = ctx.accounts.pair_mint.key();
+    locker.amount = amount;
+    locker.value = value;
+    locker.votes = Locker::compute_votes(value, period, ouroboros.time_multiplier);
+    locker.creation_timestamp = now;
+    locker.unlock_timestamp = now + period as i64;
+    locker.bumps = LockerBumps::find(ouroboros.id, &id, ctx.program_id);
+    locker.version = Locker::VERSION;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context(), amount)?;
+    token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), 1)?;
+
+    emit!(LockerCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        receipt: ctx.accounts.receipt.key(),
+        creator: ctx.accounts.creator.key(),
+        amount,
+        votes: ctx.accounts.locker.votes,
+        unlock_timestamp: ctx.accounts.locker.unlock_timestamp,
+        soulbound: false,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!("LP locker created with a value of {}", value);
+
+    Ok(())
+}
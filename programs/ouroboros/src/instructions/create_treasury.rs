@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::TreasuryCreated,
+    state::{Ouroboros, Treasury},
+};
+
+#[derive(Accounts)]
+pub struct CreateTreasury<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The treasury
+    #[account(
+        init,
+        seeds = [
+            b"treasury",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = admin
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// The admin of the ouroboros
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateTreasury>) -> ProgramResult {
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.ouroboros = ctx.accounts.ouroboros.key();
+    treasury.bump = Pubkey::find_program_address(
+        &[
+            b"treasury",
+            ctx.accounts.ouroboros.id.to_le_bytes().as_ref(),
+        ],
+        ctx.program_id,
+    )
+    .1;
+
+    // Protocol fees now flow to accounts owned by the treasury
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.treasury = treasury.key();
+
+    emit!(TreasuryCreated {
+        ouroboros: ouroboros.key(),
+        treasury: treasury.key(),
+    });
+
+    msg!("Treasury {} created", treasury.key());
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::VestingCreated,
+    state::{Ouroboros, Vesting, VestingBumps, VestingSchedule},
+};
+
+#[derive(Accounts)]
+#[instruction(id: Pubkey)]
+pub struct CreateVesting<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The vesting
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The account holding the vesting tokens
+    #[account(
+        init,
+        payer = admin,
+        seeds = [
+            b"vesting_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub vesting_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet funding the vesting
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The account funding the vesting
+    #[account(mut, constraint = admin_account.owner == admin.key())]
+    pub admin_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateVesting<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.admin_account.to_account_info(),
+                to: self.vesting_account.to_account_info(),
+                authority: self.admin.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<CreateVesting>,
+    id: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+    schedule: VestingSchedule,
+) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+    if !schedule.is_valid() {
+        return Err(ErrorCode::InvalidSchedule.into());
+    }
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.id = id;
+    vesting.admin = ctx.accounts.admin.key();
+    vesting.recipient = recipient;
+    vesting.total_amount = amount;
+    vesting.schedule = schedule.clone();
+    vesting.bumps = VestingBumps::find(ctx.accounts.ouroboros.id, &id, ctx.program_id);
+
+    token::transfer(ctx.accounts.transfer_context(), amount)?;
+
+    emit!(VestingCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        vesting: ctx.accounts.vesting.key(),
+        recipient,
+        amount,
+        start_timestamp: schedule.start_timestamp,
+        cliff_timestamp: schedule.cliff_timestamp,
+        end_timestamp: schedule.end_timestamp,
+        revocable: schedule.revocable,
+    });
+
+    msg!("Vesting created with {} tokens for {}", amount, recipient);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::{
+    errors::ErrorCode,
+    events::{IncentivesClaimed, IncentivesDistributed},
+    instructions::claim_incentives::{beneficiary_incentives, end_period},
+    state::{flags, pause, Beneficiary, BeneficiaryRegistry, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the Ouroboros token
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The page of the registry listing the beneficiaries distributed
+    #[account(
+        seeds = [
+            b"registry",
+            ouroboros.id.to_le_bytes().as_ref(),
+            registry.index.to_le_bytes().as_ref()
+        ],
+        bump = registry.bump
+    )]
+    pub registry: Box<Account<'info, BeneficiaryRegistry>>,
+
+    /// The wallet running the crank
+    pub keeper: Signer<'info>,
+
+    /// The account receiving the keeper reward
+    #[account(
+        mut,
+        constraint = keeper_account.mint == mint.key()
+    )]
+    pub keeper_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> Distribute<'info> {
+    fn mint_context(&self, to: AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.mint.to_account_info(),
+                to,
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    /// Checks that an account can receive native tokens
+    fn is_payable(&self, account: &AccountInfo<'info>) -> bool {
+        Account::<TokenAccount>::try_from(account)
+            .map(|account| account.mint == self.mint.key())
+            .unwrap_or(false)
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Distribute<'info>>) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::CLAIMS)?;
+    // Beneficiaries missing from the registry would never be paid by the crank
+    if ctx.accounts.ouroboros.flags & flags::REGISTRY_COMPLETE == 0 {
+        return Err(ErrorCode::IncompleteRegistry.into());
+    }
+
+    // The crank pays at least one beneficiary, so it can't be used only to end periods
+    let count = ctx.remaining_accounts.len() / 2;
+    if count == 0 {
+        return Err(ErrorCode::InvalidDistribution.into());
+    }
+
+    // A distribution in progress is finished before the crank ends the next period
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    if ouroboros.distribution_epoch != ouroboros.last_period
+        || ouroboros.distribution_cursor == 0
+        || ouroboros.distribution_cursor >= ouroboros.num_beneficiaries
+    {
+        end_period(ouroboros, ctx.accounts.clock.unix_timestamp);
+    }
+    if ouroboros.distribution_epoch != ouroboros.last_period {
+        ouroboros.distribution_epoch = ouroboros.last_period;
+        ouroboros.distribution_cursor = 0;
+    }
+
+    // Beneficiaries are passed with their account, starting from the cursor
+    let registry = &ctx.accounts.registry;
+    let start = (ouroboros.distribution_cursor % BeneficiaryRegistry::CAPACITY) as usize;
+    if registry.index != ouroboros.distribution_cursor / BeneficiaryRegistry::CAPACITY
+        || count * 2 != ctx.remaining_accounts.len()
+        || start + count > registry.beneficiaries.len()
+    {
+        return Err(ErrorCode::InvalidDistribution.into());
+    }
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+    let supply = ctx.accounts.mint.supply;
+
+    let mut total: u64 = 0;
+    for (i, accounts) in ctx.remaining_accounts.chunks(2).enumerate() {
+        let (beneficiary_info, account) = (&accounts[0], &accounts[1]);
+        if beneficiary_info.key() != registry.beneficiaries[start + i] {
+            return Err(ErrorCode::InvalidDistribution.into());
+        }
+        let mut beneficiary: Account<Beneficiary> = Account::try_from(beneficiary_info)?;
+        if account.key() != beneficiary.account {
+            return Err(ErrorCode::InvalidDistribution.into());
+        }
+
+        // Beneficiaries paid through another route, or unable to receive tokens, claim by themselves
+        if beneficiary.compounding_locker != Pubkey::default()
+            || beneficiary.gauge != Pubkey::default()
+            || beneficiary.recipients != Pubkey::default()
+            || !ctx.accounts.is_payable(account)
+        {
+            continue;
+        }
+
+        let amount = beneficiary_incentives(&mut ctx.accounts.ouroboros, &mut beneficiary, supply);
+        beneficiary.exit(ctx.program_id)?;
+        if amount > 0 {
+            token::mint_to(
+                ctx.accounts
+                    .mint_context(account.clone())
+                    .with_signer(signer),
+                amount,
+            )?;
+            total += amount;
+        }
+
+        emit!(IncentivesClaimed {
+            ouroboros: ctx.accounts.ouroboros.key(),
+            beneficiary: beneficiary.key(),
+            account: account.key(),
+            amount,
+            weight: beneficiary.weight,
+            epoch: ctx.accounts.ouroboros.last_period,
+        });
+    }
+    ctx.accounts.ouroboros.distribution_cursor += count as u64;
+
+    let keeper_reward = total * ctx.accounts.ouroboros.keeper_reward as u64 / 10000;
+    if keeper_reward > 0 {
+        let keeper_account = ctx.accounts.keeper_account.to_account_info();
+        token::mint_to(
+            ctx.accounts
+                .mint_context(keeper_account)
+                .with_signer(signer),
+            keeper_reward,
+        )?;
+    }
+
+    emit!(IncentivesDistributed {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiaries: count as u64,
+        amount: total,
+        keeper: ctx.accounts.keeper.key(),
+        keeper_reward,
+        cursor: ctx.accounts.ouroboros.distribution_cursor,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
+    msg!(
+        "Distributed {} incentives to {} beneficiaries",
+        total,
+        count
+    );
+
+    Ok(())
+}
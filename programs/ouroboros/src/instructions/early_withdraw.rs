@@ -0,0 +1,308 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Burn, CloseAccount, Mint, ThawAccount, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerWithdrawn,
+    instructions::withdraw::remove_votes,
+    state::{Asset, AssetBumps, Beneficiary, Locker, Ouroboros, Snapshot, Versioned},
+};
+
+#[derive(Accounts)]
+#[instruction(snapshot_index: u64)]
+pub struct EarlyWithdraw<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The locker being withdrawn
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker,
+        has_one = receipt,
+        close = owner
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account holding locked tokens
+    #[account(
+        mut,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.account
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The beneficiary the locker voted for
+    /// Can be any beneficiary if the locker never voted
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The receipt of the locker
+    #[account(mut)]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account holding the locker receipt
+    #[account(
+        mut,
+        constraint =
+            receipt_account.owner == owner.key() &&
+            receipt_account.mint == receipt.key() &&
+            receipt_account.amount == 1
+            @ ErrorCode::InvalidReceipt
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The asset of the native token
+    /// Created by the first penalty if no native tokens were received yet
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            b"asset",
+            ouroboros.id.to_le_bytes().as_ref(),
+            ouroboros.mint.as_ref()
+        ],
+        bump,
+        constraint = asset.last_snapshot_index == snapshot_index @ ErrorCode::InvalidSnapshot
+    )]
+    pub asset: Box<Account<'info, Asset>>,
+
+    /// The authority of the native asset
+    #[account(
+        seeds = [
+            b"asset_authority",
+            ouroboros.id.to_le_bytes().as_ref(),
+            ouroboros.mint.as_ref()
+        ],
+        bump = asset.authority_bump(ouroboros.id, &ouroboros.mint)
+    )]
+    pub asset_authority: AccountInfo<'info>,
+
+    /// The snapshot the penalty is added to
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            b"snapshot",
+            ouroboros.id.to_le_bytes().as_ref(),
+            ouroboros.mint.as_ref(),
+            snapshot_index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub current_snapshot: Box<Account<'info, Snapshot>>,
+
+    /// The account that stores the native token fees
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            b"asset_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            ouroboros.mint.as_ref()
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = asset_authority,
+    )]
+    pub asset_account: Box<Account<'info, TokenAccount>>,
+
+    /// The holder of the receipt
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The account receiving the unlocked tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EarlyWithdraw<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.locker_account.to_account_info(),
+                to: self.owner_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_penalty_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.locker_account.to_account_info(),
+                to: self.asset_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.locker_account.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn thaw_context(&self) -> CpiContext<'_, '_, '_, 'info, ThawAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            ThawAccount {
+                account: self.receipt_account.to_account_info(),
+                mint: self.receipt.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<EarlyWithdraw>, snapshot_index: u64) -> ProgramResult {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let locker = &ctx.accounts.locker;
+    // The penalty is paid in native tokens
+    if locker.mint != ctx.accounts.ouroboros.mint {
+        return Err(ErrorCode::InvalidCollateral.into());
+    }
+    let penalty =
+        locker.early_withdraw_penalty(ctx.accounts.ouroboros.early_withdraw_penalty, now);
+
+    remove_votes(
+        &mut ctx.accounts.ouroboros,
+        locker,
+        &mut ctx.accounts.beneficiary,
+    )?;
+
+    // The penalty is distributed to remaining lockers like any other asset
+    let ouroboros = &ctx.accounts.ouroboros;
+    let asset = &mut ctx.accounts.asset;
+    // Uninitialized asset
+    if asset.mint != ouroboros.mint {
+        asset.mint = ouroboros.mint;
+        asset.authority = ctx.accounts.asset_authority.key();
+        asset.last_update = ouroboros.last_period;
+        asset.bumps = AssetBumps::find(ouroboros.id, &asset.mint, ctx.program_id);
+        asset.version = Asset::VERSION;
+    }
+    ctx.accounts.asset.add_rewards(
+        ouroboros,
+        &mut ctx.accounts.current_snapshot,
+        snapshot_index,
+        penalty,
+        now,
+    )?;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    if penalty > 0 {
+        token::transfer(
+            ctx.accounts.transfer_penalty_context().with_signer(signer),
+            penalty,
+        )?;
+    }
+    token::transfer(
+        ctx.accounts.transfer_context().with_signer(signer),
+        locker.amount - penalty,
+    )?;
+    token::close_account(ctx.accounts.close_context().with_signer(signer))?;
+    // Soulbound receipts are frozen and must be thawed to be burnt
+    if locker.soulbound {
+        token::thaw_account(ctx.accounts.thaw_context().with_signer(signer))?;
+    }
+    token::burn(ctx.accounts.burn_context(), 1)?;
+
+    emit!(LockerWithdrawn {
+        ouroboros: ouroboros.key(),
+        locker: locker.key(),
+        owner: ctx.accounts.owner.key(),
+        amount: locker.amount - penalty,
+        penalty,
+        votes: locker.votes,
+        epoch: ouroboros.last_period,
+    });
+
+    msg!(
+        "Withdrew {} tokens from locker {} with a penalty of {}",
+        locker.amount - penalty,
+        locker.key(),
+        penalty
+    );
+
+    Ok(())
+}
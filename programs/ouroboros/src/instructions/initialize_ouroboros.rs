@@ -4,10 +4,13 @@ use anchor_spl::{
     token::{self, Mint, MintTo, Token, TokenAccount},
 };
 
-use crate::state::{OuroborosBumps, Ouroboros};
+use crate::{
+    events::OuroborosInitialized,
+    state::{flags, EmissionCaps, OuroborosBumps, Ouroboros, Versioned},
+};
 
 #[derive(Accounts)]
-#[instruction(bumps: OuroborosBumps, ouroboros_id: u64)]
+#[instruction(ouroboros_id: u64)]
 pub struct InitializeOuroboros<'info> {
     /// The Ouroboros
     #[account(
@@ -17,7 +20,7 @@ pub struct InitializeOuroboros<'info> {
             b"ouroboros",
             ouroboros_id.to_le_bytes().as_ref()
         ],
-        bump = bumps.ouroboros,
+        bump,
     )]
     pub ouroboros: Account<'info, Ouroboros>,
 
@@ -28,7 +31,7 @@ pub struct InitializeOuroboros<'info> {
             b"authority",
             ouroboros_id.to_le_bytes().as_ref()
         ],
-        bump = bumps.authority
+        bump
     )]
     pub authority: AccountInfo<'info>,
 
@@ -42,7 +45,7 @@ pub struct InitializeOuroboros<'info> {
             b"mint",
             ouroboros_id.to_le_bytes().as_ref()
         ],
-        bump = bumps.mint
+        bump
     )]
     pub mint: Account<'info, Mint>,
 
@@ -85,23 +88,33 @@ impl<'info> InitializeOuroboros<'info> {
 
 pub fn handler(
     ctx: Context<InitializeOuroboros>,
-    bumps: OuroborosBumps,
     ouroboros_id: u64,
     initial_supply: u64,
     period: u64,
     start_date: i64,
     expansion_factor: u64,
     time_multiplier: u64,
+    early_withdraw_penalty: u64,
+    caps: EmissionCaps,
 ) -> ProgramResult {
+    Ouroboros::validate_params(period, early_withdraw_penalty, &caps)?;
+
     let ouroboros = &mut ctx.accounts.ouroboros;
     ouroboros.id = ouroboros_id;
     ouroboros.authority = ctx.accounts.authority.key();
+    ouroboros.admin = ctx.accounts.creator.key();
+    ouroboros.treasury = ctx.accounts.creator.key();
     ouroboros.mint = ctx.accounts.mint.key();
     ouroboros.period = period;
     ouroboros.last_period = start_date;
     ouroboros.expansion_factor = expansion_factor;
     ouroboros.time_multiplier = time_multiplier;
-    ouroboros.bumps = bumps;
+    ouroboros.early_withdraw_penalty = early_withdraw_penalty;
+    ouroboros.caps = caps;
+    ouroboros.bumps = OuroborosBumps::find(ouroboros_id, ctx.program_id);
+    // Beneficiaries of new ouroboros are registered on creation
+    ouroboros.flags = flags::REGISTRY_COMPLETE;
+    ouroboros.version = Ouroboros::VERSION;
 
     let id_seed = ouroboros.id.to_le_bytes();
     let seeds = &[
@@ -116,6 +129,14 @@ pub fn handler(
         initial_supply,
     )?;
 
+    emit!(OuroborosInitialized {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: ctx.accounts.mint.key(),
+        initial_supply,
+        period,
+        epoch: start_date,
+    });
+
     msg!("Ouroboros initialized");
 
     Ok(())
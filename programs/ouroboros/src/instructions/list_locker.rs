@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerListed,
+    state::{Listing, ListingBumps, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct ListLocker<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The locker being sold
+    #[account(
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker,
+        has_one = receipt
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The listing
+    #[account(
+        init,
+        payer = seller,
+        seeds = [
+            b"listing",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    /// The receipt of the locker
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account holding the receipt until the locker is sold
+    #[account(
+        init,
+        payer = seller,
+        seeds = [
+            b"listing_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump,
+        token::mint = receipt,
+        token::authority = authority
+    )]
+    pub escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// The mint in which the price is paid
+    pub price_mint: Box<Account<'info, Mint>>,
+
+    /// The wallet selling the locker
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// The account of the seller holding the receipt
+    #[account(mut)]
+    pub seller_receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ListLocker<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.seller_receipt_account.to_account_info(),
+                to: self.escrow_account.to_account_info(),
+                authority: self.seller.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ListLocker>, price: u64) -> ProgramResult {
+    if price == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let locker = &ctx.accounts.locker;
+    if locker.soulbound {
+        return Err(ErrorCode::SoulboundLocker.into());
+    }
+
+    let listing = &mut ctx.accounts.listing;
+    listing.locker = locker.key();
+    listing.seller = ctx.accounts.seller.key();
+    listing.price_mint = ctx.accounts.price_mint.key();
+    listing.price = price;
+    listing.amount = locker.amount;
+    listing.votes = locker.votes;
+    listing.unlock_timestamp = locker.unlock_timestamp;
+    listing.bumps = ListingBumps::find(ctx.accounts.ouroboros.id, &locker.id, ctx.program_id);
+
+    token::transfer(ctx.accounts.transfer_context(), 1)?;
+
+    emit!(LockerListed {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        seller: ctx.accounts.seller.key(),
+        price_mint: ctx.accounts.price_mint.key(),
+        price,
+    });
+
+    msg!("Listed locker {} for {}", ctx.accounts.locker.key(), price);
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::{LockerCreated, VestingLocked},
+    state::{pause, Locker, LockerBumps, Ouroboros, Vesting, Versioned},
+};
+
+#[derive(Accounts)]
+#[instruction(id: Pubkey)]
+pub struct LockVesting<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The vesting
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.vesting,
+        has_one = recipient
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The account holding the vesting tokens
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.account
+    )]
+    pub vesting_account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker
+    #[account(
+        init,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account that will hold locked tokens
+    #[account(
+        init,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient,
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The receipt NFT used to redeem the locker
+    #[account(
+        init,
+        seeds = [
+            b"receipt",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient,
+        mint::decimals = 0,
+        mint::authority = authority
+    )]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account holding the receipt until the locker is unlocked
+    #[account(
+        init,
+        seeds = [
+            b"vesting_receipt",
+            ouroboros.id.to_le_bytes().as_ref(),
+            id.as_ref()
+        ],
+        bump,
+        payer = recipient,
+        token::mint = receipt,
+        token::authority = authority
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet receiving vested tokens
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockVesting<'info> {
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            MintTo {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.vesting_account.to_account_info(),
+                to: self.locker_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<LockVesting>,
+    id: Pubkey,
+    amount: u64,
+    period: u64,
+) -> ProgramResult {
+    ctx.accounts.ouroboros.check_active(pause::LOCKS)?;
+    Locker::validate(amount, period)?;
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    let vesting = &mut ctx.accounts.vesting;
+    if vesting.locker != Pubkey::default() {
+        return Err(ErrorCode::LockedVesting.into());
+    }
+    if amount > vesting.total_amount - vesting.vested_amount(now) {
+        return Err(ErrorCode::ExceedsUnvested.into());
+    }
+    vesting.locker = ctx.accounts.locker.key();
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.total_votes += amount;
+
+    let locker = &mut ctx.accounts.locker;
+    locker.id = id;
+    locker.receipt = ctx.accounts.receipt.key();
+    locker.beneficiary = Pubkey::default();
+    locker.mint = ouroboros.mint;
+    locker.amount = amount;
+    locker.value = amount;
+    locker.votes = Locker::compute_votes(amount, period, ouroboros.time_multiplier);
+    locker.creation_timestamp = now;
+    locker.unlock_timestamp = now + period as i64;
+    locker.bumps = LockerBumps::find(ouroboros.id, &id, ctx.program_id);
+    locker.version = Locker::VERSION;
+
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+    token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), 1)?;
+
+    emit!(LockerCreated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        receipt: ctx.accounts.receipt.key(),
+        creator: ctx.accounts.recipient.key(),
+        amount,
+        votes: ctx.accounts.locker.votes,
+        unlock_timestamp: ctx.accounts.locker.unlock_timestamp,
+        soulbound: false,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+    emit!(VestingLocked {
+        vesting: ctx.accounts.vesting.key(),
+        locker: ctx.accounts.locker.key(),
+        amount,
+    });
+
+    msg!("Locked {} vesting tokens", amount);
+
+    Ok(())
+}
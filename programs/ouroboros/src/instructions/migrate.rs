@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{bpf_loader_upgradeable, program::invoke, system_instruction};
+
+use crate::{
+    errors::ErrorCode,
+    state::{Beneficiary, Locker, Migratable, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// The account upgraded to the current layout
+    #[account(
+        mut,
+        constraint = *account.owner == crate::ID
+    )]
+    pub account: AccountInfo<'info>,
+
+    /// The wallet paying for the reserved space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateOuroboros<'info> {
+    /// The ouroboros upgraded to the current layout
+    #[account(
+        mut,
+        constraint = *account.owner == crate::ID
+    )]
+    pub account: AccountInfo<'info>,
+
+    /// The upgrade authority of the program, becoming the admin of the ouroboros
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The data account of the program, holding its upgrade authority
+    pub program_data: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateWithOuroboros<'info> {
+    /// The ouroboros the account belongs to, migrated beforehand
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The account upgraded to the current layout
+    #[account(
+        mut,
+        constraint = *account.owner == crate::ID
+    )]
+    pub account: AccountInfo<'info>,
+
+    /// The wallet paying for the reserved space
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Decodes an account of type `T` stored with its legacy layout
+fn read_legacy<T: Migratable>(account: &AccountInfo) -> Result<T::Legacy, ProgramError> {
+    let data = account.try_borrow_data()?;
+    if data.len() < 8 || data[..8] != T::discriminator() {
+        return Err(anchor_lang::__private::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    if data.len() != T::legacy_space() {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    Ok(T::Legacy::deserialize(&mut &data[8..])?)
+}
+
+/// Grows the account to the current layout, topped up to stay rent exempt, and writes `data`
+fn write<'info, T: Migratable>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    data: &T,
+) -> ProgramResult {
+    let space = T::space();
+    let rent = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if rent > 0 {
+        invoke(
+            &system_instruction::transfer(&payer.key(), &account.key(), rent),
+            &[
+                payer.to_account_info(),
+                account.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    account.realloc(space, true)?;
+    data.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Account {} migrated to version {}",
+        account.key(),
+        T::VERSION
+    );
+
+    Ok(())
+}
+
+/// Upgrades an account of type `T` from its legacy layout
+pub fn handler<T: Migratable>(ctx: Context<Migrate>) -> ProgramResult {
+    let accounts = &ctx.accounts;
+    let data = T::from_legacy(read_legacy::<T>(&accounts.account)?);
+
+    write(
+        &accounts.account,
+        &accounts.payer,
+        &accounts.system_program,
+        &data,
+    )
+}
+
+/// Checks that `deployer` is the upgrade authority of the program.
+/// Localnet builds load the program without upgrade authority, and accept the localnet deployer instead.
+fn check_deployer(deployer: &Pubkey, program_data: &AccountInfo) -> ProgramResult {
+    #[cfg(feature = "localnet")]
+    if *deployer == crate::localnet_deployer::ID {
+        return Ok(());
+    }
+
+    let address =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID).0;
+    if program_data.key() != address || *program_data.owner != bpf_loader_upgradeable::ID {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+    let data = ProgramData::try_deserialize(&mut &program_data.try_borrow_data()?[..])
+        .map_err(|_| ErrorCode::Unauthorized)?;
+    if data.upgrade_authority_address != Some(*deployer) {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+/// Upgrades a legacy ouroboros, handing it to the upgrade authority as it had no admin
+pub fn ouroboros_handler(ctx: Context<MigrateOuroboros>) -> ProgramResult {
+    let accounts = &ctx.accounts;
+    check_deployer(&accounts.payer.key(), &accounts.program_data)?;
+
+    let mut data = Ouroboros::from_legacy(read_legacy::<Ouroboros>(&accounts.account)?);
+    data.admin = accounts.payer.key();
+    data.treasury = accounts.payer.key();
+
+    write(
+        &accounts.account,
+        &accounts.payer,
+        &accounts.system_program,
+        &data,
+    )
+}
+
+/// Upgrades a locker of the ouroboros, which holds native tokens
+pub fn locker_handler(ctx: Context<MigrateWithOuroboros>) -> ProgramResult {
+    let accounts = &ctx.accounts;
+    let legacy = read_legacy::<Locker>(&accounts.account)?;
+
+    let address = Pubkey::create_program_address(
+        &[
+            b"locker",
+            accounts.ouroboros.id.to_le_bytes().as_ref(),
+            legacy.id.as_ref(),
+            &[legacy.bumps.locker],
+        ],
+        &crate::ID,
+    );
+    if address != Ok(accounts.account.key()) {
+        return Err(ErrorCode::InvalidLocker.into());
+    }
+
+    let mut data = Locker::from_legacy(legacy);
+    data.mint = accounts.ouroboros.mint;
+
+    write(
+        &accounts.account,
+        &accounts.payer,
+        &accounts.system_program,
+        &data,
+    )
+}
+
+/// Upgrades a beneficiary of the ouroboros, counting its votes as cast
+pub fn beneficiary_handler(mut ctx: Context<MigrateWithOuroboros>) -> ProgramResult {
+    let accounts = &mut ctx.accounts;
+    let legacy = read_legacy::<Beneficiary>(&accounts.account)?;
+
+    let address = Pubkey::create_program_address(
+        &[
+            b"beneficiary",
+            accounts.ouroboros.id.to_le_bytes().as_ref(),
+            legacy.account.as_ref(),
+            &[legacy.bump],
+        ],
+        &crate::ID,
+    );
+    if address != Ok(accounts.account.key()) {
+        return Err(ErrorCode::InvalidBeneficiary.into());
+    }
+
+    let data = Beneficiary::from_legacy(legacy);
+    accounts.ouroboros.cast_votes = accounts
+        .ouroboros
+        .cast_votes
+        .checked_add(data.votes)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    write(
+        &accounts.account,
+        &accounts.payer,
+        &accounts.system_program,
+        &data,
+    )
+}
@@ -1,15 +1,103 @@
+pub mod attach_ouroboros;
+pub mod buy_locker;
+pub mod buyback;
+pub mod cancel_listing;
+pub mod cast_vesting_vote;
 pub mod cast_vote;
+pub mod claim_compounded_incentives;
+pub mod claim_gauge_incentives;
+pub mod claim_genesis_locker;
 pub mod claim_incentives;
+pub mod clawback;
 pub mod collect_fees;
+pub mod complete_registry;
 pub mod create_beneficiary;
+pub mod create_distributor;
 pub mod create_locker;
+pub mod create_lp_locker;
+pub mod create_treasury;
+pub mod create_vesting;
+pub mod distribute;
+pub mod early_withdraw;
 pub mod initialize_ouroboros;
+pub mod list_locker;
+pub mod lock_vesting;
+pub mod migrate;
+pub mod pause;
+pub mod propose_mint;
 pub mod receive_asset;
+pub mod register_beneficiary;
+pub mod revoke_vesting;
+pub mod set_admin;
+pub mod set_allowed_mint;
+pub mod set_beneficiary_metadata;
+pub mod set_beneficiary_recipients;
+pub mod set_buyback;
+pub mod set_compounding_locker;
+pub mod set_guardian;
+pub mod set_keeper_reward;
+pub mod set_listing_fee;
+pub mod set_native_pair;
+pub mod set_permanent_lock;
+pub mod set_voting_mode;
+pub mod treasury_add_liquidity;
+pub mod treasury_remove_liquidity;
+pub mod treasury_stake;
+pub mod treasury_unstake;
+pub mod unlock_vesting;
+pub mod unpause;
+pub mod update_locker_votes;
+pub mod withdraw;
+pub mod withdraw_vested;
 
+pub use attach_ouroboros::*;
+pub use buy_locker::*;
+pub use buyback::*;
+pub use cancel_listing::*;
+pub use cast_vesting_vote::*;
 pub use cast_vote::*;
+pub use claim_compounded_incentives::*;
+pub use claim_gauge_incentives::*;
+pub use claim_genesis_locker::*;
 pub use claim_incentives::*;
+pub use clawback::*;
 pub use collect_fees::*;
+pub use complete_registry::*;
 pub use create_beneficiary::*;
+pub use create_distributor::*;
 pub use create_locker::*;
+pub use create_lp_locker::*;
+pub use create_treasury::*;
+pub use create_vesting::*;
+pub use distribute::*;
+pub use early_withdraw::*;
 pub use initialize_ouroboros::*;
+pub use list_locker::*;
+pub use lock_vesting::*;
+pub use migrate::*;
+pub use pause::*;
+pub use propose_mint::*;
 pub use receive_asset::*;
+pub use register_beneficiary::*;
+pub use revoke_vesting::*;
+pub use set_admin::*;
+pub use set_allowed_mint::*;
+pub use set_beneficiary_metadata::*;
+pub use set_beneficiary_recipients::*;
+pub use set_buyback::*;
+pub use set_compounding_locker::*;
+pub use set_guardian::*;
+pub use set_keeper_reward::*;
+pub use set_listing_fee::*;
+pub use set_native_pair::*;
+pub use set_permanent_lock::*;
+pub use set_voting_mode::*;
+pub use treasury_add_liquidity::*;
+pub use treasury_remove_liquidity::*;
+pub use treasury_stake::*;
+pub use treasury_unstake::*;
+pub use unlock_vesting::*;
+pub use unpause::*;
+pub use update_locker_votes::*;
+pub use withdraw::*;
+pub use withdraw_vested::*;
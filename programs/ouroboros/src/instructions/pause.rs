@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::PauseUpdated, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        constraint =
+            guardian.key() == ouroboros.guardian ||
+            guardian.key() == ouroboros.admin
+            @ ErrorCode::Unauthorized
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The guardian of the ouroboros, or its admin
+    pub guardian: Signer<'info>,
+}
+
+/// Pauses features instantly, withdrawals of lockers and vestings are never paused
+pub fn handler(ctx: Context<Pause>, features: u64) -> ProgramResult {
+    Ouroboros::validate_features(features)?;
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.paused |= features;
+
+    emit!(PauseUpdated {
+        ouroboros: ouroboros.key(),
+        paused: ouroboros.paused,
+        authority: ctx.accounts.guardian.key(),
+    });
+
+    msg!("Paused features {:#b}", ouroboros.paused);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    events::MintProposed,
+    state::{AllowedMint, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct ProposeMint<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The proposal, to be approved by the admin
+    #[account(
+        init,
+        seeds = [
+            b"allowed_mint",
+            ouroboros.id.to_le_bytes().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        payer = proposer
+    )]
+    pub allowed_mint: Box<Account<'info, AllowedMint>>,
+
+    /// The mint proposed
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The wallet proposing the mint
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeMint>) -> ProgramResult {
+    let allowed_mint = &mut ctx.accounts.allowed_mint;
+    allowed_mint.mint = ctx.accounts.mint.key();
+    allowed_mint.proposer = ctx.accounts.proposer.key();
+    allowed_mint.bump = Pubkey::find_program_address(
+        &[
+            b"allowed_mint",
+            ctx.accounts.ouroboros.id.to_le_bytes().as_ref(),
+            allowed_mint.mint.as_ref(),
+        ],
+        ctx.program_id,
+    )
+    .1;
+
+    emit!(MintProposed {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: ctx.accounts.mint.key(),
+        proposer: ctx.accounts.proposer.key(),
+    });
+
+    msg!("Mint {} proposed", ctx.accounts.mint.key());
+
+    Ok(())
+}
@@ -5,11 +5,12 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::{
     errors::ErrorCode,
-    state::{Asset, AssetBumps, Ouroboros, Snapshot},
+    events::AssetReceived,
+    state::{AllowedMint, Asset, AssetBumps, Ouroboros, Snapshot, Versioned},
 };
 
 #[derive(Accounts)]
-#[instruction(bumps: AssetBumps, snapshot_bump: u8, snapshot_index: u64)]
+#[instruction(snapshot_index: u64)]
 pub struct ReceiveAsset<'info> {
     /// The Ouroboros
     #[account(
@@ -22,6 +23,17 @@ pub struct ReceiveAsset<'info> {
     )]
     pub ouroboros: Box<Account<'info, Ouroboros>>,
 
+    /// The entry of the asset in the allowlist
+    #[account(
+        seeds = [
+            b"allowed_mint",
+            ouroboros.id.to_le_bytes().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = allowed_mint.bump
+    )]
+    pub allowed_mint: Box<Account<'info, AllowedMint>>,
+
     /// The asset being claimed
     #[account(
         init_if_needed,
@@ -31,8 +43,8 @@ pub struct ReceiveAsset<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             mint.key().as_ref()
         ],
-        bump = bumps.asset,
-        constraint = asset.last_snapshot_index == snapshot_index
+        bump,
+        constraint = asset.last_snapshot_index == snapshot_index @ ErrorCode::InvalidSnapshot
     )]
     pub asset: Box<Account<'info, Asset>>,
 
@@ -44,7 +56,7 @@ pub struct ReceiveAsset<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             mint.key().as_ref()
         ],
-        bump = bumps.authority
+        bump = asset.authority_bump(ouroboros.id, &mint.key())
     )]
     pub authority: AccountInfo<'info>,
 
@@ -58,7 +70,7 @@ pub struct ReceiveAsset<'info> {
             mint.key().as_ref(),
             snapshot_index.to_le_bytes().as_ref()
         ],
-        bump = snapshot_bump
+        bump
     )]
     pub current_snapshot: Box<Account<'info, Snapshot>>,
 
@@ -75,7 +87,7 @@ pub struct ReceiveAsset<'info> {
             ouroboros.id.to_le_bytes().as_ref(),
             mint.key().as_ref()
         ],
-        bump = bumps.account,
+        bump,
         token::mint = mint,
         token::authority = authority,
     )]
@@ -122,51 +134,65 @@ impl<'info> ReceiveAsset<'info> {
 
 pub fn handler(
     ctx: Context<ReceiveAsset>,
-    bumps: AssetBumps,
-    snapshot_bump: u8,
     snapshot_index: u64,
     amount: u64,
 ) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+    if !ctx.accounts.allowed_mint.approved {
+        return Err(ErrorCode::MintNotAllowed.into());
+    }
+    if amount < ctx.accounts.allowed_mint.min_amount {
+        return Err(ErrorCode::AmountTooSmall.into());
+    }
+
     let ouroboros = &mut ctx.accounts.ouroboros;
     let asset = &mut ctx.accounts.asset;
 
+    // Part of foreign assets is kept aside to buy back native tokens
+    let buyback = if ctx.accounts.mint.key() == ouroboros.mint {
+        0
+    } else {
+        amount * ouroboros.buyback_share as u64 / 10000
+    };
+    asset.buyback_amount += buyback;
+    let rewards = amount - buyback;
+
     if ctx.accounts.clock.unix_timestamp >= ouroboros.last_period + (ouroboros.period as i64) {
         ouroboros.last_period += ouroboros.period as i64;
-        ouroboros.last_period_votes += ouroboros.total_votes;
+        ouroboros.last_period_votes = ouroboros.period_votes();
     }
 
     // Uninitialized asset
     if asset.mint != ctx.accounts.mint.key() {
         asset.mint = ctx.accounts.mint.key();
         asset.authority = ctx.accounts.authority.key();
-        asset.reward_height += amount;
+        asset.reward_height += rewards;
         asset.last_update = ouroboros.last_period;
-        asset.bumps = bumps;
+        asset.bumps = AssetBumps::find(ouroboros.id, &asset.mint, ctx.program_id);
+        asset.version = Asset::VERSION;
     }
 
-    let current_snapshot = &mut ctx.accounts.current_snapshot;
-
-    // Uninitialized snapshot
-    if current_snapshot.timestamp == 0 {
-        current_snapshot.mint = ctx.accounts.mint.key();
-        current_snapshot.timestamp = ouroboros.last_period + ouroboros.period as i64;
-        current_snapshot.index = asset.last_snapshot_index;
-        current_snapshot.bump = snapshot_bump;
-    } else if current_snapshot.timestamp != ouroboros.last_period + ouroboros.period as i64
-        || current_snapshot.index != snapshot_index
-    {
-        return Err(ErrorCode::InvalidSnapshot.into());
-    }
-
-    if asset.last_update + (ouroboros.period as i64) < ctx.accounts.clock.unix_timestamp {
-        asset.last_update += ouroboros.period as i64;
-        asset.last_snapshot_index += 1;
-    }
+    asset.add_rewards(
+        ouroboros,
+        &mut ctx.accounts.current_snapshot,
+        snapshot_index,
+        rewards,
+        ctx.accounts.clock.unix_timestamp,
+    )?;
 
-    current_snapshot.rewards += amount;
-    current_snapshot.votes = ouroboros.total_votes;
     token::transfer(ctx.accounts.transfer_context(), amount)?;
 
+    emit!(AssetReceived {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: ctx.accounts.mint.key(),
+        sender: ctx.accounts.sender.key(),
+        amount,
+        snapshot_index,
+        epoch: ctx.accounts.ouroboros.last_period,
+    });
+
     msg!("Received {} of asset {}", amount, ctx.accounts.mint.key());
 
     Ok(())
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    events::BeneficiaryRegistered,
+    state::{Beneficiary, BeneficiaryRegistry, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct RegisterBeneficiary<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The beneficiary created before the registry
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump,
+        constraint = beneficiary.registry_slot == 0 @ ErrorCode::AlreadyRegistered
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The last page of the registry of beneficiaries
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"registry",
+            ouroboros.id.to_le_bytes().as_ref(),
+            (ouroboros.num_beneficiaries / BeneficiaryRegistry::CAPACITY).to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = admin,
+        space = BeneficiaryRegistry::space()
+    )]
+    pub registry: Box<Account<'info, BeneficiaryRegistry>>,
+
+    /// The admin of the ouroboros
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterBeneficiary>) -> ProgramResult {
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    beneficiary.registry_slot = ctx.accounts.registry.register(
+        &mut ctx.accounts.ouroboros,
+        beneficiary.key(),
+        ctx.program_id,
+    );
+
+    emit!(BeneficiaryRegistered {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: beneficiary.key(),
+        slot: beneficiary.registry_slot,
+    });
+
+    msg!("Beneficiary {} registered", beneficiary.key());
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::VestingRevoked,
+    state::{Beneficiary, Locker, Ouroboros, Vesting},
+};
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The vesting
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.vesting,
+        has_one = admin
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The account holding the vesting tokens
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.account
+    )]
+    pub vesting_account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker holding vesting tokens
+    /// Can be anything if the vesting is not locked
+    #[account(mut)]
+    pub locker: AccountInfo<'info>,
+
+    /// The account holding locked tokens
+    /// Can be anything if the vesting is not locked
+    #[account(mut)]
+    pub locker_account: AccountInfo<'info>,
+
+    /// The beneficiary the locker voted for
+    /// Can be anything if the vesting is not locked or the locker never voted
+    #[account(mut)]
+    pub locker_beneficiary: AccountInfo<'info>,
+
+    /// The admin of the vesting
+    pub admin: Signer<'info>,
+
+    /// The account receiving unvested tokens
+    #[account(mut)]
+    pub admin_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> RevokeVesting<'info> {
+    fn transfer_context(
+        &self,
+        from: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from,
+                to: self.admin_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    /// Removes `amount` unvested tokens from the locker of the vesting and their votes
+    fn unlock_unvested(&mut self, amount: u64, program_id: &Pubkey) -> ProgramResult {
+        if self.vesting.locker == Pubkey::default() || self.locker.key() != self.vesting.locker {
+            return Err(ErrorCode::LockedVesting.into());
+        }
+        let mut locker: Account<Locker> = Account::try_from(&self.locker)?;
+        let locker_account = Pubkey::create_program_address(
+            &[
+                b"locker_account",
+                self.ouroboros.id.to_le_bytes().as_ref(),
+                locker.id.as_ref(),
+                &[locker.bumps.account],
+            ],
+            program_id,
+        );
+        if locker_account != Ok(self.locker_account.key()) {
+            return Err(ErrorCode::LockedVesting.into());
+        }
+
+        // Votes decrease with the amount locked, the locking period is unchanged
+        let votes = (locker.votes as u128 * (locker.amount - amount) as u128
+            / locker.amount as u128) as u64;
+        locker.amount -= amount;
+        locker.value -= amount;
+        locker.votes = votes;
+        let ouroboros = &mut self.ouroboros;
+        ouroboros.total_votes -= amount;
+
+        if locker.beneficiary != Pubkey::default() {
+            if self.locker_beneficiary.key() != locker.beneficiary {
+                return Err(ErrorCode::InvalidBeneficiary.into());
+            }
+
+            let mut beneficiary: Account<Beneficiary> =
+                Account::try_from(&self.locker_beneficiary)?;
+            if beneficiary.last_update != ouroboros.last_period {
+                return Err(ErrorCode::UnclaimedIncentives.into());
+            }
+            beneficiary.update_votes(ouroboros, &mut locker);
+            beneficiary.exit(program_id)?;
+        }
+        locker.exit(program_id)?;
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<RevokeVesting>) -> ProgramResult {
+    let vesting = &mut ctx.accounts.vesting;
+    if !vesting.schedule.revocable || vesting.revoked {
+        return Err(ErrorCode::NotRevocable.into());
+    }
+
+    let vested = vesting.vested_amount(ctx.accounts.clock.unix_timestamp);
+    let unvested = vesting.total_amount - vested;
+    vesting.total_amount = vested;
+    vesting.revoked = true;
+
+    // Unvested tokens that were locked are clawed back from the locker
+    let liquid = unvested.min(ctx.accounts.vesting_account.amount);
+    let locked = unvested - liquid;
+    if locked > 0 {
+        ctx.accounts.unlock_unvested(locked, ctx.program_id)?;
+    }
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    if liquid > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_context(ctx.accounts.vesting_account.to_account_info())
+                .with_signer(signer),
+            liquid,
+        )?;
+    }
+    if locked > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_context(ctx.accounts.locker_account.clone())
+                .with_signer(signer),
+            locked,
+        )?;
+    }
+
+    emit!(VestingRevoked {
+        vesting: ctx.accounts.vesting.key(),
+        admin: ctx.accounts.admin.key(),
+        vested_amount: vested,
+        revoked_amount: unvested,
+    });
+
+    msg!("Revoked {} unvested tokens", unvested);
+
+    Ok(())
+}
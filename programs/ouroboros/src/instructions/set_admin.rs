@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::AdminChanged, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetAdmin<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The current admin
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAdmin>, new_admin: Pubkey) -> ProgramResult {
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.admin = new_admin;
+
+    emit!(AdminChanged {
+        ouroboros: ouroboros.key(),
+        old_admin: ctx.accounts.admin.key(),
+        new_admin,
+    });
+
+    msg!("Admin set to {}", new_admin);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::AllowedMintUpdated,
+    state::{AllowedMint, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct SetAllowedMint<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The proposed mint
+    #[account(
+        mut,
+        seeds = [
+            b"allowed_mint",
+            ouroboros.id.to_le_bytes().as_ref(),
+            allowed_mint.mint.as_ref()
+        ],
+        bump = allowed_mint.bump
+    )]
+    pub allowed_mint: Box<Account<'info, AllowedMint>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAllowedMint>, approved: bool, min_amount: u64) -> ProgramResult {
+    let allowed_mint = &mut ctx.accounts.allowed_mint;
+    allowed_mint.approved = approved;
+    allowed_mint.min_amount = min_amount;
+
+    emit!(AllowedMintUpdated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        mint: allowed_mint.mint,
+        approved,
+        min_amount,
+    });
+
+    msg!(
+        "Mint {} approved: {}, minimum: {}",
+        allowed_mint.mint,
+        approved,
+        min_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use gauges::state::Gauge;
+use pools::state::Pair;
+
+use crate::{
+    errors::ErrorCode,
+    events::BeneficiaryMetadataUpdated,
+    state::{Beneficiary, BeneficiaryMetadata, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct SetBeneficiaryMetadata<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The beneficiary described
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The metadata of the beneficiary
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"beneficiary_metadata",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.key().as_ref()
+        ],
+        bump,
+        payer = signer,
+        space = BeneficiaryMetadata::space()
+    )]
+    pub metadata: Box<Account<'info, BeneficiaryMetadata>>,
+
+    /// The pair served by the beneficiary, or the system program if none
+    #[account(
+        constraint =
+            pair.key() == System::id() ||
+            *pair.owner == pools::ID
+            @ ErrorCode::InvalidPair
+    )]
+    pub pair: AccountInfo<'info>,
+
+    /// The gauge served by the beneficiary, or the system program if none
+    /// Incentives of the beneficiary are then streamed by the gauge
+    #[account(
+        constraint =
+            gauge.key() == System::id() ||
+            *gauge.owner == gauges::ID
+            @ ErrorCode::GaugeMismatch
+    )]
+    pub gauge: AccountInfo<'info>,
+
+    /// The creator of the beneficiary or the admin of the ouroboros
+    /// Only the admin can change the gauge served by the beneficiary
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetBeneficiaryMetadata<'info> {
+    /// Checks the gauge streams the incentives of the beneficiary for the pair
+    fn validate_gauge(&self) -> ProgramResult {
+        let gauge: Account<Gauge> = Account::try_from(&self.gauge)?;
+        let pair: Account<Pair> = Account::try_from(&self.pair)?;
+        if gauge.pair != pair.key()
+            || gauge.rewards_account(&pair.mint_a, &pair.mint_b) != self.beneficiary.account
+        {
+            return Err(ErrorCode::GaugeMismatch.into());
+        }
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetBeneficiaryMetadata>,
+    name: String,
+    uri: String,
+    category: String,
+) -> ProgramResult {
+    let signer = ctx.accounts.signer.key();
+    if signer != ctx.accounts.beneficiary.creator && signer != ctx.accounts.ouroboros.admin {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+    if name.len() > BeneficiaryMetadata::MAX_NAME_LEN
+        || uri.len() > BeneficiaryMetadata::MAX_URI_LEN
+        || category.len() > BeneficiaryMetadata::MAX_CATEGORY_LEN
+    {
+        return Err(ErrorCode::MetadataTooLong.into());
+    }
+
+    let link = |account: &AccountInfo| {
+        if account.key() == System::id() {
+            Pubkey::default()
+        } else {
+            account.key()
+        }
+    };
+
+    let gauge = link(&ctx.accounts.gauge);
+    if gauge != ctx.accounts.beneficiary.gauge && signer != ctx.accounts.ouroboros.admin {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+    if gauge != Pubkey::default() {
+        ctx.accounts.validate_gauge()?;
+    }
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.beneficiary = ctx.accounts.beneficiary.key();
+    metadata.name = name;
+    metadata.uri = uri;
+    metadata.category = category;
+    metadata.pair = link(&ctx.accounts.pair);
+    metadata.gauge = gauge;
+    metadata.bump = Pubkey::find_program_address(
+        &[
+            b"beneficiary_metadata",
+            ctx.accounts.ouroboros.id.to_le_bytes().as_ref(),
+            metadata.beneficiary.as_ref(),
+        ],
+        ctx.program_id,
+    )
+    .1;
+    ctx.accounts.beneficiary.gauge = metadata.gauge;
+
+    emit!(BeneficiaryMetadataUpdated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: metadata.beneficiary,
+        name: metadata.name.clone(),
+        uri: metadata.uri.clone(),
+        category: metadata.category.clone(),
+        pair: metadata.pair,
+        gauge: metadata.gauge,
+    });
+
+    msg!("Metadata of beneficiary {} updated", metadata.beneficiary);
+
+    Ok(())
+}
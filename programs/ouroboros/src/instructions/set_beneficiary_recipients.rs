@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    errors::ErrorCode,
+    events::BeneficiaryRecipientsUpdated,
+    state::{Beneficiary, BeneficiaryRecipients, Ouroboros, Recipient},
+};
+
+#[derive(Accounts)]
+pub struct SetBeneficiaryRecipients<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The beneficiary whose incentives are split
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump,
+        has_one = account,
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The recipients of the beneficiary
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"beneficiary_recipients",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.key().as_ref()
+        ],
+        bump,
+        payer = owner,
+        space = BeneficiaryRecipients::space()
+    )]
+    pub recipients: Box<Account<'info, BeneficiaryRecipients>>,
+
+    /// The account receiving incentives
+    #[account(constraint = account.owner == owner.key())]
+    pub account: Box<Account<'info, TokenAccount>>,
+
+    /// The owner of the account receiving incentives
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetBeneficiaryRecipients>,
+    recipients: Vec<Recipient>,
+) -> ProgramResult {
+    if recipients.len() > BeneficiaryRecipients::CAPACITY {
+        return Err(ErrorCode::TooManyRecipients.into());
+    }
+    if !recipients.is_empty() && !BeneficiaryRecipients::is_complete(&recipients) {
+        return Err(ErrorCode::InvalidShares.into());
+    }
+
+    let table = &mut ctx.accounts.recipients;
+    table.beneficiary = ctx.accounts.beneficiary.key();
+    table.recipients = recipients;
+    table.bump = Pubkey::find_program_address(
+        &[
+            b"beneficiary_recipients",
+            ctx.accounts.ouroboros.id.to_le_bytes().as_ref(),
+            table.beneficiary.as_ref(),
+        ],
+        ctx.program_id,
+    )
+    .1;
+
+    // An empty table sends incentives back to the beneficiary account
+    ctx.accounts.beneficiary.recipients = if table.recipients.is_empty() {
+        Pubkey::default()
+    } else {
+        table.key()
+    };
+
+    emit!(BeneficiaryRecipientsUpdated {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: table.beneficiary,
+        recipients: table.recipients.clone(),
+    });
+
+    msg!(
+        "Incentives of beneficiary {} split among {} recipients",
+        table.beneficiary,
+        table.recipients.len()
+    );
+
+    Ok(())
+}
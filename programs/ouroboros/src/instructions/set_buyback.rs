@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::BuybackUpdated, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetBuyback<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetBuyback>,
+    buyback_share: u16,
+    buyback_slippage: u16,
+) -> ProgramResult {
+    if buyback_share > 10000 || buyback_slippage > 10000 {
+        return Err(ErrorCode::InvalidShare.into());
+    }
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.buyback_share = buyback_share;
+    ouroboros.buyback_slippage = buyback_slippage;
+
+    emit!(BuybackUpdated {
+        ouroboros: ouroboros.key(),
+        buyback_share,
+        buyback_slippage,
+    });
+
+    msg!("Buyback share set to {} BP", buyback_share);
+
+    Ok(())
+}
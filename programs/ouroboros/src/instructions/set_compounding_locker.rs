@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    errors::ErrorCode,
+    events::CompoundingLockerSet,
+    state::{Beneficiary, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+#[instruction(locker: Pubkey)]
+pub struct SetCompoundingLocker<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The beneficiary of the ouroboros incentives
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump,
+        has_one = account,
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The account receiving incentives
+    #[account(constraint = account.owner == owner.key() @ ErrorCode::Unauthorized)]
+    pub account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker receiving incentives
+    /// Can be anything when compounding is disabled
+    #[account(
+        constraint =
+            locker == Pubkey::default() ||
+            compounding_locker.key() == locker
+            @ ErrorCode::InvalidLocker
+    )]
+    pub compounding_locker: AccountInfo<'info>,
+
+    /// The account holding the locker receipt
+    /// Can be anything when compounding is disabled
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The owner of the account receiving incentives
+    pub owner: Signer<'info>,
+}
+
+impl<'info> SetCompoundingLocker<'info> {
+    /// Checks that the locker belongs to this ouroboros and its receipt to the owner
+    fn validate_locker(&self) -> ProgramResult {
+        let locker: Account<Locker> = Account::try_from(&self.compounding_locker)?;
+        let locker_address = Pubkey::create_program_address(
+            &[
+                b"locker",
+                self.ouroboros.id.to_le_bytes().as_ref(),
+                locker.id.as_ref(),
+                &[locker.bumps.locker],
+            ],
+            &crate::ID,
+        );
+        if locker_address != Ok(locker.key()) {
+            return Err(ErrorCode::InvalidLocker.into());
+        }
+        if locker.mint != self.ouroboros.mint {
+            return Err(ErrorCode::InvalidCollateral.into());
+        }
+
+        let receipt_account = &self.receipt_account;
+        if receipt_account.owner != self.owner.key()
+            || receipt_account.mint != locker.receipt
+            || receipt_account.amount != 1
+        {
+            return Err(ErrorCode::InvalidReceipt.into());
+        }
+
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<SetCompoundingLocker>, locker: Pubkey) -> ProgramResult {
+    if locker != Pubkey::default() {
+        ctx.accounts.validate_locker()?;
+    }
+    ctx.accounts.beneficiary.compounding_locker = locker;
+
+    emit!(CompoundingLockerSet {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        locker,
+    });
+
+    msg!(
+        "Incentives of beneficiary {} compounded in locker {}",
+        ctx.accounts.beneficiary.key(),
+        locker
+    );
+
+    Ok(())
+}
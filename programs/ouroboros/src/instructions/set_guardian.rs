@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::GuardianChanged, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> ProgramResult {
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let old_guardian = ouroboros.guardian;
+    ouroboros.guardian = guardian;
+
+    emit!(GuardianChanged {
+        ouroboros: ouroboros.key(),
+        old_guardian,
+        new_guardian: guardian,
+    });
+
+    msg!("Guardian set to {}", guardian);
+
+    Ok(())
+}
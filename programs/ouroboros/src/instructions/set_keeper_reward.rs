@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::KeeperRewardUpdated, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetKeeperReward<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetKeeperReward>, keeper_reward: u16) -> ProgramResult {
+    if keeper_reward > 10000 {
+        return Err(ErrorCode::InvalidFee.into());
+    }
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.keeper_reward = keeper_reward;
+
+    emit!(KeeperRewardUpdated {
+        ouroboros: ouroboros.key(),
+        keeper_reward,
+    });
+
+    msg!("Keeper reward set to {} BP", keeper_reward);
+
+    Ok(())
+}
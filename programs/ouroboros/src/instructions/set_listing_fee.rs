@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, events::ListingFeeUpdated, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetListingFee<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetListingFee>, listing_fee: u16, treasury: Pubkey) -> ProgramResult {
+    if listing_fee > 10000 {
+        return Err(ErrorCode::InvalidFee.into());
+    }
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.listing_fee = listing_fee;
+    ouroboros.treasury = treasury;
+
+    emit!(ListingFeeUpdated {
+        ouroboros: ouroboros.key(),
+        listing_fee,
+        treasury,
+    });
+
+    msg!("Listing fee set to {} BP", listing_fee);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use pools::state::Pair;
+
+use crate::{errors::ErrorCode, events::NativePairSet, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct SetNativePair<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The pair whose LP tokens can be locked
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The account of the pair holding tokens A
+    pub pair_account_a: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the pair holding tokens B
+    pub pair_account_b: Box<Account<'info, TokenAccount>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<SetNativePair>) -> ProgramResult {
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let pair = &ctx.accounts.pair;
+    if pair.mint_a != ouroboros.mint && pair.mint_b != ouroboros.mint {
+        return Err(ErrorCode::InvalidPair.into());
+    }
+    if pair.reserve_accounts()
+        != (ctx.accounts.pair_account_a.key(), ctx.accounts.pair_account_b.key())
+    {
+        return Err(ErrorCode::InvalidPair.into());
+    }
+    ouroboros.native_pair = pair.key();
+
+    // LP tokens are priced once a TWAP window is over
+    let now = ctx.accounts.clock.unix_timestamp;
+    let (price_a_cumulative, price_b_cumulative) = pair.cumulative_prices(
+        ctx.accounts.pair_account_a.amount,
+        ctx.accounts.pair_account_b.amount,
+        now,
+    );
+    let price_cumulative = if pair.mint_a == ouroboros.mint {
+        price_b_cumulative
+    } else {
+        price_a_cumulative
+    };
+    ouroboros.observe_native_price(price_cumulative, now);
+    ouroboros.native_twap = 0;
+
+    emit!(NativePairSet {
+        ouroboros: ouroboros.key(),
+        pair: pair.key(),
+        pair_mint: pair.pair_mint,
+    });
+
+    msg!("Native pair set to {}", pair.key());
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    errors::ErrorCode,
+    events::PermanentLockSet,
+    state::{Beneficiary, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct SetPermanentLock<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The locker
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The beneficiary the locker voted for
+    /// Can be anything if the locker never voted
+    #[account(mut)]
+    pub beneficiary: AccountInfo<'info>,
+
+    /// The wallet holding the locker receipt
+    pub owner: Signer<'info>,
+
+    /// The account holding the locker receipt
+    #[account(
+        constraint =
+            receipt_account.owner == owner.key() &&
+            receipt_account.mint == locker.receipt &&
+            receipt_account.amount == 1
+            @ ErrorCode::InvalidReceipt
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<SetPermanentLock>, permanent: bool) -> ProgramResult {
+    let now = ctx.accounts.clock.unix_timestamp;
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let locker = &mut ctx.accounts.locker;
+    if locker.permanent == permanent {
+        return Err(ErrorCode::SameLockMode.into());
+    }
+
+    let votes = locker.permanent_votes(ouroboros.time_multiplier);
+    if !permanent {
+        // The countdown starts from the maximum locking period
+        locker.creation_timestamp = now;
+        locker.unlock_timestamp = now + Locker::MAX_PERIOD as i64;
+    }
+
+    // The beneficiary of the locker follows its new votes
+    locker.votes = votes;
+    if locker.beneficiary != Pubkey::default()
+        && ouroboros.effective_votes(votes) != locker.effective_votes
+    {
+        if ctx.accounts.beneficiary.key() != locker.beneficiary {
+            return Err(ErrorCode::InvalidBeneficiary.into());
+        }
+
+        let mut beneficiary: Account<Beneficiary> =
+            Account::try_from(&ctx.accounts.beneficiary)?;
+        if beneficiary.last_update != ouroboros.last_period {
+            return Err(ErrorCode::UnclaimedIncentives.into());
+        }
+        beneficiary.update_votes(ouroboros, locker);
+        beneficiary.exit(ctx.program_id)?;
+    }
+    locker.permanent = permanent;
+
+    emit!(PermanentLockSet {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        locker: ctx.accounts.locker.key(),
+        permanent,
+        votes,
+        unlock_timestamp: ctx.accounts.locker.unlock_timestamp,
+    });
+
+    msg!(
+        "Locker {} is now {}",
+        ctx.accounts.locker.key(),
+        if permanent { "permanent" } else { "counting down" }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    events::VotingModeSet,
+    state::{Ouroboros, VotingMode},
+};
+
+#[derive(Accounts)]
+pub struct SetVotingMode<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetVotingMode>,
+    voting_mode: VotingMode,
+    vote_cap: u64,
+) -> ProgramResult {
+    if voting_mode == VotingMode::Capped && vote_cap == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    // Lockers keep the effective votes of their last vote until they vote again,
+    // or anyone updates them with `update_locker_votes`
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.voting_mode = voting_mode;
+    ouroboros.vote_cap = vote_cap;
+
+    emit!(VotingModeSet {
+        ouroboros: ouroboros.key(),
+        voting_mode,
+        vote_cap,
+    });
+
+    msg!("Voting mode set");
+
+    Ok(())
+}
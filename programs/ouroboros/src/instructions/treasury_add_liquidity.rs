@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use pools::{program::Pools, state::Pair};
+
+use crate::{
+    errors::ErrorCode,
+    events::TreasuryLiquidityAdded,
+    state::{Ouroboros, Treasury},
+};
+
+#[derive(Accounts)]
+pub struct TreasuryAddLiquidity<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin,
+        has_one = treasury
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The treasury providing liquidity
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// The configuration of the pools, checked by the pools program
+    pub pools_config: AccountInfo<'info>,
+
+    /// The pair receiving liquidity
+    #[account(mut, has_one = pair_mint)]
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The authority of the pair
+    #[account(mut)]
+    pub pair_authority: AccountInfo<'info>,
+
+    /// The mint of the LP tokens of the pair
+    #[account(mut)]
+    pub pair_mint: Box<Account<'info, Mint>>,
+
+    /// The account of the pair holding the minimum liquidity.
+    /// The pair must already hold liquidity since the treasury can't pay for its creation.
+    #[account(mut)]
+    pub burner_account: AccountInfo<'info>,
+
+    /// The mint of the token A
+    #[account(mut)]
+    pub mint_a: AccountInfo<'info>,
+
+    /// The mint of the token B
+    #[account(mut)]
+    pub mint_b: AccountInfo<'info>,
+
+    /// The pair account holding token A
+    #[account(mut)]
+    pub pair_account_a: AccountInfo<'info>,
+
+    /// The pair account holding token B
+    #[account(mut)]
+    pub pair_account_b: AccountInfo<'info>,
+
+    /// The account of the treasury receiving LP tokens
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = pair_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_liquidity_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account of the treasury providing token A
+    #[account(mut)]
+    pub treasury_account_a: AccountInfo<'info>,
+
+    /// The account of the treasury providing token B
+    #[account(mut)]
+    pub treasury_account_b: AccountInfo<'info>,
+
+    /// The admin of the ouroboros
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The program of the pair
+    pub pools_program: Program<'info, Pools>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TreasuryAddLiquidity<'info> {
+    fn add_liquidity_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, pools::cpi::accounts::AddLiquidity<'info>> {
+        CpiContext::new(
+            self.pools_program.to_account_info(),
+            pools::cpi::accounts::AddLiquidity {
+                config: self.pools_config.to_account_info(),
+                pair: self.pair.to_account_info(),
+                authority: self.pair_authority.to_account_info(),
+                pair_mint: self.pair_mint.to_account_info(),
+                burner_account: self.burner_account.to_account_info(),
+                mint_a: self.mint_a.to_account_info(),
+                mint_b: self.mint_b.to_account_info(),
+                pair_account_a: self.pair_account_a.to_account_info(),
+                pair_account_b: self.pair_account_b.to_account_info(),
+                liquidity_provider: self.treasury.to_account_info(),
+                liquidity_provider_account: self.treasury_liquidity_account.to_account_info(),
+                account_a: self.treasury_account_a.to_account_info(),
+                account_b: self.treasury_account_b.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(
+    ctx: Context<TreasuryAddLiquidity>,
+    desired_amount_a: u64,
+    desired_amount_b: u64,
+    min_amount_a: u64,
+    min_amount_b: u64,
+) -> ProgramResult {
+    if desired_amount_a == 0 || desired_amount_b == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let balance = ctx.accounts.treasury_liquidity_account.amount;
+
+    let id_seed = ctx.accounts.ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"treasury".as_ref(),
+        id_seed.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ];
+    let signer = &[&seeds[..]];
+    pools::cpi::add_liquidity(
+        ctx.accounts.add_liquidity_context().with_signer(signer),
+        desired_amount_a,
+        desired_amount_b,
+        min_amount_a,
+        min_amount_b,
+        // The liquidity is added within this transaction
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    ctx.accounts.treasury_liquidity_account.reload()?;
+    let liquidity = ctx.accounts.treasury_liquidity_account.amount - balance;
+
+    emit!(TreasuryLiquidityAdded {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        pair: ctx.accounts.pair.key(),
+        liquidity,
+    });
+
+    msg!(
+        "Treasury added {} liquidity to pair {}",
+        liquidity,
+        ctx.accounts.pair.key()
+    );
+
+    Ok(())
+}
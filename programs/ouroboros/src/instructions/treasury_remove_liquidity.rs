@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token};
+use pools::{program::Pools, state::Pair};
+
+use crate::{
+    errors::ErrorCode,
+    events::TreasuryLiquidityRemoved,
+    state::{Ouroboros, Treasury},
+};
+
+#[derive(Accounts)]
+pub struct TreasuryRemoveLiquidity<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin,
+        has_one = treasury
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The treasury withdrawing liquidity
+    #[account(
+        mut,
+        seeds = [
+            b"treasury",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// The pair providing liquidity
+    #[account(mut, has_one = pair_mint)]
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The authority of the pair
+    #[account(mut)]
+    pub pair_authority: AccountInfo<'info>,
+
+    /// The mint of the LP tokens of the pair
+    #[account(mut)]
+    pub pair_mint: Box<Account<'info, Mint>>,
+
+    /// The mint of the token A
+    #[account(mut)]
+    pub mint_a: AccountInfo<'info>,
+
+    /// The mint of the token B
+    #[account(mut)]
+    pub mint_b: AccountInfo<'info>,
+
+    /// The pair account holding token A
+    #[account(mut)]
+    pub pair_account_a: AccountInfo<'info>,
+
+    /// The pair account holding token B
+    #[account(mut)]
+    pub pair_account_b: AccountInfo<'info>,
+
+    /// The account of the treasury holding LP tokens
+    #[account(mut)]
+    pub treasury_liquidity_account: AccountInfo<'info>,
+
+    /// The account of the treasury receiving token A
+    #[account(mut)]
+    pub treasury_account_a: AccountInfo<'info>,
+
+    /// The account of the treasury receiving token B
+    #[account(mut)]
+    pub treasury_account_b: AccountInfo<'info>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+
+    /// The program of the pair
+    pub pools_program: Program<'info, Pools>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TreasuryRemoveLiquidity<'info> {
+    fn remove_liquidity_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, pools::cpi::accounts::RemoveLiquidity<'info>> {
+        CpiContext::new(
+            self.pools_program.to_account_info(),
+            pools::cpi::accounts::RemoveLiquidity {
+                pair: self.pair.to_account_info(),
+                authority: self.pair_authority.to_account_info(),
+                pair_mint: self.pair_mint.to_account_info(),
+                mint_a: self.mint_a.to_account_info(),
+                mint_b: self.mint_b.to_account_info(),
+                pair_account_a: self.pair_account_a.to_account_info(),
+                pair_account_b: self.pair_account_b.to_account_info(),
+                liquidity_provider: self.treasury.to_account_info(),
+                liquidity_provider_account: self.treasury_liquidity_account.to_account_info(),
+                account_a: self.treasury_account_a.to_account_info(),
+                account_b: self.treasury_account_b.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<TreasuryRemoveLiquidity>, liquidity: u64) -> ProgramResult {
+    if liquidity == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let id_seed = ctx.accounts.ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"treasury".as_ref(),
+        id_seed.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ];
+    let signer = &[&seeds[..]];
+    pools::cpi::remove_liquidity(
+        ctx.accounts.remove_liquidity_context().with_signer(signer),
+        liquidity,
+        // The liquidity is removed within this transaction
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    emit!(TreasuryLiquidityRemoved {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        pair: ctx.accounts.pair.key(),
+        liquidity,
+    });
+
+    msg!(
+        "Treasury removed {} liquidity from pair {}",
+        liquidity,
+        ctx.accounts.pair.key()
+    );
+
+    Ok(())
+}
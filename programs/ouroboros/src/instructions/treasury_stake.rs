@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+use gauges::program::Gauges;
+use pools::state::Pair;
+
+use crate::{
+    errors::ErrorCode,
+    events::TreasuryLiquidityStaked,
+    state::{Ouroboros, Treasury},
+};
+
+#[derive(Accounts)]
+pub struct TreasuryStake<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin,
+        has_one = treasury
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The treasury staking liquidity
+    #[account(
+        seeds = [
+            b"treasury",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// The configuration of the gauges, checked by the gauges program
+    pub gauges_config: AccountInfo<'info>,
+
+    /// The pair of the gauge
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The gauge receiving the LP tokens
+    #[account(mut)]
+    pub gauge: AccountInfo<'info>,
+
+    /// The account of the gauge holding LP tokens
+    #[account(mut)]
+    pub gauge_liquidity_account: AccountInfo<'info>,
+
+    /// The staker account of the treasury, created by the gauge on the first stake
+    #[account(mut)]
+    pub staker: AccountInfo<'info>,
+
+    /// The account of the treasury holding LP tokens
+    #[account(
+        mut,
+        constraint = treasury_liquidity_account.owner == treasury.key()
+    )]
+    pub treasury_liquidity_account: Box<Account<'info, TokenAccount>>,
+
+    /// The admin of the ouroboros, paying for the staker account
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The program of the gauge
+    pub gauges_program: Program<'info, Gauges>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TreasuryStake<'info> {
+    fn deposit_liquidity_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, gauges::cpi::accounts::DepositLiquidity<'info>> {
+        CpiContext::new(
+            self.gauges_program.to_account_info(),
+            gauges::cpi::accounts::DepositLiquidity {
+                config: self.gauges_config.to_account_info(),
+                pair: self.pair.to_account_info(),
+                gauge: self.gauge.to_account_info(),
+                gauge_liquidity_account: self.gauge_liquidity_account.to_account_info(),
+                liquidity_provider: self.treasury.to_account_info(),
+                payer: self.admin.to_account_info(),
+                staker: self.staker.to_account_info(),
+                liquidity_provider_account: self.treasury_liquidity_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                clock: self.clock.to_account_info(),
+                rent: self.rent.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<TreasuryStake>, amount: u64) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let id_seed = ctx.accounts.ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"treasury".as_ref(),
+        id_seed.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ];
+    let signer = &[&seeds[..]];
+    gauges::cpi::deposit_liquidity(
+        ctx.accounts.deposit_liquidity_context().with_signer(signer),
+        amount,
+    )?;
+
+    emit!(TreasuryLiquidityStaked {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        gauge: ctx.accounts.gauge.key(),
+        amount,
+    });
+
+    msg!(
+        "Treasury staked {} LP tokens in gauge {}",
+        amount,
+        ctx.accounts.gauge.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+use gauges::program::Gauges;
+use pools::state::Pair;
+
+use crate::{
+    errors::ErrorCode,
+    events::TreasuryLiquidityUnstaked,
+    state::{Ouroboros, Treasury},
+};
+
+#[derive(Accounts)]
+pub struct TreasuryUnstake<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin,
+        has_one = treasury
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The treasury withdrawing staked liquidity
+    #[account(
+        seeds = [
+            b"treasury",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// The pair of the gauge
+    pub pair: Box<Account<'info, Pair>>,
+
+    /// The gauge holding the LP tokens
+    #[account(mut)]
+    pub gauge: AccountInfo<'info>,
+
+    /// The authority of the gauge
+    pub gauge_authority: AccountInfo<'info>,
+
+    /// The account of the gauge holding LP tokens
+    #[account(mut)]
+    pub gauge_liquidity_account: AccountInfo<'info>,
+
+    /// The staker account of the treasury
+    #[account(mut)]
+    pub staker: AccountInfo<'info>,
+
+    /// The account of the treasury receiving LP tokens
+    #[account(
+        mut,
+        constraint = treasury_liquidity_account.owner == treasury.key()
+    )]
+    pub treasury_liquidity_account: Box<Account<'info, TokenAccount>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+
+    /// The program of the gauge
+    pub gauges_program: Program<'info, Gauges>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> TreasuryUnstake<'info> {
+    fn withdraw_liquidity_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, gauges::cpi::accounts::WithdrawLiquidity<'info>> {
+        CpiContext::new(
+            self.gauges_program.to_account_info(),
+            gauges::cpi::accounts::WithdrawLiquidity {
+                pair: self.pair.to_account_info(),
+                gauge: self.gauge.to_account_info(),
+                authority: self.gauge_authority.to_account_info(),
+                gauge_liquidity_account: self.gauge_liquidity_account.to_account_info(),
+                liquidity_provider: self.treasury.to_account_info(),
+                staker: self.staker.to_account_info(),
+                liquidity_provider_account: self.treasury_liquidity_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                clock: self.clock.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<TreasuryUnstake>, amount: u64) -> ProgramResult {
+    if amount == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
+
+    let id_seed = ctx.accounts.ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"treasury".as_ref(),
+        id_seed.as_ref(),
+        &[ctx.accounts.treasury.bump],
+    ];
+    let signer = &[&seeds[..]];
+    gauges::cpi::withdraw_liquidity(
+        ctx.accounts.withdraw_liquidity_context().with_signer(signer),
+        amount,
+    )?;
+
+    emit!(TreasuryLiquidityUnstaked {
+        ouroboros: ctx.accounts.ouroboros.key(),
+        gauge: ctx.accounts.gauge.key(),
+        amount,
+    });
+
+    msg!(
+        "Treasury unstaked {} LP tokens from gauge {}",
+        amount,
+        ctx.accounts.gauge.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::{
+    errors::ErrorCode,
+    events::VestingUnlocked,
+    instructions::withdraw::remove_votes,
+    state::{Beneficiary, Locker, Ouroboros, Vesting},
+};
+
+#[derive(Accounts)]
+pub struct UnlockVesting<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The vesting owning the locker
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.vesting,
+        has_one = recipient,
+        has_one = locker
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The account holding the vesting tokens
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.account
+    )]
+    pub vesting_account: Box<Account<'info, TokenAccount>>,
+
+    /// The locker being unlocked
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker,
+        has_one = receipt,
+        close = recipient
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account holding locked tokens
+    #[account(
+        mut,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.account
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The beneficiary the locker voted for
+    /// Can be any beneficiary if the locker never voted
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The receipt of the locker
+    #[account(mut)]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account holding the receipt
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_receipt",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet receiving vested tokens, refunded for closed accounts
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> UnlockVesting<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.locker_account.to_account_info(),
+                to: self.vesting_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(
+        &self,
+        account: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account,
+                destination: self.recipient.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<UnlockVesting>) -> ProgramResult {
+    let locker = &ctx.accounts.locker;
+    if ctx.accounts.clock.unix_timestamp < locker.unlock_timestamp {
+        return Err(ErrorCode::LockerLocked.into());
+    }
+
+    remove_votes(
+        &mut ctx.accounts.ouroboros,
+        locker,
+        &mut ctx.accounts.beneficiary,
+    )?;
+    ctx.accounts.vesting.locker = Pubkey::default();
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        ctx.accounts.transfer_context().with_signer(signer),
+        locker.amount,
+    )?;
+    token::burn(ctx.accounts.burn_context().with_signer(signer), 1)?;
+    token::close_account(
+        ctx.accounts
+            .close_context(ctx.accounts.locker_account.to_account_info())
+            .with_signer(signer),
+    )?;
+    token::close_account(
+        ctx.accounts
+            .close_context(ctx.accounts.receipt_account.to_account_info())
+            .with_signer(signer),
+    )?;
+
+    emit!(VestingUnlocked {
+        vesting: ctx.accounts.vesting.key(),
+        locker: locker.key(),
+        amount: locker.amount,
+    });
+
+    msg!("Returned {} tokens to vesting", locker.amount);
+
+    Ok(())
+}
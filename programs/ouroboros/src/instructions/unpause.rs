@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{events::PauseUpdated, state::Ouroboros};
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = admin
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The admin of the ouroboros
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Unpause>, features: u64) -> ProgramResult {
+    Ouroboros::validate_features(features)?;
+
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    ouroboros.paused &= !features;
+
+    emit!(PauseUpdated {
+        ouroboros: ouroboros.key(),
+        paused: ouroboros.paused,
+        authority: ctx.accounts.admin.key(),
+    });
+
+    msg!("Paused features {:#b}", ouroboros.paused);
+
+    Ok(())
+}
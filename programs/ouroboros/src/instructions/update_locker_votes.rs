@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerVotesUpdated,
+    state::{Beneficiary, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct UpdateLockerVotes<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The locker
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The beneficiary the locker voted for
+    #[account(
+        mut,
+        constraint = beneficiary.key() == locker.beneficiary @ ErrorCode::InvalidBeneficiary
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+}
+
+pub fn handler(ctx: Context<UpdateLockerVotes>) -> ProgramResult {
+    let ouroboros = &mut ctx.accounts.ouroboros;
+    let locker = &mut ctx.accounts.locker;
+    let beneficiary = &mut ctx.accounts.beneficiary;
+    if beneficiary.last_update != ouroboros.last_period {
+        return Err(ErrorCode::UnclaimedIncentives.into());
+    }
+
+    // The votes of the locker count under the current voting mode from now on
+    let old_votes = locker.effective_votes;
+    beneficiary.update_votes(ouroboros, locker);
+
+    emit!(LockerVotesUpdated {
+        ouroboros: ouroboros.key(),
+        locker: locker.key(),
+        beneficiary: beneficiary.key(),
+        old_votes,
+        new_votes: locker.effective_votes,
+    });
+
+    msg!(
+        "Locker {} now counts {} votes",
+        locker.key(),
+        locker.effective_votes
+    );
+
+    Ok(())
+}
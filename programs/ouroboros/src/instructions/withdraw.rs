@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Burn, CloseAccount, Mint, ThawAccount, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    errors::ErrorCode,
+    events::LockerWithdrawn,
+    state::{Beneficiary, Locker, Ouroboros},
+};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    /// The Ouroboros
+    #[account(
+        mut,
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    #[account(mut)]
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the locked tokens
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The locker being withdrawn
+    #[account(
+        mut,
+        seeds = [
+            b"locker",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.locker,
+        has_one = mint,
+        has_one = receipt,
+        close = owner
+    )]
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// The account holding locked tokens
+    #[account(
+        mut,
+        seeds = [
+            b"locker_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            locker.id.as_ref()
+        ],
+        bump = locker.bumps.account
+    )]
+    pub locker_account: Box<Account<'info, TokenAccount>>,
+
+    /// The beneficiary the locker voted for
+    /// Can be any beneficiary if the locker never voted
+    #[account(
+        mut,
+        seeds = [
+            b"beneficiary",
+            ouroboros.id.to_le_bytes().as_ref(),
+            beneficiary.account.as_ref()
+        ],
+        bump = beneficiary.bump
+    )]
+    pub beneficiary: Box<Account<'info, Beneficiary>>,
+
+    /// The receipt of the locker
+    #[account(mut)]
+    pub receipt: Box<Account<'info, Mint>>,
+
+    /// The account holding the locker receipt
+    #[account(
+        mut,
+        constraint =
+            receipt_account.owner == owner.key() &&
+            receipt_account.mint == receipt.key() &&
+            receipt_account.amount == 1
+            @ ErrorCode::InvalidReceipt
+    )]
+    pub receipt_account: Box<Account<'info, TokenAccount>>,
+
+    /// The holder of the receipt
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The account receiving the unlocked tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Withdraw<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.locker_account.to_account_info(),
+                to: self.owner_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.locker_account.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn thaw_context(&self) -> CpiContext<'_, '_, '_, 'info, ThawAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            ThawAccount {
+                account: self.receipt_account.to_account_info(),
+                mint: self.receipt.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Burn {
+                mint: self.receipt.to_account_info(),
+                to: self.receipt_account.to_account_info(),
+                authority: self.owner.to_account_info(),
+            },
+        )
+    }
+}
+
+/// Removes the votes of a closing locker from the ouroboros and its beneficiary
+pub fn remove_votes(
+    ouroboros: &mut Ouroboros,
+    locker: &Locker,
+    beneficiary: &mut Account<Beneficiary>,
+) -> ProgramResult {
+    if locker.permanent {
+        return Err(ErrorCode::LockerLocked.into());
+    }
+    if locker.beneficiary != Pubkey::default() {
+        if locker.beneficiary != beneficiary.key() {
+            return Err(ErrorCode::InvalidBeneficiary.into());
+        }
+        if beneficiary.last_update != ouroboros.last_period {
+            return Err(ErrorCode::UnclaimedIncentives.into());
+        }
+
+        beneficiary.remove_votes(ouroboros, locker, locker.effective_votes);
+    }
+
+    if locker.mint == ouroboros.mint {
+        ouroboros.total_votes -= locker.value;
+    } else {
+        ouroboros.lp_votes -= locker.value;
+    }
+
+    Ok(())
+}
+
+pub fn handler(ctx: Context<Withdraw>) -> ProgramResult {
+    let locker = &ctx.accounts.locker;
+    if ctx.accounts.clock.unix_timestamp < locker.unlock_timestamp {
+        return Err(ErrorCode::LockerLocked.into());
+    }
+
+    remove_votes(
+        &mut ctx.accounts.ouroboros,
+        locker,
+        &mut ctx.accounts.beneficiary,
+    )?;
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        ctx.accounts.transfer_context().with_signer(signer),
+        locker.amount,
+    )?;
+    token::close_account(ctx.accounts.close_context().with_signer(signer))?;
+    // Soulbound receipts are frozen and must be thawed to be burnt
+    if locker.soulbound {
+        token::thaw_account(ctx.accounts.thaw_context().with_signer(signer))?;
+    }
+    token::burn(ctx.accounts.burn_context(), 1)?;
+
+    emit!(LockerWithdrawn {
+        ouroboros: ouroboros.key(),
+        locker: locker.key(),
+        owner: ctx.accounts.owner.key(),
+        amount: locker.amount,
+        penalty: 0,
+        votes: locker.votes,
+        epoch: ouroboros.last_period,
+    });
+
+    msg!("Withdrew {} tokens from locker {}", locker.amount, locker.key());
+
+    Ok(())
+}
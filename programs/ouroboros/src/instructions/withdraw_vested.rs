@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::{
+    events::VestedTokensWithdrawn,
+    state::{Ouroboros, Vesting},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    /// The Ouroboros
+    #[account(
+        seeds = [
+            b"ouroboros",
+            ouroboros.id.to_le_bytes().as_ref()
+        ],
+        bump = ouroboros.bumps.ouroboros,
+        has_one = authority,
+        has_one = mint
+    )]
+    pub ouroboros: Box<Account<'info, Ouroboros>>,
+
+    /// The Ouroboros authority
+    pub authority: AccountInfo<'info>,
+
+    /// The mint of the native token
+    #[account(address = ouroboros.mint)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// The vesting
+    #[account(
+        mut,
+        seeds = [
+            b"vesting",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.vesting,
+        has_one = recipient
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// The account holding the vesting tokens
+    #[account(
+        mut,
+        seeds = [
+            b"vesting_account",
+            ouroboros.id.to_le_bytes().as_ref(),
+            vesting.id.as_ref()
+        ],
+        bump = vesting.bumps.account
+    )]
+    pub vesting_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet receiving vested tokens
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The account receiving vested tokens
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_account: Box<Account<'info, TokenAccount>>,
+
+    /// The program for interacting with the associated tokens.
+    #[account(address = associated_token::ID)]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The program for interacting with the token.
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawVested<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.vesting_account.to_account_info(),
+                to: self.recipient_account.to_account_info(),
+                authority: self.authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<WithdrawVested>) -> ProgramResult {
+    let vesting = &mut ctx.accounts.vesting;
+
+    // Tokens locked in the vesting locker can't be withdrawn until it unlocks
+    let amount = std::cmp::min(
+        vesting.vested_amount(ctx.accounts.clock.unix_timestamp) - vesting.withdrawn_amount,
+        ctx.accounts.vesting_account.amount,
+    );
+    vesting.withdrawn_amount += amount;
+
+    let ouroboros = &ctx.accounts.ouroboros;
+    let id_seed = ouroboros.id.to_le_bytes();
+    let seeds = &[
+        b"authority".as_ref(),
+        id_seed.as_ref(),
+        &[ouroboros.bumps.authority],
+    ];
+    let signer = &[&seeds[..]];
+
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount)?;
+
+    emit!(VestedTokensWithdrawn {
+        vesting: ctx.accounts.vesting.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        withdrawn_amount: ctx.accounts.vesting.withdrawn_amount,
+    });
+
+    msg!("Withdrew {} vested tokens", amount);
+
+    Ok(())
+}
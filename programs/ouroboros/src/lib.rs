@@ -3,14 +3,24 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
-use state::{asset::*, locker::*, ouroboros::*};
+use state::{
+    asset::*, beneficiary_recipients::*, claimant::*, ouroboros::*, snapshot::*, vesting::*,
+};
 
 declare_id!("3MVR32fVYfnzR1VK8nmUE6XqAVvQy2N2dcHToeB8r78p");
 
+/// The deployer of localnet builds, whose keypair is committed with the tests.
+/// Deployed builds only let the upgrade authority migrate legacy ouroboros.
+#[cfg(feature = "localnet")]
+pub mod localnet_deployer {
+    anchor_lang::declare_id!("CKPHGgR3snABtcMQiqfJvNCx6UgDzFy5Mp3Maz3SP2ug");
+}
+
 #[program]
 mod ouroboros {
     use super::*;
@@ -18,44 +28,182 @@ mod ouroboros {
     /// Initializes the ouroboros
     pub fn initialize_ouroboros(
         ctx: Context<InitializeOuroboros>,
-        bumps: OuroborosBumps,
         ouroboros_id: u64,
         initial_supply: u64,
         period: u64,
         start_date: i64,
         expansion_factor: u64,
         time_multiplier: u64,
+        early_withdraw_penalty: u64,
+        caps: EmissionCaps,
     ) -> ProgramResult {
         instructions::initialize_ouroboros::handler(
             ctx,
-            bumps,
             ouroboros_id,
             initial_supply,
             period,
             start_date,
             expansion_factor,
             time_multiplier,
+            early_withdraw_penalty,
+            caps,
         )
     }
 
+    /// Initializes an ouroboros emitting an existing token
+    pub fn attach_ouroboros(
+        ctx: Context<AttachOuroboros>,
+        ouroboros_id: u64,
+        params: OuroborosParams,
+    ) -> ProgramResult {
+        instructions::attach_ouroboros::handler(ctx, ouroboros_id, params)
+    }
+
     /// Create a beneficiary of the protocol
-    pub fn create_beneficiary(
-        ctx: Context<CreateBeneficiary>,
-        bump: u8,
-        account: Pubkey,
+    pub fn create_beneficiary(ctx: Context<CreateBeneficiary>, account: Pubkey) -> ProgramResult {
+        instructions::create_beneficiary::handler(ctx, account)
+    }
+
+    /// Sets the name, description and links of a beneficiary
+    pub fn set_beneficiary_metadata(
+        ctx: Context<SetBeneficiaryMetadata>,
+        name: String,
+        uri: String,
+        category: String,
     ) -> ProgramResult {
-        instructions::create_beneficiary::handler(ctx, bump, account)
+        instructions::set_beneficiary_metadata::handler(ctx, name, uri, category)
     }
 
     /// Create a token locker
     pub fn create_locker(
         ctx: Context<CreateLocker>,
-        bumps: LockerBumps,
         id: Pubkey,
         amount: u64,
         period: u64,
+        soulbound: bool,
+    ) -> ProgramResult {
+        instructions::create_locker::handler(ctx, id, amount, period, soulbound)
+    }
+
+    /// Create a locker of LP tokens of the native pair
+    pub fn create_lp_locker(
+        ctx: Context<CreateLpLocker>,
+        id: Pubkey,
+        amount: u64,
+        period: u64,
+    ) -> ProgramResult {
+        instructions::create_lp_locker::handler(ctx, id, amount, period)
+    }
+
+    /// Withdraws the tokens of an expired locker
+    pub fn withdraw(ctx: Context<Withdraw>) -> ProgramResult {
+        instructions::withdraw::handler(ctx)
+    }
+
+    /// Withdraws the tokens of a locker before its unlock date with a penalty
+    pub fn early_withdraw(ctx: Context<EarlyWithdraw>, snapshot_index: u64) -> ProgramResult {
+        instructions::early_withdraw::handler(ctx, snapshot_index)
+    }
+
+    /// Creates a merkle distributor of genesis lockers
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        id: Pubkey,
+        root: [u8; 32],
+        num_leaves: u64,
+        total_amount: u64,
+        clawback_timestamp: i64,
+    ) -> ProgramResult {
+        instructions::create_distributor::handler(
+            ctx,
+            id,
+            root,
+            num_leaves,
+            total_amount,
+            clawback_timestamp,
+        )
+    }
+
+    /// Claims an allocation of a distributor as a locker
+    pub fn claim_genesis_locker(
+        ctx: Context<ClaimGenesisLocker>,
+        id: Pubkey,
+        index: u64,
+        amount: u64,
+        period: u64,
+        proof: Vec<[u8; 32]>,
     ) -> ProgramResult {
-        instructions::create_locker::handler(ctx, bumps, id, amount, period)
+        instructions::claim_genesis_locker::handler(ctx, id, index, amount, period, proof)
+    }
+
+    /// Sends unclaimed tokens of a distributor back to its admin
+    pub fn clawback(ctx: Context<Clawback>) -> ProgramResult {
+        instructions::clawback::handler(ctx)
+    }
+
+    /// Creates a vesting of native tokens for a recipient
+    pub fn create_vesting(
+        ctx: Context<CreateVesting>,
+        id: Pubkey,
+        recipient: Pubkey,
+        amount: u64,
+        schedule: VestingSchedule,
+    ) -> ProgramResult {
+        instructions::create_vesting::handler(ctx, id, recipient, amount, schedule)
+    }
+
+    /// Withdraws the vested tokens of a vesting
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> ProgramResult {
+        instructions::withdraw_vested::handler(ctx)
+    }
+
+    /// Sends the unvested tokens of a revocable vesting back to its admin
+    pub fn revoke_vesting(ctx: Context<RevokeVesting>) -> ProgramResult {
+        instructions::revoke_vesting::handler(ctx)
+    }
+
+    /// Locks unvested tokens in a locker held by the vesting
+    pub fn lock_vesting(
+        ctx: Context<LockVesting>,
+        id: Pubkey,
+        amount: u64,
+        period: u64,
+    ) -> ProgramResult {
+        instructions::lock_vesting::handler(ctx, id, amount, period)
+    }
+
+    /// Use the locker of a vesting to vote
+    pub fn cast_vesting_vote(ctx: Context<CastVestingVote>) -> ProgramResult {
+        instructions::cast_vesting_vote::handler(ctx)
+    }
+
+    /// Returns the tokens of an expired vesting locker to the vesting
+    pub fn unlock_vesting(ctx: Context<UnlockVesting>) -> ProgramResult {
+        instructions::unlock_vesting::handler(ctx)
+    }
+
+    /// Keeps a locker at the maximum locking period, or starts its countdown
+    pub fn set_permanent_lock(ctx: Context<SetPermanentLock>, permanent: bool) -> ProgramResult {
+        instructions::set_permanent_lock::handler(ctx, permanent)
+    }
+
+    /// Escrows the receipt of a locker and offers it for sale
+    pub fn list_locker(ctx: Context<ListLocker>, price: u64) -> ProgramResult {
+        instructions::list_locker::handler(ctx, price)
+    }
+
+    /// Buys a listed locker if its terms did not change since it was listed, at the price the buyer expects
+    pub fn buy_locker(
+        ctx: Context<BuyLocker>,
+        expected_price: u64,
+        price_mint: Pubkey,
+    ) -> ProgramResult {
+        instructions::buy_locker::handler(ctx, expected_price, price_mint)
+    }
+
+    /// Returns the receipt of a listed locker to its seller
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> ProgramResult {
+        instructions::cancel_listing::handler(ctx)
     }
 
     /// Use a locker to vote
@@ -64,23 +212,217 @@ mod ouroboros {
     }
 
     /// Claims incentives for a beneficiary
-    pub fn claim_incentives(ctx: Context<ClaimIncentives>) -> ProgramResult {
+    pub fn claim_incentives<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimIncentives<'info>>,
+    ) -> ProgramResult {
         instructions::claim_incentives::handler(ctx)
     }
 
+    /// Claims incentives for a beneficiary and streams them through its gauge
+    pub fn claim_gauge_incentives(ctx: Context<ClaimGaugeIncentives>) -> ProgramResult {
+        instructions::claim_gauge_incentives::handler(ctx)
+    }
+
+    /// Sets the locker in which the incentives of a beneficiary are locked
+    pub fn set_compounding_locker(
+        ctx: Context<SetCompoundingLocker>,
+        locker: Pubkey,
+    ) -> ProgramResult {
+        instructions::set_compounding_locker::handler(ctx, locker)
+    }
+
+    /// Mints the incentives of a page of beneficiaries, in registry order
+    pub fn distribute<'info>(
+        ctx: Context<'_, '_, '_, 'info, Distribute<'info>>,
+    ) -> ProgramResult {
+        instructions::distribute::handler(ctx)
+    }
+
+    /// Adds a beneficiary created before the registry existed
+    pub fn register_beneficiary(ctx: Context<RegisterBeneficiary>) -> ProgramResult {
+        instructions::register_beneficiary::handler(ctx)
+    }
+
+    /// Declares every beneficiary registered, enabling the distribution crank
+    pub fn complete_registry(ctx: Context<CompleteRegistry>) -> ProgramResult {
+        instructions::complete_registry::handler(ctx)
+    }
+
+    /// Sets the share of distributed incentives minted to the keeper
+    pub fn set_keeper_reward(ctx: Context<SetKeeperReward>, keeper_reward: u16) -> ProgramResult {
+        instructions::set_keeper_reward::handler(ctx, keeper_reward)
+    }
+
+    /// Splits the incentives of a beneficiary among multiple accounts
+    pub fn set_beneficiary_recipients(
+        ctx: Context<SetBeneficiaryRecipients>,
+        recipients: Vec<Recipient>,
+    ) -> ProgramResult {
+        instructions::set_beneficiary_recipients::handler(ctx, recipients)
+    }
+
+    /// Claims incentives for a beneficiary and locks them in its locker
+    pub fn claim_compounded_incentives(ctx: Context<ClaimCompoundedIncentives>) -> ProgramResult {
+        instructions::claim_compounded_incentives::handler(ctx)
+    }
+
+    /// Transfers the admin rights of the ouroboros
+    pub fn set_admin(ctx: Context<SetAdmin>, admin: Pubkey) -> ProgramResult {
+        instructions::set_admin::handler(ctx, admin)
+    }
+
+    /// Sets the pair whose LP tokens can be locked
+    pub fn set_native_pair(ctx: Context<SetNativePair>) -> ProgramResult {
+        instructions::set_native_pair::handler(ctx)
+    }
+
+    /// Sets the fee taken on locker sales and the wallet receiving it
+    pub fn set_listing_fee(
+        ctx: Context<SetListingFee>,
+        listing_fee: u16,
+        treasury: Pubkey,
+    ) -> ProgramResult {
+        instructions::set_listing_fee::handler(ctx, listing_fee, treasury)
+    }
+
+    /// Sets how the votes of lockers count towards beneficiaries
+    pub fn set_voting_mode(
+        ctx: Context<SetVotingMode>,
+        voting_mode: VotingMode,
+        vote_cap: u64,
+    ) -> ProgramResult {
+        instructions::set_voting_mode::handler(ctx, voting_mode, vote_cap)
+    }
+
+    /// Counts the votes of a locker under the current voting mode, callable by anyone
+    pub fn update_locker_votes(ctx: Context<UpdateLockerVotes>) -> ProgramResult {
+        instructions::update_locker_votes::handler(ctx)
+    }
+
+    /// Sets the share of received assets bought back and its maximum slippage
+    pub fn set_buyback(
+        ctx: Context<SetBuyback>,
+        buyback_share: u16,
+        buyback_slippage: u16,
+    ) -> ProgramResult {
+        instructions::set_buyback::handler(ctx, buyback_share, buyback_slippage)
+    }
+
+    /// Sets the wallet allowed to pause features
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> ProgramResult {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+
+    /// Pauses features of the ouroboros, by its guardian or admin
+    pub fn pause(ctx: Context<Pause>, features: u64) -> ProgramResult {
+        instructions::pause::handler(ctx, features)
+    }
+
+    /// Resumes paused features of the ouroboros, by its admin
+    pub fn unpause(ctx: Context<Unpause>, features: u64) -> ProgramResult {
+        instructions::unpause::handler(ctx, features)
+    }
+
+    /// Swaps the buyback share of an asset for native tokens and burns them
+    pub fn buyback(ctx: Context<Buyback>) -> ProgramResult {
+        instructions::buyback::handler(ctx)
+    }
+
+    /// Creates the treasury collecting protocol fees
+    pub fn create_treasury(ctx: Context<CreateTreasury>) -> ProgramResult {
+        instructions::create_treasury::handler(ctx)
+    }
+
+    /// Provides liquidity to a pair from the treasury
+    pub fn treasury_add_liquidity(
+        ctx: Context<TreasuryAddLiquidity>,
+        desired_amount_a: u64,
+        desired_amount_b: u64,
+        min_amount_a: u64,
+        min_amount_b: u64,
+    ) -> ProgramResult {
+        instructions::treasury_add_liquidity::handler(
+            ctx,
+            desired_amount_a,
+            desired_amount_b,
+            min_amount_a,
+            min_amount_b,
+        )
+    }
+
+    /// Withdraws liquidity of the treasury from a pair
+    pub fn treasury_remove_liquidity(
+        ctx: Context<TreasuryRemoveLiquidity>,
+        liquidity: u64,
+    ) -> ProgramResult {
+        instructions::treasury_remove_liquidity::handler(ctx, liquidity)
+    }
+
+    /// Stakes LP tokens of the treasury in a gauge
+    pub fn treasury_stake(ctx: Context<TreasuryStake>, amount: u64) -> ProgramResult {
+        instructions::treasury_stake::handler(ctx, amount)
+    }
+
+    /// Withdraws LP tokens of the treasury staked in a gauge
+    pub fn treasury_unstake(ctx: Context<TreasuryUnstake>, amount: u64) -> ProgramResult {
+        instructions::treasury_unstake::handler(ctx, amount)
+    }
+
+    /// Proposes a mint to be accepted as an asset
+    pub fn propose_mint(ctx: Context<ProposeMint>) -> ProgramResult {
+        instructions::propose_mint::handler(ctx)
+    }
+
+    /// Approves or rejects a proposed mint and sets its minimum deposit
+    pub fn set_allowed_mint(
+        ctx: Context<SetAllowedMint>,
+        approved: bool,
+        min_amount: u64,
+    ) -> ProgramResult {
+        instructions::set_allowed_mint::handler(ctx, approved, min_amount)
+    }
+
     /// Called by a bribed service to notify the ouroboros
     pub fn receive_asset(
         ctx: Context<ReceiveAsset>,
-        bumps: AssetBumps,
-        snapshot_bump: u8,
         snapshot_index: u64,
         amount: u64,
     ) -> ProgramResult {
-        instructions::receive_asset::handler(ctx, bumps, snapshot_bump, snapshot_index, amount)
+        instructions::receive_asset::handler(ctx, snapshot_index, amount)
     }
 
     /// Lets a locker collect the fees it has collected for given period
-    pub fn collect_fees(ctx: Context<CollectFees>, bump: u8) -> ProgramResult {
-        instructions::collect_fees::handler(ctx, bump)
+    pub fn collect_fees(ctx: Context<CollectFees>) -> ProgramResult {
+        instructions::collect_fees::handler(ctx)
+    }
+
+    /// Upgrades a ouroboros account to the current layout
+    pub fn migrate_ouroboros(ctx: Context<MigrateOuroboros>) -> ProgramResult {
+        instructions::migrate::ouroboros_handler(ctx)
+    }
+
+    /// Upgrades a locker account to the current layout
+    pub fn migrate_locker(ctx: Context<MigrateWithOuroboros>) -> ProgramResult {
+        instructions::migrate::locker_handler(ctx)
+    }
+
+    /// Upgrades a beneficiary account to the current layout
+    pub fn migrate_beneficiary(ctx: Context<MigrateWithOuroboros>) -> ProgramResult {
+        instructions::migrate::beneficiary_handler(ctx)
+    }
+
+    /// Upgrades a asset account to the current layout
+    pub fn migrate_asset(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Asset>(ctx)
+    }
+
+    /// Upgrades a snapshot account to the current layout
+    pub fn migrate_snapshot(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Snapshot>(ctx)
+    }
+
+    /// Upgrades a claimant account to the current layout
+    pub fn migrate_claimant(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Claimant>(ctx)
     }
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// A mint proposed as an asset of the ouroboros
+#[account]
+#[derive(Default)]
+pub struct AllowedMint {
+    /// The mint of the asset
+    pub mint: Pubkey,
+
+    /// The wallet that proposed the mint
+    pub proposer: Pubkey,
+
+    /// Can the ouroboros receive this asset
+    pub approved: bool,
+
+    /// The minimum amount accepted by `receive_asset`
+    pub min_amount: u64,
+
+    /// The bump used to generate PDAs
+    pub bump: u8,
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::state::{Ouroboros, Snapshot, LegacyAsset, Migratable, Versioned};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct AssetBumps {
     pub asset: u8,
@@ -7,6 +10,29 @@ pub struct AssetBumps {
     pub account: u8
 }
 
+impl AssetBumps {
+    /// Finds the canonical bumps of the PDAs of the asset of `mint`
+    pub fn find(ouroboros_id: u64, mint: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    ouroboros_id.to_le_bytes().as_ref(),
+                    mint.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        AssetBumps {
+            asset: find(b"asset"),
+            authority: find(b"asset_authority"),
+            account: find(b"asset_account"),
+        }
+    }
+}
+
 /// An asset that the protocol received in exchange of incentives
 #[account]
 #[derive(Default)]
@@ -26,6 +52,96 @@ pub struct Asset {
     /// Index of the last snapshot
     pub last_snapshot_index: u64,
 
+    /// The amount of the asset waiting to be swapped and burned
+    pub buyback_amount: u64,
+
+    /// The cumulative price of the asset in native tokens at the last buyback
+    pub price_cumulative: u128,
+
+    /// The time of the last buyback, starting the TWAP window of the next one
+    pub observation_timestamp: i64,
+
     /// The bump used to generate PDAs
     pub bumps: AssetBumps,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Asset {
+    /// The bump of the asset authority: the stored one for assets that already exist, which may
+    /// predate on-chain derivation, and the canonical one for new assets
+    pub fn authority_bump(&self, ouroboros_id: u64, mint: &Pubkey) -> u8 {
+        if self.mint == *mint {
+            self.bumps.authority
+        } else {
+            AssetBumps::find(ouroboros_id, mint, &crate::ID).authority
+        }
+    }
+
+    /// Adds rewards to the snapshot of the current period, initializing it if needed
+    pub fn add_rewards(
+        &mut self,
+        ouroboros: &Ouroboros,
+        snapshot: &mut Snapshot,
+        snapshot_index: u64,
+        amount: u64,
+        now: i64,
+    ) -> ProgramResult {
+        // Uninitialized snapshot
+        if snapshot.timestamp == 0 {
+            snapshot.mint = self.mint;
+            snapshot.timestamp = ouroboros.last_period + ouroboros.period as i64;
+            snapshot.index = self.last_snapshot_index;
+            snapshot.bump = Pubkey::find_program_address(
+                &[
+                    b"snapshot",
+                    ouroboros.id.to_le_bytes().as_ref(),
+                    self.mint.as_ref(),
+                    snapshot_index.to_le_bytes().as_ref(),
+                ],
+                &crate::ID,
+            )
+            .1;
+            snapshot.version = Snapshot::VERSION;
+        } else if snapshot.timestamp != ouroboros.last_period + ouroboros.period as i64
+            || snapshot.index != snapshot_index
+        {
+            return Err(ErrorCode::InvalidSnapshot.into());
+        }
+
+        if self.last_update + (ouroboros.period as i64) < now {
+            self.last_update += ouroboros.period as i64;
+            self.last_snapshot_index += 1;
+        }
+
+        snapshot.rewards += amount;
+        snapshot.votes = ouroboros.total_votes;
+
+        Ok(())
+    }
+}
+
+impl Versioned for Asset {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Asset {
+    type Legacy = LegacyAsset;
+
+    fn from_legacy(legacy: LegacyAsset) -> Self {
+        Asset {
+            mint: legacy.mint,
+            authority: legacy.authority,
+            reward_height: legacy.reward_height,
+            last_update: legacy.last_update,
+            last_snapshot_index: legacy.last_snapshot_index,
+            bumps: legacy.bumps,
+            version: Asset::VERSION,
+            ..Default::default()
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{Locker, Ouroboros, LegacyBeneficiary, Migratable, Versioned};
+
 /// The beneficiary of the incentives
 #[account]
 #[derive(Default)]
@@ -10,12 +12,107 @@ pub struct Beneficiary {
     /// The number of staked tokens voting for this beneficiary
     pub votes: u64,
 
+    /// Votes cast during `pending_epoch`, active from the next epoch
+    pub pending_votes: u64,
+
+    /// The epoch during which pending votes were cast
+    pub pending_epoch: i64,
+
     /// The proportion of incentives this account receives (BP)
     pub weight: u16,
 
     /// Last time this beneficiary was updated
     pub last_update: i64,
 
+    /// The locker receiving incentives, default if incentives are sent to the account
+    pub compounding_locker: Pubkey,
+
+    /// The wallet that created the beneficiary
+    pub creator: Pubkey,
+
+    /// The gauge streaming the incentives, default if not bound to a gauge
+    pub gauge: Pubkey,
+
+    /// The table splitting incentives among recipients, default if incentives are sent to the account
+    pub recipients: Pubkey,
+
     /// The bump used to generate PDAs
     pub bump: u8,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// The position of the beneficiary in the registry plus one, 0 if not registered
+    pub registry_slot: u64,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 7],
+}
+
+impl Beneficiary {
+    /// Activates the votes cast before `epoch`
+    pub fn activate_votes(&mut self, epoch: i64) {
+        if self.pending_epoch < epoch {
+            self.votes += self.pending_votes;
+            self.pending_votes = 0;
+        }
+    }
+
+    /// Adds effective votes of a locker, pending if the locker voted during the current epoch
+    pub fn add_votes(&mut self, ouroboros: &mut Ouroboros, locker: &Locker, votes: u64) {
+        let epoch = ouroboros.last_period;
+        self.activate_votes(epoch);
+        if locker.last_vote_epoch == epoch {
+            self.pending_votes += votes;
+            self.pending_epoch = epoch;
+        } else {
+            self.votes += votes;
+        }
+        ouroboros.add_votes(locker, votes);
+    }
+
+    /// Removes effective votes of a locker, pending if the locker voted during the current epoch
+    pub fn remove_votes(&mut self, ouroboros: &mut Ouroboros, locker: &Locker, votes: u64) {
+        let epoch = ouroboros.last_period;
+        self.activate_votes(epoch);
+        if locker.last_vote_epoch == epoch {
+            self.pending_votes -= votes;
+        } else {
+            self.votes -= votes;
+        }
+        ouroboros.remove_votes(locker, votes);
+    }
+
+    /// Follows the votes of a locker after they changed
+    pub fn update_votes(&mut self, ouroboros: &mut Ouroboros, locker: &mut Locker) {
+        let votes = ouroboros.effective_votes(locker.votes);
+        if votes > locker.effective_votes {
+            self.add_votes(ouroboros, locker, votes - locker.effective_votes);
+        } else {
+            self.remove_votes(ouroboros, locker, locker.effective_votes - votes);
+        }
+        locker.effective_votes = votes;
+    }
+}
+
+impl Versioned for Beneficiary {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Beneficiary {
+    type Legacy = LegacyBeneficiary;
+
+    fn from_legacy(legacy: LegacyBeneficiary) -> Self {
+        Beneficiary {
+            account: legacy.account,
+            votes: legacy.votes,
+            weight: legacy.weight,
+            last_update: legacy.last_update,
+            bump: legacy.bump,
+            version: Beneficiary::VERSION,
+            // Legacy beneficiaries are registered by the admin
+            registry_slot: 0,
+            ..Default::default()
+        }
+    }
 }
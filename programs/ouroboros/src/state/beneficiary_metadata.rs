@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Describes a beneficiary to voting interfaces
+#[account]
+#[derive(Default)]
+pub struct BeneficiaryMetadata {
+    /// The beneficiary described
+    pub beneficiary: Pubkey,
+
+    /// The display name
+    pub name: String,
+
+    /// A link to an off-chain description
+    pub uri: String,
+
+    /// The kind of service provided by the beneficiary
+    pub category: String,
+
+    /// The pair of the pools program served by the beneficiary, default if none
+    pub pair: Pubkey,
+
+    /// The gauge served by the beneficiary, default if none
+    pub gauge: Pubkey,
+
+    /// The bump used to generate PDAs
+    pub bump: u8,
+}
+
+impl BeneficiaryMetadata {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_CATEGORY_LEN: usize = 32;
+
+    /// Computes the space needed by metadata with strings of maximum length
+    pub fn space() -> usize {
+        8 + BeneficiaryMetadata::default().try_to_vec().unwrap().len()
+            + BeneficiaryMetadata::MAX_NAME_LEN
+            + BeneficiaryMetadata::MAX_URI_LEN
+            + BeneficiaryMetadata::MAX_CATEGORY_LEN
+    }
+}
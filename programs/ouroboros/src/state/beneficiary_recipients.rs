@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// An account receiving a share of the incentives of a beneficiary
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Recipient {
+    /// The token account receiving incentives
+    pub account: Pubkey,
+
+    /// The proportion of incentives this account receives (BP)
+    pub share: u16,
+}
+
+/// Splits the incentives of a beneficiary among multiple accounts
+#[account]
+#[derive(Default)]
+pub struct BeneficiaryRecipients {
+    /// The beneficiary whose incentives are split
+    pub beneficiary: Pubkey,
+
+    /// The accounts receiving incentives, in the order they are passed when claiming
+    pub recipients: Vec<Recipient>,
+
+    /// The bump used to generate PDAs
+    pub bump: u8,
+}
+
+impl BeneficiaryRecipients {
+    /// The maximum number of recipients of a beneficiary
+    pub const CAPACITY: usize = 8;
+
+    /// Computes the space needed by a full table
+    pub fn space() -> usize {
+        8 + BeneficiaryRecipients::default().try_to_vec().unwrap().len()
+            + Recipient::default().try_to_vec().unwrap().len() * BeneficiaryRecipients::CAPACITY
+    }
+
+    /// Checks that the shares of recipients add up to 100%
+    pub fn is_complete(recipients: &[Recipient]) -> bool {
+        recipients.iter().map(|r| r.share as u64).sum::<u64>() == 10000
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Ouroboros;
+
+/// A page of the list of beneficiaries of an ouroboros
+#[account]
+#[derive(Default)]
+pub struct BeneficiaryRegistry {
+    /// The index of the page
+    pub index: u64,
+
+    /// The beneficiaries, in creation order
+    pub beneficiaries: Vec<Pubkey>,
+
+    /// The bump used to generate PDAs
+    pub bump: u8,
+}
+
+impl BeneficiaryRegistry {
+    /// The number of beneficiaries listed by a page
+    pub const CAPACITY: u64 = 100;
+
+    /// Computes the space needed by a full page
+    pub fn space() -> usize {
+        8 + BeneficiaryRegistry::default().try_to_vec().unwrap().len()
+            + 32 * BeneficiaryRegistry::CAPACITY as usize
+    }
+
+    /// Appends a beneficiary to this page, which must be the last one, and returns its slot
+    pub fn register(
+        &mut self,
+        ouroboros: &mut Ouroboros,
+        beneficiary: Pubkey,
+        program_id: &Pubkey,
+    ) -> u64 {
+        self.index = ouroboros.num_beneficiaries / BeneficiaryRegistry::CAPACITY;
+        self.bump = Pubkey::find_program_address(
+            &[
+                b"registry",
+                ouroboros.id.to_le_bytes().as_ref(),
+                self.index.to_le_bytes().as_ref(),
+            ],
+            program_id,
+        )
+        .1;
+        self.beneficiaries.push(beneficiary);
+        ouroboros.num_beneficiaries += 1;
+
+        ouroboros.num_beneficiaries
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{LegacyClaimant, Migratable, Versioned};
+
 /// Represents a locker holder's claim history for a specific asset
 #[account]
 #[derive(Default)]
@@ -15,4 +17,29 @@ pub struct Claimant {
 
     /// The bump used to generate PDAs
     pub bump: u8,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Versioned for Claimant {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Claimant {
+    type Legacy = LegacyClaimant;
+
+    fn from_legacy(legacy: LegacyClaimant) -> Self {
+        Claimant {
+            owner: legacy.owner,
+            mint: legacy.mint,
+            last_claim: legacy.last_claim,
+            bump: legacy.bump,
+            version: Claimant::VERSION,
+            ..Default::default()
+        }
+    }
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DistributorBumps {
+    pub distributor: u8,
+    pub account: u8,
+}
+
+impl DistributorBumps {
+    /// Finds the canonical bumps of the PDAs of the distributor `id`
+    pub fn find(ouroboros_id: u64, id: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    ouroboros_id.to_le_bytes().as_ref(),
+                    id.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        DistributorBumps {
+            distributor: find(b"distributor"),
+            account: find(b"distributor_account"),
+        }
+    }
+}
+
+/// A merkle distributor creating lockers from an initial allocation
+#[account]
+#[derive(Default)]
+pub struct Distributor {
+    /// The unique identifier
+    pub id: Pubkey,
+
+    /// The wallet that created the distributor and can claw back tokens
+    pub admin: Pubkey,
+
+    /// The root of the merkle tree of (index, recipient, amount, lock_period) leaves
+    pub root: [u8; 32],
+
+    /// The number of leaves of the tree
+    pub num_leaves: u64,
+
+    /// The amount of tokens deposited
+    pub total_amount: u64,
+
+    /// The amount of tokens claimed
+    pub claimed_amount: u64,
+
+    /// The date after which unclaimed tokens can be clawed back
+    pub clawback_timestamp: i64,
+
+    /// The bumps used to generate PDAs
+    pub bumps: DistributorBumps,
+
+    /// One bit per leaf, set once the leaf is claimed
+    pub claimed: Vec<u8>,
+}
+
+impl Distributor {
+    /// Computes the space needed by a distributor with `num_leaves` leaves
+    pub fn space(num_leaves: u64) -> usize {
+        8 + Distributor::default().try_to_vec().unwrap().len() + Distributor::bitmap_len(num_leaves)
+    }
+
+    /// Computes the length of the bitmap of claimed leaves
+    pub fn bitmap_len(num_leaves: u64) -> usize {
+        num_leaves as usize / 8 + 1
+    }
+
+    /// Checks if the leaf at `index` was claimed
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    /// Marks the leaf at `index` as claimed
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed[(index / 8) as usize] |= 1 << (index % 8);
+    }
+
+    /// Verifies that a leaf is part of the tree
+    pub fn verify(&self, leaf: &[u8], proof: &[[u8; 32]]) -> bool {
+        let mut node = keccak::hashv(&[&[0x00], leaf]).0;
+        for sibling in proof {
+            node = if node <= *sibling {
+                keccak::hashv(&[&[0x01], &node, sibling]).0
+            } else {
+                keccak::hashv(&[&[0x01], sibling, &node]).0
+            };
+        }
+
+        node == self.root
+    }
+}
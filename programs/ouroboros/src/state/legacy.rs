@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AssetBumps, LockerBumps, OuroborosBumps};
+
+/// The layout of an ouroboros before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyOuroboros {
+    pub id: u64,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub period: u64,
+    pub last_period: i64,
+    pub last_period_votes: u64,
+    pub total_votes: u64,
+    pub expansion_factor: u64,
+    pub time_multiplier: u64,
+    pub bumps: OuroborosBumps,
+}
+
+/// The layout of a locker before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyLocker {
+    pub id: Pubkey,
+    pub receipt: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    pub votes: u64,
+    pub creation_timestamp: i64,
+    pub unlock_timestamp: i64,
+    pub bumps: LockerBumps,
+}
+
+/// The layout of a beneficiary before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyBeneficiary {
+    pub account: Pubkey,
+    pub votes: u64,
+    pub weight: u16,
+    pub last_update: i64,
+    pub bump: u8,
+}
+
+/// The layout of an asset before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyAsset {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub reward_height: u64,
+    pub last_update: i64,
+    pub last_snapshot_index: u64,
+    pub bumps: AssetBumps,
+}
+
+/// The layout of a snapshot before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacySnapshot {
+    pub mint: Pubkey,
+    pub timestamp: i64,
+    pub index: u64,
+    pub rewards: u64,
+    pub votes: u64,
+    pub bump: u8,
+}
+
+/// The layout of a claimant before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyClaimant {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub last_claim: i64,
+    pub bump: u8,
+}
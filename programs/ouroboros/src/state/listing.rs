@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ListingBumps {
+    pub listing: u8,
+    pub escrow: u8,
+}
+
+impl ListingBumps {
+    /// Finds the canonical bumps of the PDAs of the listing of the locker `locker_id`
+    pub fn find(ouroboros_id: u64, locker_id: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    ouroboros_id.to_le_bytes().as_ref(),
+                    locker_id.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        ListingBumps {
+            listing: find(b"listing"),
+            escrow: find(b"listing_account"),
+        }
+    }
+}
+
+/// A locker offered for sale, its receipt held in escrow
+#[account]
+#[derive(Default)]
+pub struct Listing {
+    /// The locker being sold
+    pub locker: Pubkey,
+
+    /// The wallet selling the locker
+    pub seller: Pubkey,
+
+    /// The mint in which the price is paid
+    pub price_mint: Pubkey,
+
+    /// The price of the locker
+    pub price: u64,
+
+    /// The amount of tokens locked when listed
+    pub amount: u64,
+
+    /// The votes of the locker when listed
+    pub votes: u64,
+
+    /// The unlock date of the locker when listed
+    pub unlock_timestamp: i64,
+
+    /// The bump used to generate PDAs
+    pub bumps: ListingBumps,
+}
+
+impl Listing {
+    /// Checks that the locker still has the terms it was listed with
+    pub fn matches(&self, amount: u64, votes: u64, unlock_timestamp: i64) -> bool {
+        self.amount == amount && self.votes == votes && self.unlock_timestamp == unlock_timestamp
+    }
+}
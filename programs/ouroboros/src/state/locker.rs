@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::state::{sqrt, LegacyLocker, Migratable, Versioned};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct LockerBumps {
     pub locker: u8,
@@ -7,6 +10,29 @@ pub struct LockerBumps {
     pub account: u8
 }
 
+impl LockerBumps {
+    /// Finds the canonical bumps of the PDAs of the locker `id`
+    pub fn find(ouroboros_id: u64, id: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    ouroboros_id.to_le_bytes().as_ref(),
+                    id.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        LockerBumps {
+            locker: find(b"locker"),
+            receipt: find(b"receipt"),
+            account: find(b"locker_account"),
+        }
+    }
+}
+
 /// A vesting locker account
 #[account]
 #[derive(Default)]
@@ -20,18 +46,138 @@ pub struct Locker {
     /// The beneficiary of this locker's vote
     pub beneficiary: Pubkey,
 
+    /// The mint of the locked tokens, native or LP tokens of the native pair
+    pub mint: Pubkey,
+
     /// The amount of tokens locked
     pub amount: u64,
 
+    /// The value of the locked tokens in native tokens
+    pub value: u64,
+
     /// Votes granted by this locker
     pub votes: u64,
 
+    /// Votes counted for its beneficiary under the voting mode of the last vote
+    pub effective_votes: u64,
+
+    /// The epoch of the last vote of this locker
+    pub last_vote_epoch: i64,
+
     /// The creation date of the locker
     pub creation_timestamp: i64,
 
-    /// The unlock date of the locker
+    /// The unlock date of the locker, ignored while permanent
     pub unlock_timestamp: i64,
 
+    /// Is the locker kept at the maximum locking period
+    pub permanent: bool,
+
+    /// Is the receipt frozen in the wallet of its creator
+    pub soulbound: bool,
+
     /// The bump used to generate PDAs
     pub bumps: LockerBumps,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Locker {
+    /// The longest locking period, granted to permanent lockers
+    pub const MAX_PERIOD: u64 = 4 * 365 * 86400;
+
+    /// Checks the amount and period a locker is created with
+    pub fn validate(amount: u64, period: u64) -> ProgramResult {
+        if amount == 0 {
+            return Err(ErrorCode::ZeroAmount.into());
+        }
+        if period == 0 || period > Locker::MAX_PERIOD {
+            return Err(ErrorCode::InvalidPeriod.into());
+        }
+
+        Ok(())
+    }
+
+    /// Computes the votes granted by locking `amount` tokens for `period` seconds
+    pub fn compute_votes(amount: u64, period: u64, time_multiplier: u64) -> u64 {
+        (amount as u128 * period as u128 * time_multiplier as u128 / 604800 / 10000) as u64
+    }
+
+    /// Computes the votes of the locker for its value at the maximum locking period
+    pub fn permanent_votes(&self, time_multiplier: u64) -> u64 {
+        Locker::compute_votes(self.value, Locker::MAX_PERIOD, time_multiplier)
+    }
+
+    /// Computes the native value of `amount` LP tokens of a pair priced at `twap` (Q64.64).
+    /// The pool is worth `2 * sqrt(k * twap)`: unlike reserves, `k` can't be moved by a swap.
+    pub fn lp_value(
+        amount: u64,
+        native_reserve: u64,
+        other_reserve: u64,
+        twap: u128,
+        lp_supply: u64,
+    ) -> Result<u64, ProgramError> {
+        if lp_supply == 0 {
+            return Ok(0);
+        }
+
+        let k = native_reserve as u128 * other_reserve as u128;
+        let pool_value = (sqrt(k)
+            .checked_mul(sqrt(twap))
+            .ok_or(ErrorCode::MathOverflow)?
+            >> 32)
+            * 2;
+        let value = (amount as u128)
+            .checked_mul(pool_value)
+            .ok_or(ErrorCode::MathOverflow)?
+            / lp_supply as u128;
+        if value > u64::MAX as u128 {
+            return Err(ErrorCode::MathOverflow.into());
+        }
+
+        Ok(value as u64)
+    }
+
+    /// Computes the amount of locked tokens forfeited when withdrawing at `now`
+    pub fn early_withdraw_penalty(&self, max_penalty: u64, now: i64) -> u64 {
+        if now >= self.unlock_timestamp {
+            return 0;
+        }
+
+        let remaining = (self.unlock_timestamp - now) as u128;
+        let duration = (self.unlock_timestamp - self.creation_timestamp) as u128;
+        (self.amount as u128 * max_penalty as u128 * remaining / duration / 10000) as u64
+    }
+}
+
+impl Versioned for Locker {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Locker {
+    type Legacy = LegacyLocker;
+
+    fn from_legacy(legacy: LegacyLocker) -> Self {
+        Locker {
+            id: legacy.id,
+            receipt: legacy.receipt,
+            beneficiary: legacy.beneficiary,
+            // Legacy lockers hold native tokens, the mint is set by the migration
+            mint: Pubkey::default(),
+            amount: legacy.amount,
+            value: legacy.amount,
+            votes: legacy.votes,
+            // Legacy votes were linear
+            effective_votes: legacy.votes,
+            creation_timestamp: legacy.creation_timestamp,
+            unlock_timestamp: legacy.unlock_timestamp,
+            bumps: legacy.bumps,
+            version: Locker::VERSION,
+            ..Default::default()
+        }
+    }
 }
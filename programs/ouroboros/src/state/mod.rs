@@ -1,13 +1,33 @@
+pub mod allowed_mint;
 pub mod asset;
 pub mod beneficiary;
+pub mod beneficiary_metadata;
+pub mod beneficiary_recipients;
+pub mod beneficiary_registry;
 pub mod claimant;
+pub mod distributor;
+pub mod legacy;
+pub mod listing;
 pub mod locker;
 pub mod ouroboros;
 pub mod snapshot;
+pub mod treasury;
+pub mod version;
+pub mod vesting;
 
+pub use allowed_mint::*;
 pub use asset::*;
 pub use beneficiary::*;
+pub use beneficiary_metadata::*;
+pub use beneficiary_recipients::*;
+pub use beneficiary_registry::*;
 pub use claimant::*;
+pub use distributor::*;
+pub use legacy::*;
+pub use listing::*;
 pub use locker::*;
 pub use ouroboros::*;
 pub use snapshot::*;
+pub use treasury::*;
+pub use version::*;
+pub use vesting::*;
@@ -1,12 +1,143 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::ErrorCode;
+use crate::state::{Locker, LegacyOuroboros, Migratable, Versioned};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct OuroborosBumps {
     pub ouroboros: u8,
     pub authority: u8,
+    /// 0 if the ouroboros is attached to an existing mint
     pub mint: u8,
 }
 
+impl OuroborosBumps {
+    /// Finds the canonical bumps of the PDAs of the Ouroboros `ouroboros_id`
+    pub fn find(ouroboros_id: u64, program_id: &Pubkey) -> Self {
+        OuroborosBumps {
+            mint: find_bump(b"mint", ouroboros_id, program_id),
+            ..OuroborosBumps::find_attached(ouroboros_id, program_id)
+        }
+    }
+
+    /// Finds the canonical bumps of an Ouroboros attached to an existing mint, without mint PDA
+    pub fn find_attached(ouroboros_id: u64, program_id: &Pubkey) -> Self {
+        OuroborosBumps {
+            ouroboros: find_bump(b"ouroboros", ouroboros_id, program_id),
+            authority: find_bump(b"authority", ouroboros_id, program_id),
+            mint: 0,
+        }
+    }
+}
+
+/// Finds the canonical bump of the PDA `seed` of the Ouroboros `ouroboros_id`
+fn find_bump(seed: &[u8], ouroboros_id: u64, program_id: &Pubkey) -> u8 {
+    Pubkey::find_program_address(&[seed, ouroboros_id.to_le_bytes().as_ref()], program_id).1
+}
+
+/// Limits on the share of emissions a single beneficiary can receive
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EmissionCaps {
+    /// The maximum weight (BP) of a beneficiary, 10000 to disable
+    pub max_weight: u16,
+
+    /// The minimum weight (BP) for a beneficiary to receive incentives
+    pub min_weight: u16,
+
+    /// Are forfeited incentives distributed in the next period, or never minted
+    pub redistribute: bool,
+}
+
+impl Default for EmissionCaps {
+    /// No limits
+    fn default() -> Self {
+        EmissionCaps {
+            max_weight: 10000,
+            min_weight: 0,
+            redistribute: false,
+        }
+    }
+}
+
+impl EmissionCaps {
+    /// Checks that weights are at most 100%, not null and ordered
+    pub fn is_valid(&self) -> bool {
+        self.max_weight > 0 && self.max_weight <= 10000 && self.min_weight <= self.max_weight
+    }
+
+    /// Computes the weight a beneficiary effectively receives incentives for
+    pub fn capped_weight(&self, weight: u16) -> u16 {
+        if weight < self.min_weight {
+            0
+        } else if weight > self.max_weight {
+            self.max_weight
+        } else {
+            weight
+        }
+    }
+}
+
+/// How the votes of a locker count towards its beneficiary
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum VotingMode {
+    /// Every vote counts
+    Linear,
+
+    /// A locker counts for the square root of its votes
+    Quadratic,
+
+    /// A locker counts for at most `vote_cap` votes
+    Capped,
+}
+
+// `#[default]` on enum variants is too recent for the BPF toolchain
+#[allow(clippy::derivable_impls)]
+impl Default for VotingMode {
+    fn default() -> Self {
+        VotingMode::Linear
+    }
+}
+
+/// Features of an ouroboros that the guardian can pause
+pub mod pause {
+    /// Creation of lockers, from tokens, LP tokens, vestings or the genesis distributor
+    pub const LOCKS: u64 = 1;
+
+    /// Claims of incentives and of received assets
+    pub const CLAIMS: u64 = 1 << 1;
+
+    /// Every feature that can be paused
+    pub const ALL: u64 = LOCKS | CLAIMS;
+}
+
+/// Settings of an ouroboros
+pub mod flags {
+    /// Every beneficiary is listed in the registry, so the crank can distribute incentives
+    pub const REGISTRY_COMPLETE: u64 = 1;
+}
+
+/// The parameters of an ouroboros attached to an existing mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OuroborosParams {
+    /// The reward period in seconds
+    pub period: u64,
+
+    /// The start of the first period
+    pub start_date: i64,
+
+    /// The % in BP of circulating supply expansion per period
+    pub expansion_factor: u64,
+
+    /// The weekly multiplier of votes in BP
+    pub time_multiplier: u64,
+
+    /// The maximum penalty in BP applied to early withdrawals
+    pub early_withdraw_penalty: u64,
+
+    /// Limits on the weight of each beneficiary
+    pub caps: EmissionCaps,
+}
+
 /// The state of the ouroboros
 #[account]
 #[derive(Default)]
@@ -17,6 +148,9 @@ pub struct Ouroboros {
     /// The authority over native and locked tokens
     pub authority: Pubkey,
 
+    /// The wallet allowed to change the parameters of the ouroboros
+    pub admin: Pubkey,
+
     /// The mint of the token distributed to stakers
     pub mint: Pubkey,
 
@@ -29,6 +163,21 @@ pub struct Ouroboros {
     /// Votes of the last period
     pub last_period_votes: u64,
 
+    /// Effective votes cast for beneficiaries, active since an earlier epoch
+    pub cast_votes: u64,
+
+    /// Effective votes cast during `pending_epoch`, active from the next epoch
+    pub pending_votes: u64,
+
+    /// The epoch during which pending votes were cast
+    pub pending_epoch: i64,
+
+    /// How the votes of a locker count towards its beneficiary
+    pub voting_mode: VotingMode,
+
+    /// The maximum effective votes of a locker in capped mode
+    pub vote_cap: u64,
+
     /// Total number of votes staked
     pub total_votes: u64,
 
@@ -41,6 +190,254 @@ pub struct Ouroboros {
     /// Example: 192 ~ staking for 2 year earns twice as much as 1 year locking
     pub time_multiplier: u64,
 
+    /// The maximum penalty in BP applied to early withdrawals.
+    /// The penalty decreases linearly with the remaining locking time.
+    pub early_withdraw_penalty: u64,
+
+    /// Limits on the weight of each beneficiary
+    pub caps: EmissionCaps,
+
+    /// Incentives forfeited by capped beneficiaries during the current period
+    pub forfeited_emissions: u64,
+
+    /// Incentives forfeited during the last period, distributed on top of emissions
+    pub redistributed_emissions: u64,
+
+    /// The pair whose LP tokens can be locked, default if none
+    pub native_pair: Pubkey,
+
+    /// The number of beneficiaries listed in the registry
+    pub num_beneficiaries: u64,
+
+    /// The wallet receiving protocol fees
+    pub treasury: Pubkey,
+
+    /// The fee in BP taken on locker sales
+    pub listing_fee: u16,
+
+    /// The share in BP of received assets swapped for native tokens and burned
+    pub buyback_share: u16,
+
+    /// The maximum deviation in BP of a buyback from the TWAP of its pair
+    pub buyback_slippage: u16,
+
+    /// The total amount of native tokens burned by buybacks
+    pub total_burned: u64,
+
+    /// The amount of native tokens burned during the period starting at `burned_epoch`
+    pub epoch_burned: u64,
+
+    /// The period during which `epoch_burned` tokens were burned
+    pub burned_epoch: i64,
+
+    /// The epoch whose incentives are being distributed by the crank
+    pub distribution_epoch: i64,
+
+    /// The number of registered beneficiaries processed by the crank during `distribution_epoch`
+    pub distribution_cursor: u64,
+
+    /// The share in BP of distributed incentives minted to the keeper running the crank
+    pub keeper_reward: u16,
+
     /// The bump used to generate PDAs
     pub bumps: OuroborosBumps,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// The wallet allowed to pause features, default if none
+    pub guardian: Pubkey,
+
+    /// The paused features, as a bitmask of `pause` flags
+    pub paused: u64,
+
+    /// The settings of the ouroboros, as a bitmask of `flags`
+    pub flags: u64,
+
+    /// The native value of locked LP tokens, kept apart from locked native tokens
+    pub lp_votes: u64,
+
+    /// The cumulative price of the other token of the native pair when the TWAP window opened
+    pub native_price_cumulative: u128,
+
+    /// The start of the TWAP window of the native pair
+    pub native_observation_timestamp: i64,
+
+    /// The price of the other token of the native pair in native tokens (Q64.64)
+    /// over the last TWAP window, 0 until a window is over
+    pub native_twap: u128,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 2],
+}
+
+impl Ouroboros {
+    /// Checks the parameters an ouroboros is created with
+    pub fn validate_params(
+        period: u64,
+        early_withdraw_penalty: u64,
+        caps: &EmissionCaps,
+    ) -> ProgramResult {
+        if period == 0 {
+            return Err(ErrorCode::InvalidPeriod.into());
+        }
+        if early_withdraw_penalty > 10000 {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+        if !caps.is_valid() {
+            return Err(ErrorCode::InvalidCaps.into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails if any of the given features is paused
+    pub fn check_active(&self, features: u64) -> ProgramResult {
+        if self.paused & features != 0 {
+            return Err(ErrorCode::Paused.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the given features are known and not empty
+    pub fn validate_features(features: u64) -> ProgramResult {
+        if features == 0 || features & !pause::ALL != 0 {
+            return Err(ErrorCode::InvalidFeatures.into());
+        }
+
+        Ok(())
+    }
+
+    /// Computes the votes a locker counts for under the voting mode
+    pub fn effective_votes(&self, votes: u64) -> u64 {
+        match self.voting_mode {
+            VotingMode::Linear => votes,
+            VotingMode::Quadratic => sqrt(votes as u128) as u64,
+            VotingMode::Capped => votes.min(self.vote_cap),
+        }
+    }
+
+    /// Activates the votes cast before `epoch`
+    pub fn activate_votes(&mut self, epoch: i64) {
+        if self.pending_epoch < epoch {
+            self.cast_votes += self.pending_votes;
+            self.pending_votes = 0;
+        }
+    }
+
+    /// Adds effective votes of a locker, pending if the locker voted during the current epoch
+    pub fn add_votes(&mut self, locker: &Locker, votes: u64) {
+        self.activate_votes(self.last_period);
+        if locker.last_vote_epoch == self.last_period {
+            self.pending_votes += votes;
+            self.pending_epoch = self.last_period;
+        } else {
+            self.cast_votes += votes;
+        }
+    }
+
+    /// Removes effective votes of a locker, pending if the locker voted during the current epoch
+    pub fn remove_votes(&mut self, locker: &Locker, votes: u64) {
+        self.activate_votes(self.last_period);
+        if locker.last_vote_epoch == self.last_period {
+            self.pending_votes -= votes;
+        } else {
+            self.cast_votes -= votes;
+        }
+    }
+
+    /// Computes the votes active during the period that just ended
+    pub fn period_votes(&mut self) -> u64 {
+        self.activate_votes(self.last_period - self.period as i64);
+        self.cast_votes
+    }
+
+    /// Opens the TWAP window of the native pair at `now`
+    pub fn observe_native_price(&mut self, price_cumulative: u128, now: i64) {
+        self.native_price_cumulative = price_cumulative;
+        self.native_observation_timestamp = now;
+    }
+
+    /// Computes the price of the other token of the native pair in native tokens (Q64.64).
+    /// The price is averaged over a window of at least a period, which opens again once over.
+    pub fn native_twap(&mut self, price_cumulative: u128, now: i64) -> Result<u128, ProgramError> {
+        let elapsed = now - self.native_observation_timestamp;
+        if elapsed >= self.period as i64 {
+            self.native_twap =
+                price_cumulative.wrapping_sub(self.native_price_cumulative) / elapsed as u128;
+            self.observe_native_price(price_cumulative, now);
+        }
+        if self.native_twap == 0 {
+            return Err(ErrorCode::LpPriceUnavailable.into());
+        }
+
+        Ok(self.native_twap)
+    }
+
+    /// Records native tokens burned during the current period
+    pub fn record_burn(&mut self, amount: u64) {
+        if self.burned_epoch != self.last_period {
+            self.burned_epoch = self.last_period;
+            self.epoch_burned = 0;
+        }
+        self.epoch_burned += amount;
+        self.total_burned += amount;
+    }
+
+    /// Tokens burned during the current period, which still count in its supply
+    pub fn period_burned(&self) -> u64 {
+        if self.burned_epoch == self.last_period {
+            self.epoch_burned
+        } else {
+            0
+        }
+    }
+}
+
+/// Computes the integer square root of `n`
+pub fn sqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    let mut x = n;
+    let mut y = n / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+impl Versioned for Ouroboros {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Ouroboros {
+    type Legacy = LegacyOuroboros;
+
+    fn from_legacy(legacy: LegacyOuroboros) -> Self {
+        Ouroboros {
+            id: legacy.id,
+            authority: legacy.authority,
+            // Legacy ouroboros had no admin, the migration hands them to the upgrade authority
+            admin: Pubkey::default(),
+            treasury: Pubkey::default(),
+            mint: legacy.mint,
+            period: legacy.period,
+            last_period: legacy.last_period,
+            last_period_votes: legacy.last_period_votes,
+            // Votes of beneficiaries are added back as they are migrated
+            cast_votes: 0,
+            total_votes: legacy.total_votes,
+            expansion_factor: legacy.expansion_factor,
+            time_multiplier: legacy.time_multiplier,
+            bumps: legacy.bumps,
+            version: Ouroboros::VERSION,
+            // Legacy beneficiaries are not in the registry
+            flags: 0,
+            ..Default::default()
+        }
+    }
 }
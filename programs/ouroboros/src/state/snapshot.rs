@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{LegacySnapshot, Migratable, Versioned};
+
 /// Snapshot of an asset's reserve at a given period
 #[account]
 #[derive(Default)]
@@ -21,4 +23,31 @@ pub struct Snapshot {
 
     /// The bump used to generate PDAs
     pub bump: u8,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Versioned for Snapshot {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Snapshot {
+    type Legacy = LegacySnapshot;
+
+    fn from_legacy(legacy: LegacySnapshot) -> Self {
+        Snapshot {
+            mint: legacy.mint,
+            timestamp: legacy.timestamp,
+            index: legacy.index,
+            rewards: legacy.rewards,
+            votes: legacy.votes,
+            bump: legacy.bump,
+            version: Snapshot::VERSION,
+            ..Default::default()
+        }
+    }
 }
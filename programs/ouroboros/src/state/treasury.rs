@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// The treasury of an ouroboros, owning protocol fees and liquidity
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    /// The ouroboros
+    pub ouroboros: Pubkey,
+
+    /// The bump used to generate the PDA
+    pub bump: u8,
+}
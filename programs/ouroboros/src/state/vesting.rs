@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct VestingBumps {
+    pub vesting: u8,
+    pub account: u8,
+}
+
+impl VestingBumps {
+    /// Finds the canonical bumps of the PDAs of the vesting `id`
+    pub fn find(ouroboros_id: u64, id: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(
+                &[
+                    seed,
+                    ouroboros_id.to_le_bytes().as_ref(),
+                    id.as_ref(),
+                ],
+                program_id,
+            )
+            .1
+        };
+
+        VestingBumps {
+            vesting: find(b"vesting"),
+            account: find(b"vesting_account"),
+        }
+    }
+}
+
+/// A linear release of tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct VestingSchedule {
+    /// The start of the linear release
+    pub start_timestamp: i64,
+
+    /// Nothing can be withdrawn before this date
+    pub cliff_timestamp: i64,
+
+    /// The date at which all tokens are vested
+    pub end_timestamp: i64,
+
+    /// Can the admin take back unvested tokens
+    pub revocable: bool,
+}
+
+impl VestingSchedule {
+    /// Checks that the dates of the schedule are ordered
+    pub fn is_valid(&self) -> bool {
+        self.start_timestamp <= self.cliff_timestamp
+            && self.cliff_timestamp <= self.end_timestamp
+            && self.start_timestamp < self.end_timestamp
+    }
+}
+
+/// A vesting of native tokens
+#[account]
+#[derive(Default)]
+pub struct Vesting {
+    /// The unique identifier
+    pub id: Pubkey,
+
+    /// The wallet that funded the vesting and can revoke it
+    pub admin: Pubkey,
+
+    /// The wallet receiving vested tokens
+    pub recipient: Pubkey,
+
+    /// The amount of tokens vesting
+    pub total_amount: u64,
+
+    /// The amount of tokens already withdrawn by the recipient
+    pub withdrawn_amount: u64,
+
+    /// The release of tokens
+    pub schedule: VestingSchedule,
+
+    /// Was the vesting revoked, in which case `total_amount` is fully vested
+    pub revoked: bool,
+
+    /// The locker holding vesting tokens, default if none
+    pub locker: Pubkey,
+
+    /// The bumps used to generate PDAs
+    pub bumps: VestingBumps,
+}
+
+impl Vesting {
+    /// Computes the amount of tokens vested at `now`
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let schedule = &self.schedule;
+        if self.revoked || now >= schedule.end_timestamp {
+            return self.total_amount;
+        }
+        if now < schedule.cliff_timestamp {
+            return 0;
+        }
+
+        let elapsed = (now - schedule.start_timestamp) as u128;
+        let duration = (schedule.end_timestamp - schedule.start_timestamp) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+}
@@ -14,4 +14,20 @@ pub enum ErrorCode {
     InsufficientOutput,
     #[msg("Violated invariant K")]
     InvariantK,
+    #[msg("Account already uses the current layout")]
+    AlreadyMigrated,
+    #[msg("Amount must be positive")]
+    ZeroAmount,
+    #[msg("Amount is below the given minimum")]
+    SlippageExceeded,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Feature is paused")]
+    Paused,
+    #[msg("Unknown or empty pause features")]
+    InvalidFeatures,
+    #[msg("Signer is not allowed to perform this action")]
+    Unauthorized,
+    #[msg("Transaction was processed after its deadline")]
+    DeadlineExpired,
 }
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a pair is created
+#[event]
+pub struct PairCreated {
+    /// The pair
+    #[index]
+    pub pair: Pubkey,
+
+    /// The first token of the pair
+    pub mint_a: Pubkey,
+
+    /// The second token of the pair
+    pub mint_b: Pubkey,
+
+    /// Is the pool using correlated assets
+    pub stable: bool,
+
+    /// The time of creation
+    pub timestamp: i64,
+}
+
+/// Emitted when liquidity is provided to a pair
+#[event]
+pub struct LiquidityAdded {
+    /// The pair
+    #[index]
+    pub pair: Pubkey,
+
+    /// The wallet providing liquidity
+    pub liquidity_provider: Pubkey,
+
+    /// The amount of token A deposited
+    pub amount_a: u64,
+
+    /// The amount of token B deposited
+    pub amount_b: u64,
+
+    /// The amount of liquidity tokens minted
+    pub liquidity: u64,
+
+    /// The time of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted when liquidity is removed from a pair
+#[event]
+pub struct LiquidityRemoved {
+    /// The pair
+    #[index]
+    pub pair: Pubkey,
+
+    /// The wallet removing liquidity
+    pub liquidity_provider: Pubkey,
+
+    /// The amount of token A withdrawn
+    pub amount_a: u64,
+
+    /// The amount of token B withdrawn
+    pub amount_b: u64,
+
+    /// The amount of liquidity tokens burned
+    pub liquidity: u64,
+
+    /// The time of the withdrawal
+    pub timestamp: i64,
+}
+
+/// Emitted when tokens are swapped in a pair
+#[event]
+pub struct Swapped {
+    /// The pair
+    #[index]
+    pub pair: Pubkey,
+
+    /// The wallet doing the swap
+    pub swapper: Pubkey,
+
+    /// The amount of token A sent by the swapper
+    pub amount_in_a: u64,
+
+    /// The amount of token B sent by the swapper
+    pub amount_in_b: u64,
+
+    /// The amount of token A received by the swapper
+    pub amount_out_a: u64,
+
+    /// The amount of token B received by the swapper
+    pub amount_out_b: u64,
+
+    /// The time of the swap
+    pub timestamp: i64,
+}
+
+/// Emitted when trading fees are claimed
+#[event]
+pub struct FeesClaimed {
+    /// The pair
+    #[index]
+    pub pair: Pubkey,
+
+    /// The wallet claiming fees
+    pub liquidity_provider: Pubkey,
+
+    /// The amount of token A claimed
+    pub amount_a: u64,
+
+    /// The amount of token B claimed
+    pub amount_b: u64,
+
+    /// The time of the claim
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin or guardian of the pools changes
+#[event]
+pub struct ConfigUpdated {
+    /// The configuration
+    pub config: Pubkey,
+
+    /// The wallet allowed to unpause features
+    pub admin: Pubkey,
+
+    /// The wallet allowed to pause features
+    pub guardian: Pubkey,
+}
+
+/// Emitted when features of the pools are paused or unpaused
+#[event]
+pub struct PauseUpdated {
+    /// The configuration
+    pub config: Pubkey,
+
+    /// The paused features after the update
+    pub paused: u64,
+
+    /// The wallet that updated the paused features
+    pub authority: Pubkey,
+
+    /// The time of the update
+    pub timestamp: i64,
+}
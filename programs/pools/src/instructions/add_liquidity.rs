@@ -5,13 +5,18 @@ use anchor_spl::associated_token::{self, AssociatedToken};
 
 use crate::MINIMUM_LIQUIDITY;
 use crate::errors::ErrorCode;
-use crate::state::{Pair};
+use crate::events::LiquidityAdded;
+use crate::state::{pause, Config, Pair};
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct AddLiquidity<'info> {
+    /// The configuration shared by every pair
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The pair
     #[account(
+        mut,
         seeds = [
             b"pair",
             pair.mint_a.as_ref(),
@@ -54,7 +59,7 @@ pub struct AddLiquidity<'info> {
             pair.mint_a.as_ref(),
             pair.mint_b.as_ref(),
         ],
-        bump = bump,
+        bump,
         token::mint = pair_mint,
         token::authority = authority
     )]
@@ -187,39 +192,46 @@ fn quote(amount_a: u64, reserve_a: u64, reserve_b: u64) -> u64 {
 
 pub fn handler(
     ctx: Context<AddLiquidity>,
-    _bump: u8,
     desired_amount_a: u64,
     desired_amount_b: u64,
     min_amount_a: u64,
     min_amount_b: u64,
+    deadline: i64,
 ) -> ProgramResult {
-    let pair = &ctx.accounts.pair;
+    ctx.accounts.config.check_active(pause::LIQUIDITY_ADDS)?;
+    if Clock::get()?.unix_timestamp > deadline {
+        return Err(ErrorCode::DeadlineExpired.into());
+    }
+
+    if desired_amount_a == 0 || desired_amount_b == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
 
     let reserve_a = ctx.accounts.pair_account_a.amount;
     let reserve_b = ctx.accounts.pair_account_b.amount;
+    ctx.accounts.pair.update_prices(reserve_a, reserve_b, Clock::get()?.unix_timestamp);
+
+    let pair = &ctx.accounts.pair;
 
     // Computing optimal liquidity respecting the constraints
     let (amount_a, amount_b) = {
         if reserve_a == 0 && reserve_b == 0 {
             (desired_amount_a, desired_amount_b)
         } else {
-            if desired_amount_a == 0 {
-                return Err(ErrorCode::InsufficientAmount.into())
-            }
-            if reserve_a == 0 && reserve_b == 0 {
+            if reserve_a == 0 || reserve_b == 0 {
                 return Err(ErrorCode::InsufficientLiquidity.into())
             }
 
             let amount_b_optimal = quote(desired_amount_a, reserve_a, reserve_b);
             if amount_b_optimal <= desired_amount_b {
                 if amount_b_optimal < min_amount_b {
-                    return Err(ErrorCode::InsufficientAmount.into())
+                    return Err(ErrorCode::SlippageExceeded.into())
                 }
                 (desired_amount_a, amount_b_optimal)
             } else {
                 let amount_a_optimal = quote(desired_amount_b, reserve_b, reserve_a);
                 if amount_a_optimal < min_amount_a {
-                    return Err(ErrorCode::InsufficientAmount.into())
+                    return Err(ErrorCode::SlippageExceeded.into())
                 }
                 (amount_a_optimal, desired_amount_b)
             }
@@ -231,7 +243,11 @@ pub fn handler(
 
     let liquidity = {
         if ctx.accounts.pair_mint.supply == 0 {
-            approximations::sqrt(amount_a * amount_b).unwrap() - MINIMUM_LIQUIDITY
+            let product = amount_a.checked_mul(amount_b).ok_or(ErrorCode::MathOverflow)?;
+            approximations::sqrt(product)
+                .unwrap()
+                .checked_sub(MINIMUM_LIQUIDITY)
+                .ok_or(ErrorCode::InsufficientLiquidityMinted)?
         } else {
             let lhs = amount_a * ctx.accounts.pair_mint.supply / reserve_a;
             let rhs = amount_b * ctx.accounts.pair_mint.supply / reserve_b;
@@ -259,6 +275,15 @@ pub fn handler(
         MINIMUM_LIQUIDITY
     )?;
 
+    emit!(LiquidityAdded {
+        pair: pair.key(),
+        liquidity_provider: ctx.accounts.liquidity_provider.key(),
+        amount_a,
+        amount_b,
+        liquidity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Provided {} liquidity to pair A={} and B={}", liquidity, pair.mint_a, pair.mint_b);
 
     Ok(())
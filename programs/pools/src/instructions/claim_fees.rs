@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{CreatePairBumps, Pair};
+use crate::events::FeesClaimed;
+use crate::state::Pair;
 
 #[derive(Accounts)]
-#[instruction(bumps: CreatePairBumps)]
 pub struct ClaimFees<'info> {
     /// The pair
     #[account(
@@ -120,6 +120,14 @@ pub fn handler(ctx: Context<ClaimFees>) -> ProgramResult {
         ctx.accounts.fees_account_b.amount,
     )?;
 
+    emit!(FeesClaimed {
+        pair: pair.key(),
+        liquidity_provider: ctx.accounts.liquidity_provider.key(),
+        amount_a: ctx.accounts.fees_account_a.amount,
+        amount_b: ctx.accounts.fees_account_b.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!(
         "Sent {} {} to {} and {} {} to {}",
         ctx.accounts.fees_account_a.amount,
@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
-use crate::state::{CreatePairBumps, Pair};
+use crate::events::PairCreated;
+use crate::state::{CreatePairBumps, Pair, Versioned};
 
 #[derive(Accounts)]
-#[instruction(bumps: CreatePairBumps)]
 pub struct CreatePair<'info> {
     /// The pair
     #[account(
@@ -15,7 +15,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref(),
         ],
-        bump = bumps.pair
+        bump
     )]
     pub pair: Box<Account<'info, Pair>>,
 
@@ -27,7 +27,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref()
         ],
-        bump = bumps.authority
+        bump
     )]
     pub authority: AccountInfo<'info>,
 
@@ -40,7 +40,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref(),
         ],
-        bump = bumps.mint,
+        bump,
         mint::decimals = 9,
         mint::authority = authority
     )]
@@ -61,7 +61,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref()
         ],
-        bump = bumps.account_a,
+        bump,
         token::mint = mint_a,
         token::authority = authority
     )]
@@ -76,7 +76,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref()
         ],
-        bump = bumps.account_b,
+        bump,
         token::mint = mint_b,
         token::authority = authority
     )]
@@ -91,7 +91,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref()
         ],
-        bump = bumps.fees_a,
+        bump,
         token::mint = mint_a,
         token::authority = authority
     )]
@@ -106,7 +106,7 @@ pub struct CreatePair<'info> {
             mint_a.key().as_ref(),
             mint_b.key().as_ref()
         ],
-        bump = bumps.fees_b,
+        bump,
         token::mint = mint_b,
         token::authority = authority
     )]
@@ -124,14 +124,23 @@ pub struct CreatePair<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreatePair>, bumps: CreatePairBumps, stable: bool) -> ProgramResult {
+pub fn handler(ctx: Context<CreatePair>, stable: bool) -> ProgramResult {
     let pair = &mut ctx.accounts.pair;
     pair.mint_a = ctx.accounts.mint_a.key();
     pair.mint_b = ctx.accounts.mint_b.key();
     pair.stable = stable;
     pair.pair_mint = ctx.accounts.pair_mint.key();
     pair.authority = ctx.accounts.authority.key();
-    pair.bumps = bumps;
+    pair.bumps = CreatePairBumps::find(&pair.mint_a, &pair.mint_b, ctx.program_id);
+    pair.version = Pair::VERSION;
+
+    emit!(PairCreated {
+        pair: pair.key(),
+        mint_a: pair.mint_a,
+        mint_b: pair.mint_b,
+        stable,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Created pair for token A={} and B={}", pair.mint_a, pair.mint_b);
 
@@ -3,9 +3,19 @@ pub mod add_liquidity;
 pub mod remove_liquidity;
 pub mod swap_exact_input;
 pub mod claim_fees;
+pub mod migrate;
+pub mod initialize_config;
+pub mod update_config;
+pub mod pause;
+pub mod unpause;
 
 pub use create_pair::*;
 pub use add_liquidity::*;
 pub use remove_liquidity::*;
 pub use swap_exact_input::*;
-pub use claim_fees::*;
\ No newline at end of file
+pub use claim_fees::*;
+pub use migrate::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use pause::*;
+pub use unpause::*;
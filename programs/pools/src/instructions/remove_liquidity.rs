@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Transfer, Token, TokenAccount, Burn};
 
+use crate::errors::ErrorCode;
+use crate::events::LiquidityRemoved;
 use crate::state::Pair;
 
 #[derive(Accounts)]
 pub struct RemoveLiquidity<'info> {
     /// The pair
     #[account(
+        mut,
         seeds = [
             b"pair",
             pair.mint_a.as_ref(),
@@ -145,15 +148,28 @@ impl<'info> RemoveLiquidity<'info> {
 pub fn handler(
     ctx: Context<RemoveLiquidity>,
     liquidity: u64,
+    deadline: i64,
 ) -> ProgramResult {
-    let pair = &ctx.accounts.pair;
+    if Clock::get()?.unix_timestamp > deadline {
+        return Err(ErrorCode::DeadlineExpired.into());
+    }
+    if liquidity == 0 {
+        return Err(ErrorCode::ZeroAmount.into());
+    }
 
     let reserve_a = ctx.accounts.pair_account_a.amount;
     let reserve_b = ctx.accounts.pair_account_b.amount;
+    ctx.accounts.pair.update_prices(reserve_a, reserve_b, Clock::get()?.unix_timestamp);
+
+    let pair = &ctx.accounts.pair;
     let supply = ctx.accounts.pair_mint.supply;
 
-    let amount_a = liquidity * reserve_a / supply;
-    let amount_b = liquidity * reserve_b / supply;
+    if liquidity > supply {
+        return Err(ErrorCode::InsufficientLiquidity.into());
+    }
+
+    let amount_a = (liquidity as u128 * reserve_a as u128 / supply as u128) as u64;
+    let amount_b = (liquidity as u128 * reserve_b as u128 / supply as u128) as u64;
 
     let seeds = &[
         b"authority".as_ref(),
@@ -166,6 +182,15 @@ pub fn handler(
     token::transfer(ctx.accounts.transfer_b_context().with_signer(signer), amount_b)?;
     token::burn(ctx.accounts.burn_liquidity_context().with_signer(signer), liquidity)?;
 
+    emit!(LiquidityRemoved {
+        pair: pair.key(),
+        liquidity_provider: ctx.accounts.liquidity_provider.key(),
+        amount_a,
+        amount_b,
+        liquidity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Removed {} liquidity to pair A={} and B={}", liquidity, pair.mint_a, pair.mint_b);
 
     Ok(())
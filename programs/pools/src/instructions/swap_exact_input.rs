@@ -3,13 +3,18 @@ use anchor_spl::associated_token::{self, AssociatedToken};
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::errors::ErrorCode;
-use crate::state::Pair;
+use crate::events::Swapped;
+use crate::state::{pause, Config, Pair};
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
 pub struct SwapExactInput<'info> {
+    /// The configuration shared by every pair
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+
     /// The pair
     #[account(
+        mut,
         seeds = [
             b"pair",
             pair.mint_a.as_ref(),
@@ -204,7 +209,7 @@ impl<'info> SwapExactInput<'info> {
 fn get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
     let amount_in_with_fees = amount_in * 999 / 1000;
     let numerator = amount_in_with_fees * reserve_out;
-    let denominator = reserve_in * 1000 + amount_in_with_fees;
+    let denominator = reserve_in + amount_in_with_fees;
     numerator / denominator
 }
 
@@ -226,20 +231,33 @@ pub fn handler(
     amount_in_b: u64,
     min_amount_out_a: u64,
     min_amount_out_b: u64,
+    deadline: i64,
 ) -> ProgramResult {
+    ctx.accounts.config.check_active(pause::SWAPS)?;
+    if Clock::get()?.unix_timestamp > deadline {
+        return Err(ErrorCode::DeadlineExpired.into());
+    }
+
     if amount_in_a == 0 && amount_in_b == 0 {
         return Err(ErrorCode::InsufficientInput.into());
     }
 
-    let pair = &ctx.accounts.pair;
-
     let reserve_a = ctx.accounts.pair_account_a.amount;
     let reserve_b = ctx.accounts.pair_account_b.amount;
+    ctx.accounts.pair.update_prices(reserve_a, reserve_b, Clock::get()?.unix_timestamp);
+
+    let pair = &ctx.accounts.pair;
 
     if min_amount_out_a > reserve_a || min_amount_out_b > reserve_b {
         return Err(ErrorCode::InsufficientLiquidity.into());
     }
 
+    let amount_out_a = get_amount_out(amount_in_b, reserve_b, reserve_a);
+    let amount_out_b = get_amount_out(amount_in_a, reserve_a, reserve_b);
+    if amount_out_a < min_amount_out_a || amount_out_b < min_amount_out_b {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
     // Transfer tokens and take fees
     if amount_in_a > 0 {
         token::transfer(
@@ -256,9 +274,6 @@ pub fn handler(
         token::transfer(ctx.accounts.transfer_fees_b_context(), amount_in_b / 1000)?;
     }
 
-    let amount_out_a = get_amount_out(amount_in_b, reserve_b, reserve_a);
-    let amount_out_b = get_amount_out(amount_in_a, reserve_a, reserve_b);
-    
     let seeds = &[
         b"authority".as_ref(),
         pair.mint_a.as_ref(),
@@ -301,6 +316,16 @@ pub fn handler(
         return Err(ErrorCode::InvariantK.into());
     }
 
+    emit!(Swapped {
+        pair: pair.key(),
+        swapper: ctx.accounts.swapper.key(),
+        amount_in_a,
+        amount_in_b,
+        amount_out_a,
+        amount_out_b,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!(
         "Swapped {} {} for {} {}",
         amount_in_a,
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PauseUpdated;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// The configuration shared by every pair
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The admin of the pools
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Unpause>, features: u64) -> ProgramResult {
+    Config::validate_features(features)?;
+
+    let config = &mut ctx.accounts.config;
+    config.paused &= !features;
+
+    emit!(PauseUpdated {
+        config: config.key(),
+        paused: config.paused,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Paused features {:#b}", config.paused);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ConfigUpdated;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The configuration shared by every pair
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The admin of the pools
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, admin: Pubkey, guardian: Pubkey) -> ProgramResult {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.guardian = guardian;
+
+    emit!(ConfigUpdated {
+        config: config.key(),
+        admin,
+        guardian,
+    });
+
+    msg!("Pools administrated by {} and guarded by {}", admin, guardian);
+
+    Ok(())
+}
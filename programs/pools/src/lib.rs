@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -11,35 +12,42 @@ const MINIMUM_LIQUIDITY: u64 = 1000;
 
 declare_id!("EL5LsNHBPaaoUJ2LmRuTpCmKPHw1jHLfiKCufTAmqYvh");
 
+/// The deployer of localnet builds, whose keypair is committed with the tests.
+/// Deployed builds only let the upgrade authority create the configuration of the pools.
+#[cfg(feature = "localnet")]
+pub mod localnet_deployer {
+    anchor_lang::declare_id!("CKPHGgR3snABtcMQiqfJvNCx6UgDzFy5Mp3Maz3SP2ug");
+}
+
 #[program]
 pub mod pools {
     use super::*;
-    use crate::{instructions::CreatePair, state::CreatePairBumps};
+    use crate::instructions::CreatePair;
 
     pub fn create_pair(
         ctx: Context<CreatePair>,
-        bumps: CreatePairBumps,
         stable: bool,
     ) -> ProgramResult {
-        instructions::create_pair::handler(ctx, bumps, stable)
+        instructions::create_pair::handler(ctx, stable)
     }
 
     pub fn add_liquidity(
         ctx: Context<AddLiquidity>,
-        bump: u8,
         desired_amount_a: u64,
         desired_amount_b: u64,
         min_amount_a: u64,
-        min_amount_b: u64
+        min_amount_b: u64,
+        deadline: i64,
     ) -> ProgramResult {
-        instructions::add_liquidity::handler(ctx, bump, desired_amount_a, desired_amount_b, min_amount_a, min_amount_b)
+        instructions::add_liquidity::handler(ctx, desired_amount_a, desired_amount_b, min_amount_a, min_amount_b, deadline)
     }
 
     pub fn remove_liquidity(
         ctx: Context<RemoveLiquidity>,
-        liquidity: u64
+        liquidity: u64,
+        deadline: i64,
     ) -> ProgramResult {
-        instructions::remove_liquidity::handler(ctx, liquidity)
+        instructions::remove_liquidity::handler(ctx, liquidity, deadline)
     }
 
     pub fn swap_exact_input(
@@ -48,8 +56,37 @@ pub mod pools {
         amount_in_b: u64,
         min_amount_out_a: u64,
         min_amount_out_b: u64,
+        deadline: i64,
+    ) -> ProgramResult {
+        instructions::swap_exact_input::handler(ctx, amount_in_a, amount_in_b, min_amount_out_a, min_amount_out_b, deadline)
+    }
+
+    pub fn migrate_pair(ctx: Context<Migrate>) -> ProgramResult {
+        instructions::migrate::handler::<Pair>(ctx)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        guardian: Pubkey,
     ) -> ProgramResult {
-        instructions::swap_exact_input::handler(ctx, amount_in_a, amount_in_b, min_amount_out_a, min_amount_out_b)
+        instructions::initialize_config::handler(ctx, admin, guardian)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        admin: Pubkey,
+        guardian: Pubkey,
+    ) -> ProgramResult {
+        instructions::update_config::handler(ctx, admin, guardian)
+    }
+
+    pub fn pause(ctx: Context<Pause>, features: u64) -> ProgramResult {
+        instructions::pause::handler(ctx, features)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, features: u64) -> ProgramResult {
+        instructions::unpause::handler(ctx, features)
     }
 }
 
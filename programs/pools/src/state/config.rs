@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::Versioned;
+
+/// Features of the pools that the guardian can pause
+pub mod pause {
+    /// Swaps between the tokens of a pair
+    pub const SWAPS: u64 = 1;
+
+    /// Liquidity provided to a pair
+    pub const LIQUIDITY_ADDS: u64 = 1 << 1;
+
+    /// Every feature that can be paused
+    pub const ALL: u64 = SWAPS | LIQUIDITY_ADDS;
+}
+
+/// The configuration shared by every pair
+#[account]
+#[derive(Default)]
+pub struct Config {
+    /// The wallet allowed to unpause features and change the configuration
+    pub admin: Pubkey,
+
+    /// The wallet allowed to pause features
+    pub guardian: Pubkey,
+
+    /// The paused features, as a bitmask of `pause` flags
+    pub paused: u64,
+
+    /// The bump used to generate the PDA
+    pub bump: u8,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Config {
+    /// Fails if any of the given features is paused
+    pub fn check_active(&self, features: u64) -> ProgramResult {
+        if self.paused & features != 0 {
+            return Err(ErrorCode::Paused.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the given features are known and not empty
+    pub fn validate_features(features: u64) -> ProgramResult {
+        if features == 0 || features & !pause::ALL != 0 {
+            return Err(ErrorCode::InvalidFeatures.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Versioned for Config {
+    const VERSION: u8 = 1;
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::CreatePairBumps;
+
+/// The layout of a pair before versioning
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyPair {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub stable: bool,
+    pub pair_mint: Pubkey,
+    pub authority: Pubkey,
+    pub bumps: CreatePairBumps,
+}
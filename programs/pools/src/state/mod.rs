@@ -1,3 +1,9 @@
+pub mod config;
+pub mod legacy;
 pub mod pair;
+pub mod version;
 
-pub use pair::*;
\ No newline at end of file
+pub use config::*;
+pub use legacy::*;
+pub use pair::*;
+pub use version::*;
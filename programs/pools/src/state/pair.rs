@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{LegacyPair, Migratable, Versioned};
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct CreatePairBumps {
     pub pair: u8,
@@ -11,6 +13,25 @@ pub struct CreatePairBumps {
     pub fees_b: u8,
 }
 
+impl CreatePairBumps {
+    /// Finds the canonical bumps of the PDAs of the pair of `mint_a` and `mint_b`
+    pub fn find(mint_a: &Pubkey, mint_b: &Pubkey, program_id: &Pubkey) -> Self {
+        let find = |seed: &[u8]| {
+            Pubkey::find_program_address(&[seed, mint_a.as_ref(), mint_b.as_ref()], program_id).1
+        };
+
+        CreatePairBumps {
+            pair: find(b"pair"),
+            authority: find(b"authority"),
+            mint: find(b"mint"),
+            account_a: find(b"account_a"),
+            account_b: find(b"account_b"),
+            fees_a: find(b"fees_a"),
+            fees_b: find(b"fees_b"),
+        }
+    }
+}
+
 /// A vesting locker account
 #[account]
 #[derive(Default)]
@@ -30,6 +51,89 @@ pub struct Pair {
     /// The authority of the pair mint
     pub authority: Pubkey,
 
+    /// The sum of the price of token A in token B (Q64.64) over each second
+    pub price_a_cumulative: u128,
+
+    /// The sum of the price of token B in token A (Q64.64) over each second
+    pub price_b_cumulative: u128,
+
+    /// The last time cumulative prices were updated
+    pub last_update: i64,
+
     /// The bump used to generate PDAs
     pub bumps: CreatePairBumps,
+
+    /// The layout version of the account
+    pub version: u8,
+
+    /// Space reserved for future fields
+    pub reserved: [u64; 8],
+}
+
+impl Pair {
+    /// Computes the addresses of the accounts holding the reserves of the pair
+    pub fn reserve_accounts(&self) -> (Pubkey, Pubkey) {
+        let find = |seed: &[u8], bump: u8| {
+            Pubkey::create_program_address(
+                &[seed, self.mint_a.as_ref(), self.mint_b.as_ref(), &[bump]],
+                &crate::ID,
+            )
+            .unwrap_or_default()
+        };
+
+        (
+            find(b"account_a", self.bumps.account_a),
+            find(b"account_b", self.bumps.account_b),
+        )
+    }
+
+    /// Computes the cumulative prices at a given time, using the reserves held since the last update.
+    /// Prices wrap around on overflow: only differences between two observations are meaningful.
+    pub fn cumulative_prices(&self, reserve_a: u64, reserve_b: u64, now: i64) -> (u128, u128) {
+        let elapsed = (now - self.last_update) as u128;
+        if self.last_update == 0 || elapsed == 0 || reserve_a == 0 || reserve_b == 0 {
+            return (self.price_a_cumulative, self.price_b_cumulative);
+        }
+
+        let price_a = ((reserve_b as u128) << 64) / reserve_a as u128;
+        let price_b = ((reserve_a as u128) << 64) / reserve_b as u128;
+        (
+            self.price_a_cumulative
+                .wrapping_add(price_a.wrapping_mul(elapsed)),
+            self.price_b_cumulative
+                .wrapping_add(price_b.wrapping_mul(elapsed)),
+        )
+    }
+
+    /// Accumulates prices before the reserves change
+    pub fn update_prices(&mut self, reserve_a: u64, reserve_b: u64, now: i64) {
+        let (price_a_cumulative, price_b_cumulative) =
+            self.cumulative_prices(reserve_a, reserve_b, now);
+        self.price_a_cumulative = price_a_cumulative;
+        self.price_b_cumulative = price_b_cumulative;
+        self.last_update = now;
+    }
+}
+
+impl Versioned for Pair {
+    const VERSION: u8 = 1;
+}
+
+impl Migratable for Pair {
+    type Legacy = LegacyPair;
+
+    fn from_legacy(legacy: LegacyPair) -> Self {
+        Pair {
+            mint_a: legacy.mint_a,
+            mint_b: legacy.mint_b,
+            stable: legacy.stable,
+            pair_mint: legacy.pair_mint,
+            authority: legacy.authority,
+            // Prices start accumulating on the next update of the reserves
+            last_update: 0,
+            bumps: legacy.bumps,
+            version: Pair::VERSION,
+            ..Default::default()
+        }
+    }
 }
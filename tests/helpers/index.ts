@@ -1,5 +1,11 @@
 import assert from "assert";
-import { web3, Provider, BN } from "@project-serum/anchor";
+import {
+  web3,
+  Provider,
+  BN,
+  workspace,
+  Program,
+} from "@project-serum/anchor";
 
 import {
   TOKEN_PROGRAM_ID,
@@ -7,6 +13,27 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { MerkleTree } from "./merkleTree";
+import { Ouroboros } from "../../ts";
+import deployerKey from "../fixtures/localnet-deployer.json";
+
+/**
+ * The deployer accepted by programs built with the `localnet` feature,
+ * which load without upgrade authority
+ */
+export const localnetDeployer = web3.Keypair.fromSecretKey(
+  Uint8Array.from(deployerKey)
+);
+
+/**
+ * Finds the data account of an upgradeable program, holding its upgrade authority
+ */
+export const findProgramDataAddress = async (programId: web3.PublicKey) =>
+  (
+    await web3.PublicKey.findProgramAddress(
+      [programId.toBuffer()],
+      new web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    )
+  )[0];
 
 export const findAssociatedAddress = async (
   owner: web3.PublicKey,
@@ -21,19 +48,104 @@ export const findAssociatedAddress = async (
   );
 };
 
+/**
+ * Finds the codes of an error name across the programs of the workspace
+ */
+const errorCodes = (error: string): number[] =>
+  [workspace.Ouroboros, workspace.Pools, workspace.Gauges]
+    .reduce<any[]>((acc, program) => acc.concat(program.idl.errors || []), [])
+    .filter((e) => e.name === error)
+    .map((e) => e.code);
+
+/**
+ * Extracts the custom error code of a failed transaction
+ */
+const failureCode = (err: any): number | undefined => {
+  if (typeof err?.code === "number") return err.code;
+  const match = /custom program error: (0x[0-9a-f]+)/.exec(`${err}`);
+  return match ? parseInt(match[1], 16) : undefined;
+};
+
 export const assertFail = async (pendingTx: Promise<any>, error?: string) => {
   const log = console.log;
   console.log = () => {};
   let success = true;
+  let failure: any;
   try {
     await pendingTx;
   } catch (err) {
     success = false;
+    failure = err;
     // log(err);
   } finally {
     console.log = log;
   }
   if (success) throw new Error("Should have failed");
+  if (error) {
+    const codes = errorCodes(error);
+    if (codes.length === 0) throw new Error(`Unknown error ${error}`);
+    const code = failureCode(failure);
+    if (codes.indexOf(code) === -1)
+      throw new Error(`Should have failed with ${error}, got ${failure}`);
+  }
+};
+
+/**
+ * Creates the configuration of the pools or gauges once for every suite, signed by the localnet deployer.
+ * The provider wallet is both its admin and guardian.
+ */
+export const initializeConfig = async (program: Program<any>, provider: Provider) => {
+  const [config] = await web3.PublicKey.findProgramAddress(
+    [Buffer.from("config")],
+    program.programId
+  );
+  if (!(await provider.connection.getAccountInfo(config))) {
+    await airdropUsers([localnetDeployer], provider);
+    await program.rpc.initializeConfig(
+      provider.wallet.publicKey,
+      provider.wallet.publicKey,
+      {
+        accounts: {
+          config,
+          payer: localnetDeployer.publicKey,
+          programData: await findProgramDataAddress(program.programId),
+          systemProgram: web3.SystemProgram.programId,
+        },
+        signers: [localnetDeployer],
+      }
+    );
+  }
+
+  return config;
+};
+
+export const sleep = (ms: number) =>
+  new Promise((resolve) => setTimeout(resolve, ms));
+
+/**
+ * A deadline leaving pool transactions enough time to be processed
+ */
+export const deadline = () => new BN(Math.round(Date.now() / 1000) + 600);
+
+/**
+ * Reads the unix timestamp of the cluster clock
+ */
+export const clusterTime = async (provider: Provider) =>
+  (await provider.connection.getBlockTime(
+    await provider.connection.getSlot()
+  )) || 0;
+
+/**
+ * Waits for the current epoch of an ouroboros to be over, so the next claim ends it
+ */
+export const waitForEpochEnd = async (ouroboros: Ouroboros) => {
+  const o = await ouroboros.program.account.ouroboros.fetch(
+    ouroboros.addresses.ouroboros
+  );
+  const end = o.lastPeriod.add(o.period).toNumber();
+  while ((await clusterTime(ouroboros.provider)) < end) {
+    await sleep(500);
+  }
 };
 
 export const airdropUsers = async (
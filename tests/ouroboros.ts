@@ -6,11 +6,34 @@ import { testInitializeOuroboros } from "./suites/ouroboros/initializeOuroboros"
 import { testClaimIncentives } from "./suites/ouroboros/claimIncentives";
 import { testReceiveAsset } from "./suites/ouroboros/receiveAsset";
 import { testCollectFees } from "./suites/ouroboros/collectFees";
+import { testEarlyWithdraw } from "./suites/ouroboros/earlyWithdraw";
+import { testClaimCompoundedIncentives } from "./suites/ouroboros/claimCompoundedIncentives";
+import { testEmissionCaps } from "./suites/ouroboros/emissionCaps";
+import { testGenesisDistributor } from "./suites/ouroboros/genesisDistributor";
+import { testVesting } from "./suites/ouroboros/vesting";
+import { testAllowedMints } from "./suites/ouroboros/allowedMints";
+import { testClaimGaugeIncentives } from "./suites/ouroboros/claimGaugeIncentives";
+import { testMarketplace } from "./suites/ouroboros/marketplace";
+import { testPermanentLock } from "./suites/ouroboros/permanentLock";
+import { testSoulboundLocker } from "./suites/ouroboros/soulboundLocker";
+import { testLpLocker } from "./suites/ouroboros/lpLocker";
+import { testAttachOuroboros } from "./suites/ouroboros/attachOuroboros";
+import { testTreasury } from "./suites/ouroboros/treasury";
+import { testBuyback } from "./suites/ouroboros/buyback";
+import { testVotingModes } from "./suites/ouroboros/votingModes";
+import { testRecipients } from "./suites/ouroboros/recipients";
+import { testDistribute } from "./suites/ouroboros/distribute";
+import { testMigrations } from "./suites/ouroboros/migrations";
+import { testErrors } from "./suites/ouroboros/errors";
+import { testPause } from "./suites/ouroboros/pause";
+import { testConfigs } from "./suites/ouroboros/configs";
 
 describe("Ouroboros", () => {
   const provider = Provider.local();
   setProvider(provider);
 
+  // Creates the configs of the pools and gauges used by later suites
+  testConfigs(provider);
   testInitializeOuroboros(provider);
   testCreateBeneficiary(provider);
   testCreateLocker(provider);
@@ -18,4 +41,24 @@ describe("Ouroboros", () => {
   testClaimIncentives(provider);
   testReceiveAsset(provider);
   testCollectFees(provider);
+  testEarlyWithdraw(provider);
+  testClaimCompoundedIncentives(provider);
+  testEmissionCaps(provider);
+  testGenesisDistributor(provider);
+  testVesting(provider);
+  testAllowedMints(provider);
+  testClaimGaugeIncentives(provider);
+  testMarketplace(provider);
+  testPermanentLock(provider);
+  testSoulboundLocker(provider);
+  testLpLocker(provider);
+  testAttachOuroboros(provider);
+  testTreasury(provider);
+  testBuyback(provider);
+  testVotingModes(provider);
+  testRecipients(provider);
+  testDistribute(provider);
+  testMigrations(provider);
+  testErrors(provider);
+  testPause(provider);
 });
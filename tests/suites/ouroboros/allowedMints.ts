@@ -0,0 +1,101 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { airdropUsers, assertFail } from "../../helpers";
+import { Ouroboros } from "../../../ts";
+
+export const testAllowedMints = (provider: Provider) =>
+  describe("Restrict received assets", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let proposer: Keypair;
+    let ouroboros: Ouroboros;
+    let proposerOuroboros: Ouroboros;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+    const minAmount = new BN(1000);
+
+    before(async () => {
+      creator = Keypair.generate();
+      proposer = Keypair.generate();
+      await airdropUsers([creator, proposer], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      proposerOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(proposer), {}),
+        ouroborosId
+      );
+    });
+
+    it("Fails to receive an unknown asset", async () => {
+      await assertFail(
+        ouroboros.sendAssetAndNotify(ouroboros.addresses.mint, minAmount)
+      );
+    });
+
+    it("Anyone can propose a mint", async () => {
+      const allowedMint = await proposerOuroboros.proposeMint(
+        ouroboros.addresses.mint
+      );
+
+      const a = await ouroboros.program.account.allowedMint.fetch(allowedMint);
+      expect(a.proposer.toString()).to.equal(proposer.publicKey.toString());
+      expect(a.approved).to.be.false;
+
+      await assertFail(
+        ouroboros.sendAssetAndNotify(ouroboros.addresses.mint, minAmount)
+      );
+    });
+
+    it("Only the admin can approve a mint", async () => {
+      await assertFail(
+        proposerOuroboros.setAllowedMint(ouroboros.addresses.mint, true, minAmount)
+      );
+      await ouroboros.setAllowedMint(ouroboros.addresses.mint, true, minAmount);
+    });
+
+    it("Enforces the minimum deposit", async () => {
+      await assertFail(
+        ouroboros.sendAssetAndNotify(
+          ouroboros.addresses.mint,
+          minAmount.sub(new BN(1))
+        )
+      );
+
+      const { snapshot } = await ouroboros.sendAssetAndNotify(
+        ouroboros.addresses.mint,
+        minAmount
+      );
+      const s = await ouroboros.program.account.snapshot.fetch(snapshot);
+      expect(s.rewards.toString()).to.equal(minAmount.toString());
+    });
+
+    it("Transfers the admin rights", async () => {
+      await ouroboros.setAdmin(proposer.publicKey);
+      await assertFail(
+        ouroboros.setAllowedMint(ouroboros.addresses.mint, false, minAmount)
+      );
+      await proposerOuroboros.setAllowedMint(
+        ouroboros.addresses.mint,
+        false,
+        minAmount
+      );
+
+      await assertFail(
+        ouroboros.sendAssetAndNotify(ouroboros.addresses.mint, minAmount)
+      );
+    });
+  });
@@ -0,0 +1,143 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Locker, Ouroboros } from "../../../ts";
+
+export const testAttachOuroboros = (provider: Provider) =>
+  describe("Attach an ouroboros to an existing mint", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let token: Token;
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const decimals = 6;
+    const initialSupply = new BN(10 ** 12);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 11);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+      startDate = new BN(await clusterTime(provider));
+
+      token = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        decimals,
+        TOKEN_PROGRAM_ID
+      );
+      await token.mintTo(
+        (await token.getOrCreateAssociatedAccountInfo(creator.publicKey))
+          .address,
+        creator,
+        [],
+        initialSupply.toNumber()
+      );
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+    });
+
+    it("Fails to attach without the mint authority", async () => {
+      const outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+      const outsiderOuroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(outsider), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+
+      await assertFail(
+        outsiderOuroboros.attach(token.publicKey, startDate),
+        "InvalidMintAuthority"
+      );
+    });
+
+    it("Fails to attach a mint with a freeze authority", async () => {
+      const freezable = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        creator.publicKey,
+        decimals,
+        TOKEN_PROGRAM_ID
+      );
+
+      await assertFail(
+        ouroboros.attach(freezable.publicKey, startDate),
+        "FreezableMint"
+      );
+    });
+
+    it("Attaches to the mint", async () => {
+      await ouroboros.attach(token.publicKey, startDate);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.mint.toString()).to.equal(token.publicKey.toString());
+      expect(o.bumps.mint).to.equal(0);
+      // The mint authority was handed over during the attach
+      expect((await token.getMintInfo()).mintAuthority.toString()).to.equal(
+        ouroboros.addresses.authority.toString()
+      );
+
+      const loaded = await Ouroboros.load(ouroboros.provider, ouroborosId);
+      expect(loaded.addresses.mint.toString()).to.equal(
+        token.publicKey.toString()
+      );
+    });
+
+    it("Emits incentives in the existing token", async () => {
+      const account = await token.getOrCreateAssociatedAccountInfo(
+        Keypair.generate().publicKey
+      );
+      beneficiary = await ouroboros.createBeneficiary(account.address);
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(604800)
+      );
+      await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+
+      const incentives = initialSupply
+        .sub(depositAmount)
+        .mul(expansionFactor)
+        .div(new BN(10000));
+      expect(
+        (await token.getAccountInfo(account.address)).amount.toString()
+      ).to.equal(incentives.toString());
+      expect((await token.getMintInfo()).decimals).to.equal(decimals);
+    });
+  });
@@ -0,0 +1,219 @@
+import { expect } from "chai";
+import {
+  setProvider,
+  Provider,
+  Program,
+  workspace,
+  BN,
+  Wallet,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Pools } from "../../../target/types/pools";
+import {
+  airdropUsers,
+  initializeConfig,
+  assertFail,
+  findAssociatedAddress,
+  sleep,
+  deadline,
+} from "../../helpers";
+import { Asset, Ouroboros } from "../../../ts";
+
+export const testBuyback = (provider: Provider) =>
+  describe("Buy back and burn native tokens with received assets", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let tokenB: Token;
+    let pair: PublicKey;
+    let asset: Asset;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(2);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const liquidity = new BN(10 ** 9);
+    const sentAmount = new BN(10 ** 8);
+    const buybackShare = 5000;
+    const buybackSlippage = 1000;
+
+    const find = (seed: string) =>
+      PublicKey.findProgramAddress(
+        [
+          Buffer.from(seed),
+          ouroboros.addresses.mint.toBuffer(),
+          tokenB.publicKey.toBuffer(),
+        ],
+        pools.programId
+      );
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      tokenB = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+      const creatorAccountB = (
+        await tokenB.getOrCreateAssociatedAccountInfo(creator.publicKey)
+      ).address;
+      await tokenB.mintTo(
+        creatorAccountB,
+        creator,
+        [],
+        initialSupply.toNumber()
+      );
+
+      const [pairAddress] = await find("pair");
+      const [authority] = await find("authority");
+      const [mint] = await find("mint");
+      const [accountA] = await find("account_a");
+      const [accountB] = await find("account_b");
+      const [feesA] = await find("fees_a");
+      const [feesB] = await find("fees_b");
+      const [burner] = await find("burner");
+      await pools.rpc.createPair(false, {
+        accounts: {
+          pair: pairAddress,
+          authority: authority,
+          pairMint: mint,
+          mintA: ouroboros.addresses.mint,
+          mintB: tokenB.publicKey,
+          pairAccountA: accountA,
+          pairAccountB: accountB,
+          feesAccountA: feesA,
+          feesAccountB: feesB,
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      pair = pairAddress;
+
+      await pools.rpc.addLiquidity(
+        liquidity,
+        liquidity,
+        new BN(0),
+        new BN(0),
+        deadline(),
+        {
+          accounts: {
+            config: await initializeConfig(pools, provider),
+            pair: pair,
+            authority: authority,
+            pairMint: mint,
+            burnerAccount: burner,
+            pairAccountA: accountA,
+            pairAccountB: accountB,
+            liquidityProvider: creator.publicKey,
+            liquidityProviderAccount: await findAssociatedAddress(
+              creator.publicKey,
+              mint
+            ),
+            mintA: ouroboros.addresses.mint,
+            mintB: tokenB.publicKey,
+            accountA: await findAssociatedAddress(
+              creator.publicKey,
+              ouroboros.addresses.mint
+            ),
+            accountB: creatorAccountB,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          },
+          signers: [creator],
+        }
+      );
+
+      await ouroboros.proposeMint(tokenB.publicKey);
+      await ouroboros.setAllowedMint(tokenB.publicKey, true, new BN(0));
+    });
+
+    it("Fails to set a share above 100%", async () => {
+      await assertFail(ouroboros.setBuyback(10001, buybackSlippage));
+    });
+
+    it("Keeps the buyback share of received assets aside", async () => {
+      await ouroboros.setBuyback(buybackShare, buybackSlippage);
+
+      const sent = await ouroboros.sendAssetAndNotify(
+        tokenB.publicKey,
+        sentAmount
+      );
+      asset = sent.asset;
+
+      const buyback = sentAmount.muln(buybackShare).divn(10000);
+      const a = await ouroboros.program.account.asset.fetch(
+        asset.addresses.asset
+      );
+      expect(a.buybackAmount.toString()).to.equal(buyback.toString());
+
+      const s = await ouroboros.program.account.snapshot.fetch(sent.snapshot);
+      expect(s.rewards.toString()).to.equal(sentAmount.sub(buyback).toString());
+    });
+
+    it("Opens the TWAP window", async () => {
+      await asset.buyback(pair);
+
+      const a = await ouroboros.program.account.asset.fetch(
+        asset.addresses.asset
+      );
+      expect(a.observationTimestamp.toNumber()).to.be.greaterThan(0);
+      expect(a.buybackAmount.toNumber()).to.be.greaterThan(0);
+
+      await assertFail(asset.buyback(pair));
+    });
+
+    it("Buys back and burns native tokens", async () => {
+      const supply = (await ouroboros.token.getMintInfo()).supply;
+
+      await sleep((rewardPeriod.toNumber() + 1) * 1000);
+      await asset.buyback(pair);
+
+      const a = await ouroboros.program.account.asset.fetch(
+        asset.addresses.asset
+      );
+      expect(a.buybackAmount.toString()).to.equal("0");
+
+      const burned = supply.sub((await ouroboros.token.getMintInfo()).supply);
+      expect(burned.toNumber()).to.be.greaterThan(0);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalBurned.toString()).to.equal(burned.toString());
+      expect(o.epochBurned.toString()).to.equal(burned.toString());
+      expect(o.burnedEpoch.toString()).to.equal(o.lastPeriod.toString());
+    });
+  });
@@ -1,7 +1,7 @@
 import { expect } from "chai";
 import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
 import { Keypair } from "@solana/web3.js";
-import { airdropUsers } from "../../helpers";
+import { airdropUsers, assertFail } from "../../helpers";
 import { Beneficiary, Locker, Ouroboros } from "../../../ts";
 
 export const testCastVote = (provider: Provider) =>
@@ -63,7 +63,25 @@ export const testCastVote = (provider: Provider) =>
       );
 
       expect(b.account.toString()).to.equal(someAccount.toString());
-      expect(b.votes.toString()).to.equal(depositAmount.toString());
+      expect(b.votes.toString()).to.equal("0");
+      expect(b.pendingVotes.toString()).to.equal(depositAmount.toString());
       expect(b.weight.toString()).to.equal(new BN(0).toString());
     });
+
+    it("Fails to move the votes from another beneficiary", async () => {
+      await assertFail(
+        locker.castVote(beneficiary2, beneficiary2),
+        "InvalidBeneficiary"
+      );
+
+      const b2 = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary2.address
+      );
+      expect(b2.votes.toString()).to.equal("0");
+      expect(b2.pendingVotes.toString()).to.equal("0");
+    });
+
+    it("Fails to change the vote during the same epoch", async () => {
+      await assertFail(locker.castVote(beneficiary2, beneficiary));
+    });
   });
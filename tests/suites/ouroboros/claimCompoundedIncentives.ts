@@ -0,0 +1,125 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Ouroboros, Locker } from "../../../ts";
+
+export const testClaimCompoundedIncentives = (provider: Provider) =>
+  describe("Compound incentives of a beneficiary in a locker", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let lockerId = Keypair.generate().publicKey;
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      const account = await ouroboros.token.getOrCreateAssociatedAccountInfo(
+        creator.publicKey
+      );
+      beneficiary = await ouroboros.createBeneficiary(account.address);
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      const lockingPeriod = new BN(604800);
+      locker = await ouroboros.createLocker(
+        lockerId,
+        depositAmount,
+        lockingPeriod
+      );
+
+      await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+      await beneficiary.setCompoundingLocker(locker);
+    });
+
+    it("Fails to compound in a locker of another ouroboros", async () => {
+      const other = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId.addn(1),
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await other.initialize(creator.publicKey, initialSupply, startDate);
+      const otherLocker = await other.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(604800)
+      );
+
+      await assertFail(
+        beneficiary.setCompoundingLocker(otherLocker),
+        "InvalidLocker"
+      );
+    });
+
+    it("Fails to claim liquid incentives", async () => {
+      await assertFail(beneficiary.claimIncentives());
+    });
+
+    it("Compound incentives", async () => {
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimCompoundedIncentives(locker, beneficiary);
+
+      const incentives = initialSupply
+        .sub(depositAmount)
+        .mul(expansionFactor)
+        .div(new BN(10000));
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.amount.toString()).to.equal(
+        depositAmount.add(incentives).toString()
+      );
+      expect(l.votes.gt(depositAmount)).to.be.true;
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.weight.toString()).to.equal(new BN(10000).toString());
+      expect(b.votes.toString()).to.equal(l.votes.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(
+        depositAmount.add(incentives).toString()
+      );
+
+      expect(
+        (await ouroboros.token.getAccountInfo(locker.addresses.account)).amount.toString()
+      ).to.equal(depositAmount.add(incentives).toString());
+    });
+  });
@@ -0,0 +1,381 @@
+import { expect } from "chai";
+import {
+  setProvider,
+  Provider,
+  Program,
+  workspace,
+  BN,
+  Wallet,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Pools } from "../../../target/types/pools";
+import { Gauges } from "../../../target/types/gauges";
+import {
+  airdropUsers,
+  assertFail,
+  findAssociatedAddress,
+  initializeConfig,
+  sleep,
+  clusterTime,
+  waitForEpochEnd,
+  deadline,
+} from "../../helpers";
+import { Beneficiary, Ouroboros, Locker } from "../../../ts";
+
+export const testClaimGaugeIncentives = (provider: Provider) =>
+  describe("Stream incentives of a beneficiary through a gauge", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+    const gauges = workspace.Gauges as Program<Gauges>;
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let lockerId = Keypair.generate().publicKey;
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    let pair: PublicKey;
+    let gauge: PublicKey;
+    let rewardsAccount: PublicKey;
+    let stakeAccounts: any;
+    let lpAmount: BN;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+    const liquidity = new BN(10 ** 9);
+
+    const findPairAddress = (seed: string, mintA: Token, mintB: Token) =>
+      PublicKey.findProgramAddress(
+        [
+          Buffer.from(seed),
+          mintA.publicKey.toBuffer(),
+          mintB.publicKey.toBuffer(),
+        ],
+        pools.programId
+      );
+    const findGaugeAddress = (seed: string, mintA: Token, mintB: Token) =>
+      PublicKey.findProgramAddress(
+        [
+          Buffer.from(seed),
+          ouroboros.addresses.mint.toBuffer(),
+          mintA.publicKey.toBuffer(),
+          mintB.publicKey.toBuffer(),
+        ],
+        gauges.programId
+      );
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+      const poolsConfig = await initializeConfig(pools, provider);
+      const gaugesConfig = await initializeConfig(gauges, provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      const tokenA = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+      const tokenB = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+
+      const [pairAddress] = await findPairAddress("pair", tokenA, tokenB);
+      const [pairAuthority] = await findPairAddress("authority", tokenA, tokenB);
+      const [pairMint] = await findPairAddress("mint", tokenA, tokenB);
+      const [pairAccountA] = await findPairAddress("account_a", tokenA, tokenB);
+      const [pairAccountB] = await findPairAddress("account_b", tokenA, tokenB);
+      const [feesA] = await findPairAddress("fees_a", tokenA, tokenB);
+      const [feesB] = await findPairAddress("fees_b", tokenA, tokenB);
+      await pools.rpc.createPair(false, {
+        accounts: {
+          pair: pairAddress,
+          authority: pairAuthority,
+          pairMint: pairMint,
+          mintA: tokenA.publicKey,
+          mintB: tokenB.publicKey,
+          pairAccountA: pairAccountA,
+          pairAccountB: pairAccountB,
+          feesAccountA: feesA,
+          feesAccountB: feesB,
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      pair = pairAddress;
+
+      for (const token of [tokenA, tokenB]) {
+        const account = await token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        );
+        await token.mintTo(account.address, creator, [], liquidity.toNumber());
+      }
+      const [burner] = await findPairAddress("burner", tokenA, tokenB);
+      const lpAccount = await findAssociatedAddress(creator.publicKey, pairMint);
+      await pools.rpc.addLiquidity(liquidity, liquidity, new BN(0), new BN(0), deadline(), {
+        accounts: {
+          config: poolsConfig,
+          pair,
+          authority: pairAuthority,
+          pairMint,
+          burnerAccount: burner,
+          mintA: tokenA.publicKey,
+          mintB: tokenB.publicKey,
+          pairAccountA,
+          pairAccountB,
+          liquidityProvider: creator.publicKey,
+          liquidityProviderAccount: lpAccount,
+          accountA: await findAssociatedAddress(
+            creator.publicKey,
+            tokenA.publicKey
+          ),
+          accountB: await findAssociatedAddress(
+            creator.publicKey,
+            tokenB.publicKey
+          ),
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      lpAmount = (
+        await new Token(
+          provider.connection,
+          pairMint,
+          TOKEN_PROGRAM_ID,
+          creator
+        ).getAccountInfo(lpAccount)
+      ).amount;
+
+      const [gaugeAddress] = await findGaugeAddress("gauge", tokenA, tokenB);
+      const [gaugeAuthority] = await findGaugeAddress("authority", tokenA, tokenB);
+      const [gaugeMint] = await findGaugeAddress("mint", tokenA, tokenB);
+      const [liquidityAccount] = await findGaugeAddress("liquidity_account", tokenA, tokenB);
+      const [rewardsAddress] = await findGaugeAddress("rewards_account", tokenA, tokenB);
+      const [gaugeAccountA] = await findGaugeAddress("account_a", tokenA, tokenB);
+      const [gaugeAccountB] = await findGaugeAddress("account_b", tokenA, tokenB);
+      await gauges.rpc.createGauge({
+        accounts: {
+          pair: pair,
+          gauge: gaugeAddress,
+          authority: gaugeAuthority,
+          mintLiquidity: pairMint,
+          gaugeMint: gaugeMint,
+          mintRewards: ouroboros.addresses.mint,
+          mintA: tokenA.publicKey,
+          mintB: tokenB.publicKey,
+          liquidityAccount: liquidityAccount,
+          rewardsAccount: rewardsAddress,
+          accountA: gaugeAccountA,
+          accountB: gaugeAccountB,
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      gauge = gaugeAddress;
+      rewardsAccount = rewardsAddress;
+
+      const [staker] = await PublicKey.findProgramAddress(
+        [
+          Buffer.from("staker"),
+          ouroboros.addresses.mint.toBuffer(),
+          tokenA.publicKey.toBuffer(),
+          tokenB.publicKey.toBuffer(),
+          creator.publicKey.toBuffer(),
+        ],
+        gauges.programId
+      );
+      stakeAccounts = {
+        config: gaugesConfig,
+        pair,
+        gauge,
+        authority: gaugeAuthority,
+        gaugeLiquidityAccount: liquidityAccount,
+        liquidityProvider: creator.publicKey,
+        payer: creator.publicKey,
+        staker,
+        liquidityProviderAccount: lpAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      };
+      await gauges.rpc.depositLiquidity(lpAmount, {
+        accounts: stakeAccounts,
+        signers: [creator],
+      });
+
+      beneficiary = await ouroboros.createBeneficiary(rewardsAccount);
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      await assertFail(
+        beneficiary2.setMetadata({
+          name: "Gauge",
+          uri: "",
+          category: "liquidity",
+          pair: pair,
+          gauge: gauge,
+        }),
+        "GaugeMismatch"
+      );
+      await beneficiary.setMetadata({
+        name: "Gauge",
+        uri: "",
+        category: "liquidity",
+        pair: pair,
+        gauge: gauge,
+      });
+
+      locker = await ouroboros.createLocker(
+        lockerId,
+        depositAmount,
+        new BN(604800)
+      );
+      await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimGaugeIncentives(gauge, pair);
+    });
+
+    it("Fails to bind a gauge without being admin", async () => {
+      const outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+      const outsiderOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(outsider), {}),
+        ouroborosId
+      );
+      const outsiderBeneficiary = await outsiderOuroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      await assertFail(
+        outsiderBeneficiary.setMetadata({
+          name: "Gauge",
+          uri: "",
+          category: "liquidity",
+          pair: pair,
+          gauge: gauge,
+        }),
+        "Unauthorized"
+      );
+    });
+
+    it("Fails to claim liquid incentives", async () => {
+      await assertFail(beneficiary.claimIncentives());
+    });
+
+    it("Streams incentives through the gauge", async () => {
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimGaugeIncentives(gauge, pair);
+
+      const incentives = initialSupply
+        .sub(depositAmount)
+        .mul(expansionFactor)
+        .div(new BN(10000));
+
+      expect(
+        (await ouroboros.token.getAccountInfo(rewardsAccount)).amount.toString()
+      ).to.equal(incentives.toString());
+
+      const g = await gauges.account.gauge.fetch(gauge);
+      expect(g.rewardRate.toString()).to.equal(
+        incentives.div(rewardPeriod).toString()
+      );
+      expect(g.periodFinish.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("Accrues the streamed incentives to the staker", async () => {
+      await sleep(rewardPeriod.toNumber() * 1000 + 1000);
+
+      const rewards = await findAssociatedAddress(
+        creator.publicKey,
+        ouroboros.addresses.mint
+      );
+      await gauges.rpc.collectRewards({
+        accounts: {
+          pair,
+          gauge,
+          authority: stakeAccounts.authority,
+          mintRewards: ouroboros.addresses.mint,
+          rewardsAccount,
+          liquidityProvider: creator.publicKey,
+          staker: stakeAccounts.staker,
+          liquidityProviderAccount: rewards,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+
+      const g = await gauges.account.gauge.fetch(gauge);
+      const collected = (await ouroboros.token.getAccountInfo(rewards)).amount;
+      expect(collected.toNumber()).to.be.greaterThan(0);
+      expect(collected.lte(g.rewardRate.mul(rewardPeriod))).to.be.true;
+
+      const s = await gauges.account.staker.fetch(stakeAccounts.staker);
+      expect(s.rewards.toNumber()).to.equal(0);
+      expect(s.amount.toString()).to.equal(lpAmount.toString());
+    });
+
+    it("Withdraws the staked liquidity", async () => {
+      await assertFail(
+        gauges.rpc.withdrawLiquidity(lpAmount.addn(1), {
+          accounts: stakeAccounts,
+          signers: [creator],
+        }),
+        "InsufficientStake"
+      );
+      await gauges.rpc.withdrawLiquidity(lpAmount, {
+        accounts: stakeAccounts,
+        signers: [creator],
+      });
+
+      const g = await gauges.account.gauge.fetch(gauge);
+      expect(g.totalStaked.toNumber()).to.equal(0);
+    });
+  });
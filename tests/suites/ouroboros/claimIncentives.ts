@@ -6,7 +6,7 @@ import {
   Wallet,
 } from "@project-serum/anchor";
 import { Transaction, Keypair } from "@solana/web3.js";
-import { airdropUsers } from "../../helpers";
+import { airdropUsers, clusterTime, waitForEpochEnd } from "../../helpers";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   Token,
@@ -29,7 +29,7 @@ export const testClaimIncentives = (provider: Provider) =>
     let beneficiary2: Beneficiary;
     const initialSupply = new BN(10 ** 10);
     const rewardPeriod = new BN(5);
-    const startDate = new BN(10000000000);
+    let startDate: BN;
     const expansionFactor = new BN(500);
     const timeMultiplier = new BN(10000);
     const depositAmount = new BN(10 ** 9);
@@ -45,6 +45,7 @@ export const testClaimIncentives = (provider: Provider) =>
         expansionFactor,
         timeMultiplier
       );
+      startDate = new BN(await clusterTime(provider));
       await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
 
       const account = await Token.getAssociatedTokenAddress(
@@ -76,9 +77,13 @@ export const testClaimIncentives = (provider: Provider) =>
       );
 
       await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
     });
 
     it("Claim incentives", async () => {
+      await waitForEpochEnd(ouroboros);
       await beneficiary.claimIncentives();
 
       const b = await ouroboros.program.account.beneficiary.fetch(beneficiary.address);
@@ -0,0 +1,78 @@
+import { expect } from "chai";
+import { setProvider, Provider, Program, workspace } from "@project-serum/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { Pools } from "../../../target/types/pools";
+import { Gauges } from "../../../target/types/gauges";
+import {
+  airdropUsers,
+  assertFail,
+  findProgramDataAddress,
+  initializeConfig,
+} from "../../helpers";
+
+export const testConfigs = (provider: Provider) =>
+  describe("Create the configurations of the pools and gauges", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+    const gauges = workspace.Gauges as Program<Gauges>;
+
+    let outsider: Keypair;
+
+    /**
+     * Tries to create the config of a program, signed by a wallet other than its deployer
+     *
+     * @param program - The pools or gauges program
+     */
+    const createAsOutsider = async (program: Program<any>) => {
+      const [config] = await PublicKey.findProgramAddress(
+        [Buffer.from("config")],
+        program.programId
+      );
+      // The config must not have been created by another suite yet
+      expect(await provider.connection.getAccountInfo(config)).to.be.null;
+
+      return program.rpc.initializeConfig(
+        outsider.publicKey,
+        outsider.publicKey,
+        {
+          accounts: {
+            config,
+            payer: outsider.publicKey,
+            programData: await findProgramDataAddress(program.programId),
+            systemProgram: SystemProgram.programId,
+          },
+          signers: [outsider],
+        }
+      );
+    };
+
+    before(async () => {
+      outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+    });
+
+    it("Fails to create the config of the pools without being the deployer", async () => {
+      await assertFail(createAsOutsider(pools), "Unauthorized");
+    });
+
+    it("Fails to create the config of the gauges without being the deployer", async () => {
+      await assertFail(createAsOutsider(gauges), "Unauthorized");
+    });
+
+    it("Creates the config of the pools as the deployer", async () => {
+      const config = await initializeConfig(pools, provider);
+
+      const c = await pools.account.config.fetch(config);
+      expect(c.admin.equals(provider.wallet.publicKey)).to.be.true;
+      expect(c.guardian.equals(provider.wallet.publicKey)).to.be.true;
+    });
+
+    it("Creates the config of the gauges as the deployer", async () => {
+      const config = await initializeConfig(gauges, provider);
+
+      const c = await gauges.account.config.fetch(config);
+      expect(c.admin.equals(provider.wallet.publicKey)).to.be.true;
+      expect(c.guardian.equals(provider.wallet.publicKey)).to.be.true;
+    });
+  });
@@ -1,7 +1,7 @@
 import { expect } from "chai";
 import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
-import { Keypair } from "@solana/web3.js";
-import { airdropUsers } from "../../helpers";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { airdropUsers, assertFail } from "../../helpers";
 import { Beneficiary, Ouroboros } from "../../../ts";
 
 export const testCreateBeneficiary = (provider: Provider) =>
@@ -44,5 +44,61 @@ export const testCreateBeneficiary = (provider: Provider) =>
       expect(b.votes.toString()).to.equal(new BN(0).toString());
       expect(b.weight.toString()).to.equal(new BN(0).toString());
       expect(b.lastUpdate.toString()).to.equal(startDate.toString());
+      expect(b.creator.toString()).to.equal(creator.publicKey.toString());
+    });
+
+    it("List beneficiaries", async () => {
+      const other = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      const beneficiaries = await ouroboros.getBeneficiaries();
+      expect(beneficiaries.map((e) => e.toString())).to.deep.equal([
+        beneficiary.address.toString(),
+        other.address.toString(),
+      ]);
+    });
+
+    it("Set the metadata of a beneficiary", async () => {
+      const metadata = await beneficiary.setMetadata({
+        name: "SOL/USDC pool",
+        uri: "https://example.com/beneficiary.json",
+        category: "pool",
+      });
+
+      const m = await ouroboros.program.account.beneficiaryMetadata.fetch(
+        metadata
+      );
+      expect(m.beneficiary.toString()).to.equal(beneficiary.address.toString());
+      expect(m.name).to.equal("SOL/USDC pool");
+      expect(m.category).to.equal("pool");
+      expect(m.pair.toString()).to.equal(PublicKey.default.toString());
+    });
+
+    it("Fails to set metadata that is too long", async () => {
+      await assertFail(
+        beneficiary.setMetadata({
+          name: "x".repeat(33),
+          uri: "",
+          category: "pool",
+        })
+      );
+    });
+
+    it("Fails to set metadata as someone else", async () => {
+      const stranger = Keypair.generate();
+      await airdropUsers([stranger], provider);
+      const strangerOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(stranger), {}),
+        ouroborosId
+      );
+
+      await assertFail(
+        new Beneficiary(strangerOuroboros, beneficiary.account).setMetadata({
+          name: "Scam",
+          uri: "",
+          category: "pool",
+        })
+      );
     });
   });
@@ -0,0 +1,204 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Ouroboros } from "../../../ts";
+
+export const testDistribute = (provider: Provider) =>
+  describe("Distribute incentives to every beneficiary with a crank", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let beneficiaries: Beneficiary[] = [];
+    let keeperAccount: PublicKey;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const amounts = [2 * 10 ** 9, 10 ** 9, 10 ** 9].map((a) => new BN(a));
+    const keeperReward = 100;
+
+    const balance = async (address: PublicKey) =>
+      (await ouroboros.token.getAccountInfo(address)).amount;
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+      await ouroboros.setKeeperReward(keeperReward);
+
+      keeperAccount = (
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        )
+      ).address;
+
+      for (let i = 0; i < amounts.length; i++) {
+        const account = await ouroboros.token.createAssociatedTokenAccount(
+          Keypair.generate().publicKey
+        );
+        beneficiaries.push(await ouroboros.createBeneficiary(account));
+      }
+      for (let i = 0; i < amounts.length; i++) {
+        const locker = await ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          amounts[i],
+          new BN(604800)
+        );
+        // The old beneficiary is ignored on the first vote
+        await locker.castVote(beneficiaries[i], beneficiaries[0]);
+      }
+
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiaries[0].claimIncentives();
+    });
+
+    it("Fails to distribute to no beneficiary", async () => {
+      await assertFail(
+        ouroboros.distribute([], keeperAccount),
+        "InvalidDistribution"
+      );
+    });
+
+    it("Fails to distribute out of registry order", async () => {
+      await assertFail(ouroboros.distribute([beneficiaries[1]], keeperAccount));
+    });
+
+    it("Registers beneficiaries on creation", async () => {
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiaries[1].address
+      );
+      expect(b.registrySlot.toNumber()).to.equal(2);
+
+      await assertFail(
+        ouroboros.registerBeneficiary(beneficiaries[1]),
+        "AlreadyRegistered"
+      );
+    });
+
+    it("Fails to set a keeper reward above 100%", async () => {
+      await assertFail(ouroboros.setKeeperReward(10001));
+    });
+
+    it("Distributes incentives to a page of beneficiaries", async () => {
+      const keeperBalance = await balance(keeperAccount);
+      await waitForEpochEnd(ouroboros);
+      await ouroboros.distribute(beneficiaries.slice(0, 2), keeperAccount);
+
+      const totalVotes = amounts.reduce((total, a) => total.add(a), new BN(0));
+      const incentives = initialSupply
+        .sub(totalVotes)
+        .mul(expansionFactor)
+        .div(new BN(10000));
+      const expected = [5000, 2500].map((weight) =>
+        incentives.mul(new BN(weight)).div(new BN(10000))
+      );
+
+      for (let i = 0; i < 2; i++) {
+        expect(
+          (await balance(beneficiaries[i].account)).toString()
+        ).to.equal(expected[i].toString());
+      }
+      expect((await balance(beneficiaries[2].account)).toString()).to.equal(
+        "0"
+      );
+
+      const distributed = expected[0].add(expected[1]);
+      expect(
+        (await balance(keeperAccount)).sub(keeperBalance).toString()
+      ).to.equal(
+        distributed.mul(new BN(keeperReward)).div(new BN(10000)).toString()
+      );
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.distributionCursor.toNumber()).to.equal(2);
+      expect(o.distributionEpoch.toString()).to.equal(
+        o.lastPeriod.toString()
+      );
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiaries[0].address
+      );
+      expect(b.lastUpdate.toString()).to.equal(o.lastPeriod.toString());
+    });
+
+    it("Distributes the next page from the cursor", async () => {
+      const keeperBalance = await balance(keeperAccount);
+      const supply = (await ouroboros.token.getMintInfo()).supply;
+      let o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      const epoch = o.lastPeriod;
+
+      await ouroboros.distribute(beneficiaries.slice(2), keeperAccount);
+
+      const totalVotes = amounts.reduce((total, a) => total.add(a), new BN(0));
+      const expected = supply
+        .sub(totalVotes)
+        .mul(expansionFactor)
+        .div(new BN(10000))
+        .mul(new BN(2500))
+        .div(new BN(10000));
+      expect((await balance(beneficiaries[2].account)).toString()).to.equal(
+        expected.toString()
+      );
+      expect(
+        (await balance(keeperAccount)).sub(keeperBalance).toString()
+      ).to.equal(
+        expected.mul(new BN(keeperReward)).div(new BN(10000)).toString()
+      );
+
+      // The distribution went on within the same epoch
+      o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.distributionCursor.toNumber()).to.equal(3);
+      expect(o.lastPeriod.toString()).to.equal(epoch.toString());
+    });
+
+    it("Pays nothing when repeated during the same epoch", async () => {
+      const balances = await Promise.all(
+        [...beneficiaries.map((b) => b.account), keeperAccount].map((a) =>
+          balance(a)
+        )
+      );
+
+      await assertFail(
+        ouroboros.distribute(beneficiaries.slice(0, 2), keeperAccount),
+        "InvalidDistribution"
+      );
+
+      const after = await Promise.all(
+        [...beneficiaries.map((b) => b.account), keeperAccount].map((a) =>
+          balance(a)
+        )
+      );
+      expect(after.map((a) => a.toString())).to.deep.equal(
+        balances.map((b) => b.toString())
+      );
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.distributionCursor.toNumber()).to.equal(3);
+    });
+  });
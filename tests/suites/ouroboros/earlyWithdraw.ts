@@ -0,0 +1,101 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { airdropUsers, assertFail } from "../../helpers";
+import { Beneficiary, Locker, Ouroboros } from "../../../ts";
+
+export const testEarlyWithdraw = (provider: Provider) =>
+  describe("Withdraw a locker before its unlock date", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+    const earlyWithdrawPenalty = new BN(5000);
+    const depositAmount = new BN(10 ** 9);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier,
+        earlyWithdrawPenalty
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      const lockingPeriod = new BN(604800);
+      await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        lockingPeriod
+      );
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        lockingPeriod
+      );
+      await locker.castVote(beneficiary, beneficiary2);
+    });
+
+    it("Fails to withdraw a locked locker without penalty", async () => {
+      await assertFail(locker.withdraw(beneficiary));
+    });
+
+    it("Withdraw with a penalty", async () => {
+      const before = (
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        )
+      ).amount;
+
+      const { snapshot } = await locker.earlyWithdraw(beneficiary);
+
+      const after = (
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        )
+      ).amount;
+      const s = await ouroboros.program.account.snapshot.fetch(snapshot);
+      const penalty = s.rewards;
+
+      expect(after.sub(before).add(penalty).toString()).to.equal(
+        depositAmount.toString()
+      );
+      expect(penalty.lte(depositAmount.div(new BN(2)))).to.be.true;
+      expect(penalty.gt(new BN(0))).to.be.true;
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(depositAmount.toString());
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.votes.toString()).to.equal("0");
+
+      expect(
+        await provider.connection.getAccountInfo(locker.addresses.locker)
+      ).to.be.null;
+    });
+  });
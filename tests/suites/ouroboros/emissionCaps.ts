@@ -0,0 +1,146 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { airdropUsers, clusterTime, waitForEpochEnd } from "../../helpers";
+import { Beneficiary, Ouroboros } from "../../../ts";
+
+export const testEmissionCaps = (provider: Provider) =>
+  describe("Cap the incentives of beneficiaries", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let whale: Beneficiary;
+    let regular: Beneficiary;
+    let dust: Beneficiary;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const caps = { maxWeight: 5000, minWeight: 1000, redistribute: true };
+    const whaleVotes = new BN(3 * 10 ** 9);
+    const regularVotes = new BN(10 ** 9);
+    const dustVotes = new BN(2 * 10 ** 8);
+    const totalVotes = whaleVotes.add(regularVotes).add(dustVotes);
+
+    const balance = async (beneficiary: Beneficiary) =>
+      (await ouroboros.token.getAccountInfo(beneficiary.account)).amount;
+    const emissions = (supply: BN, redistributed: BN) =>
+      supply
+        .sub(totalVotes)
+        .mul(expansionFactor)
+        .div(new BN(10000))
+        .add(redistributed);
+    const weight = (votes: BN) => votes.mul(new BN(10000)).div(totalVotes);
+    const share = (amount: BN, weight: BN) =>
+      amount.mul(weight).div(new BN(10000));
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier,
+        new BN(0),
+        caps
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      const beneficiaries: Beneficiary[] = [];
+      for (let i = 0; i < 3; i++) {
+        const account = await ouroboros.token.createAssociatedTokenAccount(
+          Keypair.generate().publicKey
+        );
+        beneficiaries.push(await ouroboros.createBeneficiary(account));
+      }
+      [whale, regular, dust] = beneficiaries;
+
+      const votes = [whaleVotes, regularVotes, dustVotes];
+      for (let i = 0; i < 3; i++) {
+        const locker = await ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          votes[i],
+          new BN(604800)
+        );
+        // The old beneficiary is ignored on the first vote
+        await locker.castVote(beneficiaries[i], beneficiaries[(i + 1) % 3]);
+      }
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await dust.claimIncentives();
+    });
+
+    it("Caps the whale and carries the excess over", async () => {
+      await waitForEpochEnd(ouroboros);
+      await whale.claimIncentives();
+
+      const total = emissions(initialSupply, new BN(0));
+      const expected = share(total, new BN(caps.maxWeight));
+      expect((await balance(whale)).toString()).to.equal(expected.toString());
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        whale.address
+      );
+      expect(b.weight.toString()).to.equal(caps.maxWeight.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.forfeitedEmissions.toString()).to.equal(
+        share(total, weight(whaleVotes)).sub(expected).toString()
+      );
+    });
+
+    it("Gives nothing below the minimum weight", async () => {
+      let o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      const forfeited = o.forfeitedEmissions;
+      const supply = (await ouroboros.token.getMintInfo()).supply;
+
+      await waitForEpochEnd(ouroboros);
+      await dust.claimIncentives();
+
+      expect((await balance(dust)).toString()).to.equal("0");
+
+      // The next period distributes what was forfeited in the previous one
+      const total = emissions(supply, forfeited);
+      o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.redistributedEmissions.toString()).to.equal(
+        forfeited.toString()
+      );
+      expect(o.forfeitedEmissions.toString()).to.equal(
+        share(total, weight(dustVotes)).toString()
+      );
+    });
+
+    it("Redistributes forfeited incentives", async () => {
+      let o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      const forfeited = o.forfeitedEmissions;
+      const supply = (await ouroboros.token.getMintInfo()).supply;
+
+      await waitForEpochEnd(ouroboros);
+      await regular.claimIncentives();
+
+      const total = emissions(supply, forfeited);
+      expect((await balance(regular)).toString()).to.equal(
+        share(total, weight(regularVotes)).toString()
+      );
+
+      o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.forfeitedEmissions.toString()).to.equal("0");
+    });
+  });
@@ -0,0 +1,241 @@
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Locker, Ouroboros } from "../../../ts";
+
+export const testErrors = (provider: Provider) =>
+  describe("Reject invalid arguments", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let outsider: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+    const lockingPeriod = new BN(604800);
+
+    const creatorOuroboros = (
+      id: BN,
+      period: BN = rewardPeriod,
+      earlyWithdrawPenalty?: BN,
+      caps?: { maxWeight: number; minWeight: number; redistribute: boolean }
+    ) =>
+      new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        id,
+        period,
+        expansionFactor,
+        timeMultiplier,
+        earlyWithdrawPenalty,
+        caps
+      );
+
+    before(async () => {
+      creator = Keypair.generate();
+      outsider = Keypair.generate();
+      await airdropUsers([creator, outsider], provider);
+
+      ouroboros = creatorOuroboros(ouroborosId);
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        new BN(10 ** 9),
+        lockingPeriod
+      );
+    });
+
+    it("Rejects an Ouroboros without period", async () => {
+      await assertFail(
+        creatorOuroboros(ouroborosId.addn(1), new BN(0)).initialize(
+          creator.publicKey,
+          initialSupply,
+          startDate
+        ),
+        "InvalidPeriod"
+      );
+    });
+
+    it("Rejects a penalty above 100%", async () => {
+      await assertFail(
+        creatorOuroboros(
+          ouroborosId.addn(2),
+          rewardPeriod,
+          new BN(10001)
+        ).initialize(creator.publicKey, initialSupply, startDate),
+        "InvalidFee"
+      );
+    });
+
+    it("Rejects inverted emission caps", async () => {
+      await assertFail(
+        creatorOuroboros(ouroborosId.addn(3), rewardPeriod, new BN(0), {
+          maxWeight: 1000,
+          minWeight: 2000,
+          redistribute: false,
+        }).initialize(creator.publicKey, initialSupply, startDate),
+        "InvalidCaps"
+      );
+    });
+
+    it("Rejects a null maximum weight", async () => {
+      await assertFail(
+        creatorOuroboros(ouroborosId.addn(4), rewardPeriod, new BN(0), {
+          maxWeight: 0,
+          minWeight: 0,
+          redistribute: false,
+        }).initialize(creator.publicKey, initialSupply, startDate),
+        "InvalidCaps"
+      );
+    });
+
+    it("Rejects an empty locker", async () => {
+      await assertFail(
+        ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          new BN(0),
+          lockingPeriod
+        ),
+        "ZeroAmount"
+      );
+    });
+
+    it("Rejects locking periods out of bounds", async () => {
+      await assertFail(
+        ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          new BN(10 ** 9),
+          new BN(0)
+        ),
+        "InvalidPeriod"
+      );
+      await assertFail(
+        ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          new BN(10 ** 9),
+          new BN(4 * 365 * 86400 + 1)
+        ),
+        "InvalidPeriod"
+      );
+    });
+
+    it("Rejects a vote without the receipt", async () => {
+      const outsiderProvider = new Provider(
+        provider.connection,
+        new Wallet(outsider),
+        {}
+      );
+      const receipt = new Token(
+        provider.connection,
+        locker.addresses.receipt,
+        TOKEN_PROGRAM_ID,
+        outsider
+      );
+      await receipt.getOrCreateAssociatedAccountInfo(outsider.publicKey);
+
+      const outsiderOuroboros = await Ouroboros.load(
+        outsiderProvider,
+        ouroborosId
+      );
+      await assertFail(
+        new Locker(outsiderOuroboros, locker.id).castVote(
+          beneficiary,
+          beneficiary
+        ),
+        "InvalidReceipt"
+      );
+    });
+
+    it("Rejects a free listing", async () => {
+      await assertFail(
+        locker.list(ouroboros.addresses.mint, new BN(0)),
+        "ZeroAmount"
+      );
+    });
+
+    it("Rejects a capped voting mode without cap", async () => {
+      await assertFail(
+        ouroboros.setVotingMode({ capped: {} }, new BN(0)),
+        "ZeroAmount"
+      );
+    });
+
+    it("Rejects a distributor clawed back in the past", async () => {
+      await assertFail(
+        ouroboros.createDistributor(
+          Keypair.generate().publicKey,
+          Array(32).fill(0),
+          new BN(1),
+          new BN(10 ** 9),
+          new BN(Math.round(Date.now() / 1000) - 3600)
+        ),
+        "InvalidClawback"
+      );
+    });
+
+    it("Rejects a claim before the epoch is over", async () => {
+      // The first epoch of this ouroboros starts in the future
+      const claimant = await ouroboros.createBeneficiary(
+        (
+          await ouroboros.token.getOrCreateAssociatedAccountInfo(
+            creator.publicKey
+          )
+        ).address
+      );
+
+      await assertFail(claimant.claimIncentives(), "EpochNotFinished");
+    });
+
+    it("Rejects a vote for a beneficiary behind on claims", async () => {
+      const live = creatorOuroboros(ouroborosId.addn(5));
+      await live.initialize(
+        creator.publicKey,
+        initialSupply,
+        new BN(await clusterTime(provider))
+      );
+      const claimed = await live.createBeneficiary(
+        (
+          await live.token.getOrCreateAssociatedAccountInfo(creator.publicKey)
+        ).address
+      );
+      const stale = await live.createBeneficiary(Keypair.generate().publicKey);
+      const liveLocker = await live.createLocker(
+        Keypair.generate().publicKey,
+        new BN(10 ** 9),
+        lockingPeriod
+      );
+
+      await waitForEpochEnd(live);
+      await claimed.claimIncentives();
+
+      await assertFail(liveLocker.castVote(stale, stale), "BeneficiaryInactive");
+    });
+
+    it("Rejects an empty distributor", async () => {
+      await assertFail(
+        ouroboros.createDistributor(
+          Keypair.generate().publicKey,
+          Array(32).fill(0),
+          new BN(0),
+          new BN(10 ** 9),
+          new BN(Math.round(Date.now() / 1000) + 3600)
+        ),
+        "ZeroAmount"
+      );
+    });
+  });
@@ -0,0 +1,174 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { airdropUsers, assertFail } from "../../helpers";
+import { MerkleTree } from "../../helpers/merkleTree";
+import { Distributor, GenesisAllocation, Ouroboros } from "../../../ts";
+
+export const testGenesisDistributor = (provider: Provider) =>
+  describe("Distribute genesis lockers", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let distributor: Distributor;
+    let tree: MerkleTree;
+    let allocations: GenesisAllocation[];
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      allocations = [
+        {
+          recipient: creator.publicKey,
+          amount: new BN(10 ** 9),
+          period: new BN(604800),
+        },
+        {
+          recipient: Keypair.generate().publicKey,
+          amount: new BN(2 * 10 ** 9),
+          period: new BN(2 * 604800),
+        },
+        {
+          recipient: creator.publicKey,
+          amount: new BN(10 ** 9),
+          period: new BN(0),
+        },
+      ];
+      tree = new MerkleTree(
+        allocations.map((allocation, i) => Distributor.leaf(i, allocation))
+      );
+    });
+
+    it("Fails to create a distributor without being admin", async () => {
+      const outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+      const outsiderAccount =
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          outsider.publicKey
+        );
+      await ouroboros.token.transfer(
+        (
+          await ouroboros.token.getOrCreateAssociatedAccountInfo(
+            creator.publicKey
+          )
+        ).address,
+        outsiderAccount.address,
+        creator,
+        [],
+        10 ** 9
+      );
+      const outsiderOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(outsider), {}),
+        ouroborosId
+      );
+
+      await assertFail(
+        outsiderOuroboros.createDistributor(
+          Keypair.generate().publicKey,
+          tree.getRootArray(),
+          new BN(allocations.length),
+          new BN(10 ** 9),
+          new BN(Math.round(Date.now() / 1000) + 3600)
+        )
+      );
+    });
+
+    it("Create a distributor", async () => {
+      const total = allocations.reduce((acc, e) => acc.add(e.amount), new BN(0));
+      distributor = await ouroboros.createDistributor(
+        Keypair.generate().publicKey,
+        tree.getRootArray(),
+        new BN(allocations.length),
+        total,
+        new BN(Math.round(Date.now() / 1000) + 3600)
+      );
+
+      const d = await ouroboros.program.account.distributor.fetch(
+        distributor.addresses.distributor
+      );
+      expect(d.totalAmount.toString()).to.equal(total.toString());
+      expect(d.claimed.length).to.equal(1);
+      expect(
+        (
+          await ouroboros.token.getAccountInfo(distributor.addresses.account)
+        ).amount.toString()
+      ).to.equal(total.toString());
+    });
+
+    it("Fails to claim someone else's allocation", async () => {
+      await assertFail(
+        distributor.claim(
+          Keypair.generate().publicKey,
+          1,
+          allocations[1],
+          tree.getProofArray(1)
+        )
+      );
+    });
+
+    it("Claim a genesis locker", async () => {
+      const locker = await distributor.claim(
+        Keypair.generate().publicKey,
+        0,
+        allocations[0],
+        tree.getProofArray(0)
+      );
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.amount.toString()).to.equal(allocations[0].amount.toString());
+      expect(l.votes.toString()).to.equal(allocations[0].amount.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(
+        allocations[0].amount.toString()
+      );
+    });
+
+    it("Fails to claim a leaf without lock period", async () => {
+      await assertFail(
+        distributor.claim(
+          Keypair.generate().publicKey,
+          2,
+          allocations[2],
+          tree.getProofArray(2)
+        ),
+        "InvalidPeriod"
+      );
+    });
+
+    it("Fails to claim twice", async () => {
+      await assertFail(
+        distributor.claim(
+          Keypair.generate().publicKey,
+          0,
+          allocations[0],
+          tree.getProofArray(0)
+        )
+      );
+    });
+
+    it("Fails to claw back before the deadline", async () => {
+      await assertFail(distributor.clawback());
+    });
+  });
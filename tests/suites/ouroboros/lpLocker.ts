@@ -0,0 +1,282 @@
+import { expect } from "chai";
+import {
+  setProvider,
+  Provider,
+  Program,
+  workspace,
+  BN,
+  Wallet,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Pools } from "../../../target/types/pools";
+import {
+  airdropUsers,
+  initializeConfig,
+  assertFail,
+  findAssociatedAddress,
+  sleep,
+  clusterTime,
+  waitForEpochEnd,
+  deadline,
+} from "../../helpers";
+import { Beneficiary, Locker, Ouroboros } from "../../../ts";
+
+export const testLpLocker = (provider: Provider) =>
+  describe("Lock LP tokens of the native pair", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let pair: PublicKey;
+    let otherPair: PublicKey;
+    let pairMint: PublicKey;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    // More native tokens than the free supply are paired
+    const liquidity = new BN(6 * 10 ** 9);
+
+    const createPair = async (mintA: PublicKey, mintB: PublicKey) => {
+      const find = (seed: string) =>
+        findProgramAddress(seed, mintA, mintB, pools.programId);
+      const [pairAddress] = await find("pair");
+      const [authority] = await find("authority");
+      const [mint] = await find("mint");
+      const [accountA] = await find("account_a");
+      const [accountB] = await find("account_b");
+      const [feesA] = await find("fees_a");
+      const [feesB] = await find("fees_b");
+
+      await pools.rpc.createPair(false, {
+        accounts: {
+          pair: pairAddress,
+          authority: authority,
+          pairMint: mint,
+          mintA: mintA,
+          mintB: mintB,
+          pairAccountA: accountA,
+          pairAccountB: accountB,
+          feesAccountA: feesA,
+          feesAccountB: feesB,
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+
+      return pairAddress;
+    };
+
+    const addLiquidity = async (mintA: PublicKey, mintB: PublicKey) => {
+      const find = (seed: string) =>
+        findProgramAddress(seed, mintA, mintB, pools.programId);
+      const [pairAddress] = await find("pair");
+      const [authority] = await find("authority");
+      const [mint] = await find("mint");
+      const [burner] = await find("burner");
+      const [accountA] = await find("account_a");
+      const [accountB] = await find("account_b");
+
+      await pools.rpc.addLiquidity(
+        liquidity,
+        liquidity,
+        new BN(0),
+        new BN(0),
+        deadline(),
+        {
+          accounts: {
+            config: await initializeConfig(pools, provider),
+            pair: pairAddress,
+            authority: authority,
+            pairMint: mint,
+            burnerAccount: burner,
+            pairAccountA: accountA,
+            pairAccountB: accountB,
+            liquidityProvider: creator.publicKey,
+            liquidityProviderAccount: await Token.getAssociatedTokenAddress(
+              ASSOCIATED_TOKEN_PROGRAM_ID,
+              TOKEN_PROGRAM_ID,
+              mint,
+              creator.publicKey
+            ),
+            mintA: mintA,
+            mintB: mintB,
+            accountA: await findAssociatedAddress(creator.publicKey, mintA),
+            accountB: await findAssociatedAddress(creator.publicKey, mintB),
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          },
+          signers: [creator],
+        }
+      );
+
+      return mint;
+    };
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      const tokenB = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+      await tokenB.mintTo(
+        (await tokenB.getOrCreateAssociatedAccountInfo(creator.publicKey))
+          .address,
+        creator,
+        [],
+        initialSupply.toNumber()
+      );
+      const tokenC = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+
+      pair = await createPair(ouroboros.addresses.mint, tokenB.publicKey);
+      pairMint = await addLiquidity(ouroboros.addresses.mint, tokenB.publicKey);
+      otherPair = await createPair(tokenB.publicKey, tokenC.publicKey);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        (
+          await ouroboros.token.getOrCreateAssociatedAccountInfo(
+            Keypair.generate().publicKey
+          )
+        ).address
+      );
+    });
+
+    it("Fails to use a pair without the native token", async () => {
+      await assertFail(ouroboros.setNativePair(otherPair));
+    });
+
+    it("Fails to lock LP tokens before the TWAP window is over", async () => {
+      await ouroboros.setNativePair(pair);
+
+      await assertFail(
+        ouroboros.createLpLocker(
+          Keypair.generate().publicKey,
+          pair,
+          new BN(1000),
+          new BN(1)
+        ),
+        "LpPriceUnavailable"
+      );
+    });
+
+    it("Locks LP tokens for their native value", async () => {
+      await sleep(rewardPeriod.toNumber() * 1000 + 1000);
+
+      const lpToken = new Token(
+        provider.connection,
+        pairMint,
+        TOKEN_PROGRAM_ID,
+        creator
+      );
+      const lpAmount = (
+        await lpToken.getAccountInfo(
+          await findAssociatedAddress(creator.publicKey, pairMint)
+        )
+      ).amount;
+      const lpSupply = (await lpToken.getMintInfo()).supply;
+
+      locker = await ouroboros.createLpLocker(
+        Keypair.generate().publicKey,
+        pair,
+        lpAmount,
+        new BN(1)
+      );
+
+      const value = lpAmount.mul(liquidity).mul(new BN(2)).div(lpSupply);
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.mint.toString()).to.equal(pairMint.toString());
+      expect(l.amount.toString()).to.equal(lpAmount.toString());
+      expect(l.value.toString()).to.equal(value.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      // LP value is kept apart from locked native tokens
+      expect(o.totalVotes.toString()).to.equal("0");
+      expect(o.lpVotes.toString()).to.equal(value.toString());
+      expect(value.gt(initialSupply)).to.be.true;
+    });
+
+    it("Claims incentives while LP value exceeds the supply", async () => {
+      await locker.castVote(beneficiary, beneficiary);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+
+      expect(
+        (
+          await ouroboros.token.getAccountInfo(beneficiary.account)
+        ).amount.toString()
+      ).to.equal(initialSupply.mul(expansionFactor).div(new BN(10000)).toString());
+    });
+
+    it("Withdraws the LP tokens", async () => {
+      await sleep(2000);
+      await locker.withdraw(beneficiary);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal("0");
+      expect(o.lpVotes.toString()).to.equal("0");
+    });
+  });
+
+const findProgramAddress = (
+  seed: string,
+  mintA: PublicKey,
+  mintB: PublicKey,
+  programId: PublicKey
+) =>
+  PublicKey.findProgramAddress(
+    [Buffer.from(seed), mintA.toBuffer(), mintB.toBuffer()],
+    programId
+  );
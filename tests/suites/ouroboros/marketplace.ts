@@ -0,0 +1,144 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { airdropUsers, assertFail, findAssociatedAddress } from "../../helpers";
+import { Locker, Ouroboros } from "../../../ts";
+
+export const testMarketplace = (provider: Provider) =>
+  describe("Trade lockers through escrowed listings", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let buyer: Keypair;
+    let treasury: PublicKey;
+    let ouroboros: Ouroboros;
+    let buyerOuroboros: Ouroboros;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+    const price = new BN(2 * 10 ** 8);
+    const listingFee = 500;
+
+    const balance = async (owner: PublicKey, mint: PublicKey) =>
+      (
+        await ouroboros.token.getAccountInfo(
+          await findAssociatedAddress(owner, mint)
+        )
+      ).amount;
+
+    before(async () => {
+      creator = Keypair.generate();
+      buyer = Keypair.generate();
+      treasury = Keypair.generate().publicKey;
+      await airdropUsers([creator, buyer], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+      await ouroboros.setListingFee(listingFee, treasury);
+
+      buyerOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(buyer), {}),
+        ouroborosId
+      );
+
+      const creatorAccount = await ouroboros.token.getOrCreateAssociatedAccountInfo(
+        creator.publicKey
+      );
+      const buyerAccount = await ouroboros.token.getOrCreateAssociatedAccountInfo(
+        buyer.publicKey
+      );
+      await ouroboros.token.transfer(
+        creatorAccount.address,
+        buyerAccount.address,
+        creator,
+        [],
+        price.toNumber()
+      );
+
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(604800)
+      );
+    });
+
+    it("Lists a locker", async () => {
+      const listing = await locker.list(ouroboros.addresses.mint, price);
+
+      const l = await ouroboros.program.account.listing.fetch(listing);
+      expect(l.seller.toString()).to.equal(creator.publicKey.toString());
+      expect(l.price.toString()).to.equal(price.toString());
+      expect(l.amount.toString()).to.equal(depositAmount.toString());
+
+      const [escrow] = locker.findEscrow();
+      expect(
+        (await ouroboros.token.getAccountInfo(escrow)).amount.toString()
+      ).to.equal("1");
+    });
+
+    it("Fails to cancel someone else's listing", async () => {
+      await assertFail(new Locker(buyerOuroboros, locker.id).cancelListing());
+    });
+
+    it("Fails to buy a locker at another price", async () => {
+      const buyerLocker = new Locker(buyerOuroboros, locker.id);
+      await assertFail(
+        buyerLocker.buy(price.subn(1), ouroboros.addresses.mint),
+        "ListingOutdated"
+      );
+      await assertFail(
+        buyerLocker.buy(price, Keypair.generate().publicKey),
+        "ListingOutdated"
+      );
+    });
+
+    it("Buys a locker", async () => {
+      const sellerBalance = await balance(
+        creator.publicKey,
+        ouroboros.addresses.mint
+      );
+
+      await new Locker(buyerOuroboros, locker.id).buy(
+        price,
+        ouroboros.addresses.mint
+      );
+
+      const fee = price.mul(new BN(listingFee)).div(new BN(10000));
+      expect(
+        (await balance(creator.publicKey, ouroboros.addresses.mint)).toString()
+      ).to.equal(sellerBalance.add(price).sub(fee).toString());
+      expect(
+        (await balance(treasury, ouroboros.addresses.mint)).toString()
+      ).to.equal(fee.toString());
+      expect(
+        (await balance(buyer.publicKey, locker.addresses.receipt)).toString()
+      ).to.equal("1");
+
+      const [listing] = locker.findListing();
+      expect(await provider.connection.getAccountInfo(listing)).to.be.null;
+    });
+
+    it("Cancels a listing", async () => {
+      const buyerLocker = new Locker(buyerOuroboros, locker.id);
+      await buyerLocker.list(ouroboros.addresses.mint, price);
+      await buyerLocker.cancelListing();
+
+      expect(
+        (await balance(buyer.publicKey, locker.addresses.receipt)).toString()
+      ).to.equal("1");
+
+      const [listing] = locker.findListing();
+      expect(await provider.connection.getAccountInfo(listing)).to.be.null;
+    });
+  });
@@ -0,0 +1,254 @@
+import { expect } from "chai";
+import { setProvider, Provider, Program, workspace } from "@project-serum/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { Ouroboros } from "../../../target/types/ouroboros";
+import { Pools } from "../../../target/types/pools";
+import { Gauges } from "../../../target/types/gauges";
+import {
+  airdropUsers,
+  assertFail,
+  findProgramDataAddress,
+  localnetDeployer,
+} from "../../helpers";
+import ouroborosFixture from "../../fixtures/legacy/ouroboros.json";
+import lockerFixture from "../../fixtures/legacy/locker.json";
+import beneficiaryFixture from "../../fixtures/legacy/beneficiary.json";
+import assetFixture from "../../fixtures/legacy/asset.json";
+import snapshotFixture from "../../fixtures/legacy/snapshot.json";
+import claimantFixture from "../../fixtures/legacy/claimant.json";
+import pairFixture from "../../fixtures/legacy/pair.json";
+import gaugeFixture from "../../fixtures/legacy/gauge.json";
+import stakerFixture from "../../fixtures/legacy/staker.json";
+
+export const testMigrations = (provider: Provider) =>
+  describe("Migrate accounts created before versioning", () => {
+    setProvider(provider);
+
+    const ouroboros = workspace.Ouroboros as Program<Ouroboros>;
+    const pools = workspace.Pools as Program<Pools>;
+    const gauges = workspace.Gauges as Program<Gauges>;
+
+    const ouroborosAddress = new PublicKey(ouroborosFixture.pubkey);
+
+    /**
+     * Migrates a fixture with the legacy layout loaded by the validator and checks that it grew
+     *
+     * @param instruction - The migration instruction of the account type
+     * @param client - The client of the account type
+     * @param fixture - The legacy layout of the account
+     * @param extraAccounts - The accounts needed besides the migrated one
+     * @param signers - The signers besides the provider wallet
+     * @returns The migrated account
+     */
+    const migrate = async (
+      instruction: (ctx: any) => Promise<string>,
+      client: { size: number; fetch: (address: PublicKey) => Promise<any> },
+      fixture: { pubkey: string },
+      extraAccounts = {},
+      signers: Keypair[] = []
+    ) => {
+      const address = new PublicKey(fixture.pubkey);
+      const accounts = {
+        account: address,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+        ...extraAccounts,
+      };
+      const before = await provider.connection.getAccountInfo(address);
+
+      await instruction({ accounts, signers });
+
+      const after = await provider.connection.getAccountInfo(address);
+      expect(after.data.length).to.be.above(before.data.length);
+      expect(after.data.length).to.equal(client.size);
+      expect(after.lamports).to.equal(
+        await provider.connection.getMinimumBalanceForRentExemption(
+          after.data.length
+        )
+      );
+
+      const account = await client.fetch(address);
+      expect(account.version).to.equal(1);
+      expect(account.reserved.every((r) => r.isZero())).to.be.true;
+
+      // Migrating twice fails
+      await assertFail(instruction({ accounts, signers }));
+
+      return account;
+    };
+
+    it("Fails to migrate an account as another type", async () => {
+      await assertFail(
+        ouroboros.rpc.migrateAsset({
+          accounts: {
+            account: new PublicKey(beneficiaryFixture.pubkey),
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+        })
+      );
+    });
+
+    it("Fails to migrate an ouroboros without being the deployer", async () => {
+      const outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+
+      await assertFail(
+        ouroboros.rpc.migrateOuroboros({
+          accounts: {
+            account: ouroborosAddress,
+            payer: outsider.publicKey,
+            programData: await findProgramDataAddress(ouroboros.programId),
+            systemProgram: SystemProgram.programId,
+          },
+          signers: [outsider],
+        }),
+        "Unauthorized"
+      );
+    });
+
+    it("Migrates an ouroboros", async () => {
+      await airdropUsers([localnetDeployer], provider);
+      const o = await migrate(
+        ouroboros.rpc.migrateOuroboros,
+        ouroboros.account.ouroboros,
+        ouroborosFixture,
+        {
+          payer: localnetDeployer.publicKey,
+          programData: await findProgramDataAddress(ouroboros.programId),
+        },
+        [localnetDeployer]
+      );
+      // Legacy fields are decoded from their old offsets
+      expect(o.id.toNumber()).to.equal(424242);
+      expect(o.period.toNumber()).to.equal(604800);
+      expect(o.lastPeriod.toNumber()).to.equal(1640000000);
+      expect(o.lastPeriodVotes.toNumber()).to.equal(3000);
+      expect(o.totalVotes.toNumber()).to.equal(4000);
+      expect(o.expansionFactor.toNumber()).to.equal(50);
+      expect(o.timeMultiplier.toNumber()).to.equal(192);
+      // Legacy ouroboros are handed to the deployer
+      expect(o.admin.equals(localnetDeployer.publicKey)).to.be.true;
+      expect(o.treasury.equals(localnetDeployer.publicKey)).to.be.true;
+      // New fields start with their defaults
+      expect(o.caps.maxWeight).to.equal(10000);
+      expect(o.caps.minWeight).to.equal(0);
+      expect(o.guardian.equals(PublicKey.default)).to.be.true;
+      expect(o.paused.toNumber()).to.equal(0);
+      expect(o.castVotes.toNumber()).to.equal(0);
+      // Beneficiaries created before the registry must be registered first
+      expect(o.flags.toNumber()).to.equal(0);
+      expect(o.lpVotes.toNumber()).to.equal(0);
+      expect(o.nativeTwap.toNumber()).to.equal(0);
+    });
+
+    it("Migrates a locker", async () => {
+      const l = await migrate(
+        ouroboros.rpc.migrateLocker,
+        ouroboros.account.locker,
+        lockerFixture,
+        { ouroboros: ouroborosAddress }
+      );
+      expect(l.amount.toNumber()).to.equal(1000);
+      expect(l.votes.toNumber()).to.equal(2000);
+      expect(l.unlockTimestamp.toNumber()).to.equal(1640000000 + 52 * 604800);
+      // Legacy lockers hold native tokens and counted every vote
+      const o = await ouroboros.account.ouroboros.fetch(ouroborosAddress);
+      expect(l.mint.equals(o.mint)).to.be.true;
+      expect(l.value.toNumber()).to.equal(1000);
+      expect(l.effectiveVotes.toNumber()).to.equal(2000);
+      expect(l.permanent).to.be.false;
+    });
+
+    it("Migrates a beneficiary", async () => {
+      const b = await migrate(
+        ouroboros.rpc.migrateBeneficiary,
+        ouroboros.account.beneficiary,
+        beneficiaryFixture,
+        { ouroboros: ouroborosAddress }
+      );
+      expect(b.votes.toNumber()).to.equal(500);
+      expect(b.weight).to.equal(1250);
+      expect(b.lastUpdate.toNumber()).to.equal(1640000000);
+      expect(b.registrySlot.toNumber()).to.equal(0);
+      expect(b.pendingVotes.toNumber()).to.equal(0);
+      // The votes of the beneficiary are counted as cast
+      const o = await ouroboros.account.ouroboros.fetch(ouroborosAddress);
+      expect(o.castVotes.toNumber()).to.equal(500);
+    });
+
+    it("Migrates an asset", async () => {
+      const a = await migrate(
+        ouroboros.rpc.migrateAsset,
+        ouroboros.account.asset,
+        assetFixture
+      );
+      expect(a.rewardHeight.toNumber()).to.equal(7);
+      expect(a.lastUpdate.toNumber()).to.equal(1640000000);
+      expect(a.lastSnapshotIndex.toNumber()).to.equal(3);
+      expect(a.bumps.account).to.equal(253);
+      expect(a.buybackAmount.toNumber()).to.equal(0);
+    });
+
+    it("Migrates a snapshot", async () => {
+      const s = await migrate(
+        ouroboros.rpc.migrateSnapshot,
+        ouroboros.account.snapshot,
+        snapshotFixture
+      );
+      expect(s.timestamp.toNumber()).to.equal(1640000000);
+      expect(s.index.toNumber()).to.equal(3);
+      expect(s.rewards.toNumber()).to.equal(99);
+      expect(s.votes.toNumber()).to.equal(4000);
+      expect(s.bump).to.equal(255);
+    });
+
+    it("Migrates a claimant", async () => {
+      const c = await migrate(
+        ouroboros.rpc.migrateClaimant,
+        ouroboros.account.claimant,
+        claimantFixture
+      );
+      expect(c.lastClaim.toNumber()).to.equal(1234);
+      expect(c.bump).to.equal(254);
+    });
+
+    it("Migrates a pair", async () => {
+      const p = await migrate(
+        pools.rpc.migratePair,
+        pools.account.pair,
+        pairFixture
+      );
+      expect(p.stable).to.be.true;
+      expect(p.bumps.feesB).to.equal(249);
+      // Prices start accumulating on the next update of the reserves
+      expect(p.lastUpdate.toNumber()).to.equal(0);
+      expect(p.priceACumulative.toNumber()).to.equal(0);
+    });
+
+    it("Migrates a gauge", async () => {
+      const g = await migrate(
+        gauges.rpc.migrateGauge,
+        gauges.account.gauge,
+        gaugeFixture
+      );
+      expect(g.cumulativeFees.toNumber()).to.equal(4200);
+      expect(g.bumps.accountB).to.equal(249);
+      // Reward streams start with the defaults
+      expect(g.rewardRate.toNumber()).to.equal(0);
+      expect(g.periodFinish.toNumber()).to.equal(0);
+      expect(g.totalStaked.toNumber()).to.equal(0);
+    });
+
+    it("Migrates a staker", async () => {
+      const s = await migrate(
+        gauges.rpc.migrateStaker,
+        gauges.account.staker,
+        stakerFixture
+      );
+      expect(s.lastCollect.toNumber()).to.equal(5);
+      expect(s.bump).to.equal(255);
+      expect(s.amount.toNumber()).to.equal(0);
+      expect(s.rewards.toNumber()).to.equal(0);
+    });
+  });
@@ -0,0 +1,343 @@
+import { expect } from "chai";
+import {
+  setProvider,
+  Provider,
+  Program,
+  workspace,
+  BN,
+  Wallet,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Pools } from "../../../target/types/pools";
+import {
+  airdropUsers,
+  assertFail,
+  findAssociatedAddress,
+  initializeConfig,
+  sleep,
+  deadline,
+} from "../../helpers";
+import {
+  Beneficiary,
+  Locker,
+  Ouroboros,
+  OuroborosPause,
+  PoolsPause,
+} from "../../../ts";
+
+export const testPause = (provider: Provider) =>
+  describe("Pause features with a guardian", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+
+    let creator: Keypair;
+    let guardian: Keypair;
+    let ouroboros: Ouroboros;
+    let guardianOuroboros: Ouroboros;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let config: PublicKey;
+    let tokenA: Token;
+    let tokenB: Token;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+    const liquidity = new BN(10 ** 9);
+
+    const pairAccounts = async () => {
+      const find = async (seed: string) =>
+        (
+          await PublicKey.findProgramAddress(
+            [
+              Buffer.from(seed),
+              tokenA.publicKey.toBuffer(),
+              tokenB.publicKey.toBuffer(),
+            ],
+            pools.programId
+          )
+        )[0];
+      const mint = await find("mint");
+
+      return {
+        pair: await find("pair"),
+        authority: await find("authority"),
+        pairMint: mint,
+        pairAccountA: await find("account_a"),
+        pairAccountB: await find("account_b"),
+        liquidityProvider: creator.publicKey,
+        liquidityProviderAccount: await findAssociatedAddress(
+          creator.publicKey,
+          mint
+        ),
+        mintA: tokenA.publicKey,
+        mintB: tokenB.publicKey,
+        accountA: await findAssociatedAddress(
+          creator.publicKey,
+          tokenA.publicKey
+        ),
+        accountB: await findAssociatedAddress(
+          creator.publicKey,
+          tokenB.publicKey
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      };
+    };
+
+    const addLiquidity = async () => {
+      const accounts = await pairAccounts();
+      const [burner] = await PublicKey.findProgramAddress(
+        [
+          Buffer.from("burner"),
+          tokenA.publicKey.toBuffer(),
+          tokenB.publicKey.toBuffer(),
+        ],
+        pools.programId
+      );
+
+      await pools.rpc.addLiquidity(liquidity, liquidity, new BN(0), new BN(0), deadline(), {
+        accounts: {
+          ...accounts,
+          config,
+          burnerAccount: burner,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        },
+        signers: [creator],
+      });
+    };
+
+    before(async () => {
+      creator = Keypair.generate();
+      guardian = Keypair.generate();
+      await airdropUsers([creator, guardian], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+      guardianOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(guardian), {}),
+        ouroborosId
+      );
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(1)
+      );
+      await locker.castVote(beneficiary, beneficiary);
+
+      config = await initializeConfig(pools, provider);
+      const mint = () =>
+        Token.createMint(
+          provider.connection,
+          creator,
+          creator.publicKey,
+          null,
+          9,
+          TOKEN_PROGRAM_ID
+        );
+      tokenA = await mint();
+      tokenB = await mint();
+      for (const token of [tokenA, tokenB]) {
+        const account = await token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        );
+        await token.mintTo(
+          account.address,
+          creator,
+          [],
+          liquidity.muln(2).toNumber()
+        );
+      }
+
+      const { pair, authority, pairMint, pairAccountA, pairAccountB } =
+        await pairAccounts();
+      const find = async (seed: string) =>
+        (
+          await PublicKey.findProgramAddress(
+            [
+              Buffer.from(seed),
+              tokenA.publicKey.toBuffer(),
+              tokenB.publicKey.toBuffer(),
+            ],
+            pools.programId
+          )
+        )[0];
+      await pools.rpc.createPair(false, {
+        accounts: {
+          pair,
+          authority,
+          pairMint,
+          mintA: tokenA.publicKey,
+          mintB: tokenB.publicKey,
+          pairAccountA,
+          pairAccountB,
+          feesAccountA: await find("fees_a"),
+          feesAccountB: await find("fees_b"),
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      await addLiquidity();
+    });
+
+    it("Sets the guardian", async () => {
+      await ouroboros.setGuardian(guardian.publicKey);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.guardian.toString()).to.equal(guardian.publicKey.toString());
+      expect(o.paused.toNumber()).to.equal(0);
+    });
+
+    it("Fails to set the guardian without being admin", async () => {
+      await assertFail(guardianOuroboros.setGuardian(guardian.publicKey));
+    });
+
+    it("Fails to pause without being guardian", async () => {
+      const outsider = Keypair.generate();
+      await airdropUsers([outsider], provider);
+      const outsiderOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(outsider), {}),
+        ouroborosId
+      );
+
+      await assertFail(
+        outsiderOuroboros.pause(OuroborosPause.locks),
+        "Unauthorized"
+      );
+    });
+
+    it("Fails to pause unknown features", async () => {
+      await assertFail(guardianOuroboros.pause(0), "InvalidFeatures");
+      await assertFail(guardianOuroboros.pause(1 << 7), "InvalidFeatures");
+    });
+
+    it("Pauses locks and claims", async () => {
+      await guardianOuroboros.pause(OuroborosPause.locks | OuroborosPause.claims);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.paused.toNumber()).to.equal(
+        OuroborosPause.locks | OuroborosPause.claims
+      );
+
+      await assertFail(
+        ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          depositAmount,
+          new BN(1)
+        ),
+        "Paused"
+      );
+      await assertFail(beneficiary.claimIncentives(), "Paused");
+    });
+
+    it("Withdraws a locker while paused", async () => {
+      await sleep(2000);
+      await locker.withdraw(beneficiary);
+
+      expect(
+        await provider.connection.getAccountInfo(locker.addresses.locker)
+      ).to.be.null;
+    });
+
+    it("Fails to unpause without being admin", async () => {
+      await assertFail(guardianOuroboros.unpause(OuroborosPause.locks));
+    });
+
+    it("Unpauses locks", async () => {
+      await ouroboros.unpause(OuroborosPause.locks);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.paused.toNumber()).to.equal(OuroborosPause.claims);
+
+      await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(1)
+      );
+    });
+
+    it("Pauses liquidity adds of the pools", async () => {
+      await assertFail(
+        pools.rpc.pause(new BN(PoolsPause.liquidityAdds), {
+          accounts: { config, guardian: creator.publicKey },
+          signers: [creator],
+        }),
+        "Unauthorized"
+      );
+
+      await pools.rpc.pause(new BN(PoolsPause.liquidityAdds), {
+        accounts: { config, guardian: provider.wallet.publicKey },
+      });
+
+      await assertFail(addLiquidity(), "Paused");
+    });
+
+    it("Removes liquidity while paused", async () => {
+      const accounts = await pairAccounts();
+      const lpToken = new Token(
+        provider.connection,
+        accounts.pairMint,
+        TOKEN_PROGRAM_ID,
+        creator
+      );
+      const lpAmount = (
+        await lpToken.getAccountInfo(accounts.liquidityProviderAccount)
+      ).amount;
+
+      await pools.rpc.removeLiquidity(lpAmount, deadline(), {
+        accounts,
+        signers: [creator],
+      });
+
+      expect(
+        (
+          await lpToken.getAccountInfo(accounts.liquidityProviderAccount)
+        ).amount.toNumber()
+      ).to.equal(0);
+    });
+
+    it("Unpauses liquidity adds of the pools", async () => {
+      await pools.rpc.unpause(new BN(PoolsPause.liquidityAdds), {
+        accounts: { config, admin: provider.wallet.publicKey },
+      });
+
+      const c = await pools.account.config.fetch(config);
+      expect(c.paused.toNumber()).to.equal(0);
+
+      await addLiquidity();
+    });
+  });
@@ -0,0 +1,103 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Locker, MAX_LOCK_PERIOD, Ouroboros } from "../../../ts";
+
+export const testPermanentLock = (provider: Provider) =>
+  describe("Keep lockers at the maximum locking period", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+    const permanentVotes = depositAmount
+      .mul(new BN(MAX_LOCK_PERIOD))
+      .mul(timeMultiplier)
+      .div(new BN(604800))
+      .div(new BN(10000));
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(604800)
+      );
+      await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+    });
+
+    it("Makes a locker permanent", async () => {
+      await locker.setPermanentLock(true, beneficiary);
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.permanent).to.be.true;
+      expect(l.votes.toString()).to.equal(permanentVotes.toString());
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.votes.toString()).to.equal(permanentVotes.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(depositAmount.toString());
+    });
+
+    it("Fails to set the same lock mode", async () => {
+      await assertFail(locker.setPermanentLock(true, beneficiary));
+    });
+
+    it("Starts the countdown from the maximum locking period", async () => {
+      const now = Math.round(Date.now() / 1000);
+      await locker.setPermanentLock(false, beneficiary);
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.permanent).to.be.false;
+      expect(l.votes.toString()).to.equal(permanentVotes.toString());
+      expect(l.unlockTimestamp.toNumber()).to.be.greaterThan(
+        now + MAX_LOCK_PERIOD - 60
+      );
+    });
+  });
@@ -39,6 +39,8 @@ export const testReceiveAsset = (provider: Provider) =>
         timeMultiplier
       );
       await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+      await ouroboros.proposeMint(ouroboros.addresses.mint);
+      await ouroboros.setAllowedMint(ouroboros.addresses.mint, true, new BN(0));
 
       const lockingPeriod = new BN(604800);
       locker = await ouroboros.createLocker(
@@ -0,0 +1,136 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Ouroboros, Locker } from "../../../ts";
+
+export const testRecipients = (provider: Provider) =>
+  describe("Split incentives of a beneficiary among recipients", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let user: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    let account: PublicKey;
+    let recipient: PublicKey;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    let startDate: BN;
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    // Incentives don't split evenly, leaving a rounding remainder
+    const depositAmount = new BN(10 ** 9 + 7);
+
+    const balance = async (address: PublicKey) =>
+      (await ouroboros.token.getAccountInfo(address)).amount;
+
+    before(async () => {
+      creator = Keypair.generate();
+      user = Keypair.generate();
+      await airdropUsers([creator, user], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      startDate = new BN(await clusterTime(provider));
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      account = (
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          creator.publicKey
+        )
+      ).address;
+      recipient = await ouroboros.token.createAssociatedTokenAccount(
+        Keypair.generate().publicKey
+      );
+      beneficiary = await ouroboros.createBeneficiary(account);
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(604800)
+      );
+      await locker.castVote(beneficiary, beneficiary2);
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+    });
+
+    it("Fails to set shares that don't add up to 100%", async () => {
+      await assertFail(
+        beneficiary.setRecipients([
+          { account: account, share: 6000 },
+          { account: recipient, share: 3000 },
+        ])
+      );
+    });
+
+    it("Fails to set recipients if not the owner", async () => {
+      const userOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(user), {}),
+        ouroborosId
+      );
+      await assertFail(
+        new Beneficiary(userOuroboros, account).setRecipients([
+          { account: recipient, share: 10000 },
+        ])
+      );
+    });
+
+    it("Splits incentives among recipients", async () => {
+      const table = await beneficiary.setRecipients([
+        { account: account, share: 6000 },
+        { account: recipient, share: 4000 },
+      ]);
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.recipients.toString()).to.equal(table.toString());
+
+      const accountBalance = await balance(account);
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+
+      const incentives = initialSupply
+        .sub(depositAmount)
+        .mul(expansionFactor)
+        .div(new BN(10000));
+      const share = incentives.mul(new BN(6000)).div(new BN(10000));
+      expect((await balance(account)).sub(accountBalance).toString()).to.equal(
+        share.toString()
+      );
+      // The last recipient receives the rounding remainder
+      expect((await balance(recipient)).toString()).to.equal(
+        incentives.sub(share).toString()
+      );
+      expect(
+        incentives.sub(share).gt(incentives.mul(new BN(4000)).div(new BN(10000)))
+      ).to.be.true;
+    });
+
+    it("Sends incentives back to the account", async () => {
+      await beneficiary.setRecipients();
+
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.recipients.toString()).to.equal(PublicKey.default.toString());
+    });
+  });
@@ -0,0 +1,108 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  airdropUsers,
+  assertFail,
+  findAssociatedAddress,
+  sleep,
+} from "../../helpers";
+import { Beneficiary, Locker, Ouroboros } from "../../../ts";
+
+export const testSoulboundLocker = (provider: Provider) =>
+  describe("Create lockers that can't be transferred", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let ouroboros: Ouroboros;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const depositAmount = new BN(10 ** 9);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+    });
+
+    it("Freezes the receipt of a soulbound locker", async () => {
+      locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        depositAmount,
+        new BN(1),
+        true
+      );
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.soulbound).to.be.true;
+
+      const receipt = new Token(
+        provider.connection,
+        locker.addresses.receipt,
+        TOKEN_PROGRAM_ID,
+        creator
+      );
+      const account = await receipt.getAccountInfo(
+        await findAssociatedAddress(creator.publicKey, locker.addresses.receipt)
+      );
+      expect(account.isFrozen).to.be.true;
+    });
+
+    it("Fails to transfer the receipt", async () => {
+      const receipt = new Token(
+        provider.connection,
+        locker.addresses.receipt,
+        TOKEN_PROGRAM_ID,
+        creator
+      );
+      const destination = await receipt.getOrCreateAssociatedAccountInfo(
+        Keypair.generate().publicKey
+      );
+      await assertFail(
+        receipt.transfer(
+          await findAssociatedAddress(
+            creator.publicKey,
+            locker.addresses.receipt
+          ),
+          destination.address,
+          creator,
+          [],
+          1
+        )
+      );
+    });
+
+    it("Fails to list the locker", async () => {
+      await assertFail(locker.list(ouroboros.addresses.mint, new BN(1)));
+    });
+
+    it("Withdraws the locker", async () => {
+      await sleep(2000);
+      await locker.withdraw(beneficiary);
+
+      expect(
+        await provider.connection.getAccountInfo(locker.addresses.locker)
+      ).to.be.null;
+    });
+  });
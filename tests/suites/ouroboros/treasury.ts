@@ -0,0 +1,330 @@
+import { expect } from "chai";
+import {
+  setProvider,
+  Provider,
+  Program,
+  workspace,
+  BN,
+  Wallet,
+} from "@project-serum/anchor";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Pools } from "../../../target/types/pools";
+import { Gauges } from "../../../target/types/gauges";
+import {
+  airdropUsers,
+  initializeConfig,
+  assertFail,
+  findAssociatedAddress,
+  deadline,
+} from "../../helpers";
+import { Ouroboros } from "../../../ts";
+
+export const testTreasury = (provider: Provider) =>
+  describe("Manage protocol-owned liquidity from the treasury", () => {
+    setProvider(provider);
+
+    const pools = workspace.Pools as Program<Pools>;
+    const gauges = workspace.Gauges as Program<Gauges>;
+
+    let creator: Keypair;
+    let user: Keypair;
+    let ouroboros: Ouroboros;
+    let treasury: PublicKey;
+    let tokenB: Token;
+    let pair: PublicKey;
+    let gauge: PublicKey;
+    let gaugeLiquidityAccount: PublicKey;
+    let lpToken: Token;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+    const liquidity = new BN(10 ** 9);
+
+    const find = (seed: string) =>
+      PublicKey.findProgramAddress(
+        [
+          Buffer.from(seed),
+          ouroboros.addresses.mint.toBuffer(),
+          tokenB.publicKey.toBuffer(),
+        ],
+        pools.programId
+      );
+    const findGaugeAddress = (seed: string) =>
+      PublicKey.findProgramAddress(
+        [
+          Buffer.from(seed),
+          ouroboros.addresses.mint.toBuffer(),
+          ouroboros.addresses.mint.toBuffer(),
+          tokenB.publicKey.toBuffer(),
+        ],
+        gauges.programId
+      );
+
+    const fundTreasury = async (token: Token, source: PublicKey) => {
+      const account = await findAssociatedAddress(treasury, token.publicKey);
+      await provider.send(
+        new Transaction().add(
+          Token.createAssociatedTokenAccountInstruction(
+            ASSOCIATED_TOKEN_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+            token.publicKey,
+            account,
+            treasury,
+            creator.publicKey
+          )
+        ),
+        [creator]
+      );
+      await token.transfer(source, account, creator, [], liquidity.toNumber());
+    };
+
+    before(async () => {
+      creator = Keypair.generate();
+      user = Keypair.generate();
+      await airdropUsers([creator, user], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      tokenB = await Token.createMint(
+        provider.connection,
+        creator,
+        creator.publicKey,
+        null,
+        9,
+        TOKEN_PROGRAM_ID
+      );
+      const creatorAccountB = (
+        await tokenB.getOrCreateAssociatedAccountInfo(creator.publicKey)
+      ).address;
+      await tokenB.mintTo(
+        creatorAccountB,
+        creator,
+        [],
+        initialSupply.toNumber()
+      );
+
+      const [pairAddress] = await find("pair");
+      const [authority] = await find("authority");
+      const [mint] = await find("mint");
+      const [accountA] = await find("account_a");
+      const [accountB] = await find("account_b");
+      const [feesA] = await find("fees_a");
+      const [feesB] = await find("fees_b");
+      const [burner] = await find("burner");
+      await pools.rpc.createPair(false, {
+        accounts: {
+          pair: pairAddress,
+          authority: authority,
+          pairMint: mint,
+          mintA: ouroboros.addresses.mint,
+          mintB: tokenB.publicKey,
+          pairAccountA: accountA,
+          pairAccountB: accountB,
+          feesAccountA: feesA,
+          feesAccountB: feesB,
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      pair = pairAddress;
+      lpToken = new Token(provider.connection, mint, TOKEN_PROGRAM_ID, creator);
+
+      // The treasury can't pay for the burner account of an empty pair
+      await pools.rpc.addLiquidity(
+        liquidity,
+        liquidity,
+        new BN(0),
+        new BN(0),
+        deadline(),
+        {
+          accounts: {
+            config: await initializeConfig(pools, provider),
+            pair: pair,
+            authority: authority,
+            pairMint: mint,
+            burnerAccount: burner,
+            pairAccountA: accountA,
+            pairAccountB: accountB,
+            liquidityProvider: creator.publicKey,
+            liquidityProviderAccount: await findAssociatedAddress(
+              creator.publicKey,
+              mint
+            ),
+            mintA: ouroboros.addresses.mint,
+            mintB: tokenB.publicKey,
+            accountA: await findAssociatedAddress(
+              creator.publicKey,
+              ouroboros.addresses.mint
+            ),
+            accountB: creatorAccountB,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          },
+          signers: [creator],
+        }
+      );
+
+      await initializeConfig(gauges, provider);
+      const [gaugeAddress] = await findGaugeAddress("gauge");
+      const [liquidityAccount] = await findGaugeAddress("liquidity_account");
+      await gauges.rpc.createGauge({
+        accounts: {
+          pair: pair,
+          gauge: gaugeAddress,
+          authority: (await findGaugeAddress("authority"))[0],
+          mintLiquidity: mint,
+          gaugeMint: (await findGaugeAddress("mint"))[0],
+          mintRewards: ouroboros.addresses.mint,
+          mintA: ouroboros.addresses.mint,
+          mintB: tokenB.publicKey,
+          liquidityAccount: liquidityAccount,
+          rewardsAccount: (await findGaugeAddress("rewards_account"))[0],
+          accountA: (await findGaugeAddress("account_a"))[0],
+          accountB: (await findGaugeAddress("account_b"))[0],
+          creator: creator.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: [creator],
+      });
+      gauge = gaugeAddress;
+      gaugeLiquidityAccount = liquidityAccount;
+    });
+
+    it("Creates the treasury", async () => {
+      treasury = await ouroboros.createTreasury();
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.treasury.toString()).to.equal(treasury.toString());
+
+      await fundTreasury(
+        ouroboros.token,
+        await findAssociatedAddress(creator.publicKey, ouroboros.addresses.mint)
+      );
+      await fundTreasury(
+        tokenB,
+        await findAssociatedAddress(creator.publicKey, tokenB.publicKey)
+      );
+    });
+
+    it("Fails to add liquidity if not admin", async () => {
+      const userOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(user), {}),
+        ouroborosId
+      );
+      await assertFail(
+        userOuroboros.treasuryAddLiquidity(pair, liquidity, liquidity)
+      );
+    });
+
+    it("Adds liquidity from the treasury", async () => {
+      await ouroboros.treasuryAddLiquidity(pair, liquidity, liquidity);
+
+      const lpAmount = (
+        await lpToken.getAccountInfo(
+          await findAssociatedAddress(treasury, lpToken.publicKey)
+        )
+      ).amount;
+      expect(lpAmount.gt(new BN(0))).to.be.true;
+      expect(
+        (
+          await ouroboros.token.getAccountInfo(
+            await findAssociatedAddress(treasury, ouroboros.addresses.mint)
+          )
+        ).amount.toString()
+      ).to.equal("0");
+    });
+
+    it("Fails to stake liquidity if not admin", async () => {
+      const userOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(user), {}),
+        ouroborosId
+      );
+      await assertFail(userOuroboros.treasuryStake(gauge, new BN(1)));
+    });
+
+    it("Stakes liquidity of the treasury in a gauge", async () => {
+      const lpAccount = await findAssociatedAddress(
+        treasury,
+        lpToken.publicKey
+      );
+      const lpAmount = (await lpToken.getAccountInfo(lpAccount)).amount;
+
+      await ouroboros.treasuryStake(gauge, lpAmount);
+
+      expect(
+        (await lpToken.getAccountInfo(lpAccount)).amount.toString()
+      ).to.equal("0");
+      expect(
+        (await lpToken.getAccountInfo(gaugeLiquidityAccount)).amount.toString()
+      ).to.equal(lpAmount.toString());
+    });
+
+    it("Unstakes liquidity of the treasury from a gauge", async () => {
+      const staked = (await lpToken.getAccountInfo(gaugeLiquidityAccount))
+        .amount;
+
+      await ouroboros.treasuryUnstake(gauge, staked);
+
+      expect(
+        (
+          await lpToken.getAccountInfo(
+            await findAssociatedAddress(treasury, lpToken.publicKey)
+          )
+        ).amount.toString()
+      ).to.equal(staked.toString());
+      expect(
+        (await lpToken.getAccountInfo(gaugeLiquidityAccount)).amount.toString()
+      ).to.equal("0");
+    });
+
+    it("Removes liquidity to the treasury", async () => {
+      const lpAccount = await findAssociatedAddress(
+        treasury,
+        lpToken.publicKey
+      );
+      const lpAmount = (await lpToken.getAccountInfo(lpAccount)).amount;
+
+      await ouroboros.treasuryRemoveLiquidity(pair, lpAmount);
+
+      expect(
+        (await lpToken.getAccountInfo(lpAccount)).amount.toString()
+      ).to.equal("0");
+      expect(
+        (
+          await tokenB.getAccountInfo(
+            await findAssociatedAddress(treasury, tokenB.publicKey)
+          )
+        ).amount.gt(new BN(0))
+      ).to.be.true;
+    });
+  });
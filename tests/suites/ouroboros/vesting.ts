@@ -0,0 +1,177 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import { airdropUsers, assertFail, sleep } from "../../helpers";
+import { Beneficiary, Locker, Ouroboros, Vesting } from "../../../ts";
+
+export const testVesting = (provider: Provider) =>
+  describe("Vest native tokens", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    let recipient: Keypair;
+    let ouroboros: Ouroboros;
+    let vesting: Vesting;
+    let recipientVesting: Vesting;
+    let locker: Locker;
+    let beneficiary: Beneficiary;
+    let beneficiary2: Beneficiary;
+    let ouroborosId = new BN(Math.round(Math.random() * 100000));
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(10000);
+    const timeMultiplier = new BN(10000);
+    const vestingAmount = new BN(10 ** 9);
+    const lockedAmount = new BN(5 * 10 ** 8);
+
+    before(async () => {
+      creator = Keypair.generate();
+      recipient = Keypair.generate();
+      await airdropUsers([creator, recipient], provider);
+
+      ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      beneficiary = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+      beneficiary2 = await ouroboros.createBeneficiary(
+        Keypair.generate().publicKey
+      );
+    });
+
+    it("Create a vesting", async () => {
+      const now = Math.round(Date.now() / 1000);
+      vesting = await ouroboros.createVesting(
+        Keypair.generate().publicKey,
+        recipient.publicKey,
+        vestingAmount,
+        {
+          startTimestamp: new BN(now - 100),
+          cliffTimestamp: new BN(now - 50),
+          endTimestamp: new BN(now + 3600),
+          revocable: true,
+        }
+      );
+      recipientVesting = new Vesting(
+        await Ouroboros.load(
+          new Provider(provider.connection, new Wallet(recipient), {}),
+          ouroborosId
+        ),
+        vesting.id
+      );
+
+      expect(
+        (
+          await ouroboros.token.getAccountInfo(vesting.addresses.account)
+        ).amount.toString()
+      ).to.equal(vestingAmount.toString());
+    });
+
+    it("Fails to withdraw someone else's vesting", async () => {
+      await assertFail(vesting.withdraw());
+    });
+
+    it("Withdraw vested tokens", async () => {
+      await recipientVesting.withdraw();
+
+      const balance = (
+        await ouroboros.token.getOrCreateAssociatedAccountInfo(
+          recipient.publicKey
+        )
+      ).amount;
+      expect(balance.gt(new BN(0))).to.be.true;
+      expect(balance.lt(vestingAmount.div(new BN(10)))).to.be.true;
+    });
+
+    it("Lock unvested tokens and vote", async () => {
+      locker = await recipientVesting.lock(
+        Keypair.generate().publicKey,
+        lockedAmount,
+        new BN(2)
+      );
+      await recipientVesting.castVote(locker, beneficiary, beneficiary2);
+
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.pendingVotes.toString()).to.equal(l.votes.toString());
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(lockedAmount.toString());
+    });
+
+    it("Fails to lock twice", async () => {
+      await assertFail(
+        recipientVesting.lock(Keypair.generate().publicKey, new BN(1), new BN(2))
+      );
+    });
+
+    it("Revoke unvested tokens, including locked ones", async () => {
+      const before = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+
+      await vesting.revoke();
+
+      const v = await ouroboros.program.account.vesting.fetch(
+        vesting.addresses.vesting
+      );
+      const l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      const remaining = (
+        await ouroboros.token.getAccountInfo(vesting.addresses.account)
+      ).amount;
+      expect(v.revoked).to.be.true;
+      expect(l.amount.lt(before.amount)).to.be.true;
+      expect(l.votes.lt(before.votes)).to.be.true;
+      // The recipient keeps exactly the vested tokens, liquid or locked
+      expect(v.totalAmount.toString()).to.equal(
+        remaining.add(l.amount).add(v.withdrawnAmount).toString()
+      );
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal(l.amount.toString());
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.pendingVotes.toString()).to.equal(l.votes.toString());
+
+      await assertFail(vesting.revoke());
+    });
+
+    it("Unlock the revoked vesting", async () => {
+      await sleep(3000);
+      await vesting.unlock(locker, beneficiary);
+
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.totalVotes.toString()).to.equal("0");
+
+      const v = await ouroboros.program.account.vesting.fetch(
+        vesting.addresses.vesting
+      );
+      const remaining = (
+        await ouroboros.token.getAccountInfo(vesting.addresses.account)
+      ).amount;
+      expect(v.totalAmount.toString()).to.equal(
+        remaining.add(v.withdrawnAmount).toString()
+      );
+    });
+  });
@@ -0,0 +1,210 @@
+import { expect } from "chai";
+import { setProvider, Provider, BN, Wallet } from "@project-serum/anchor";
+import { Keypair } from "@solana/web3.js";
+import {
+  airdropUsers,
+  assertFail,
+  clusterTime,
+  waitForEpochEnd,
+} from "../../helpers";
+import { Beneficiary, Locker, Ouroboros, VotingMode } from "../../../ts";
+
+export const testVotingModes = (provider: Provider) =>
+  describe("Count votes linearly, quadratically or capped", () => {
+    setProvider(provider);
+
+    let creator: Keypair;
+    const initialSupply = new BN(10 ** 10);
+    const rewardPeriod = new BN(5);
+    const startDate = new BN(10000000000);
+    const expansionFactor = new BN(500);
+    const timeMultiplier = new BN(10000);
+
+    before(async () => {
+      creator = Keypair.generate();
+      await airdropUsers([creator], provider);
+    });
+
+    /**
+     * Votes for three beneficiaries under a voting mode,
+     * and returns their effective votes and weights
+     */
+    const vote = async (
+      votingMode: VotingMode,
+      amounts: BN[],
+      voteCap?: BN
+    ) => {
+      const ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        new BN(Math.round(Math.random() * 100000)),
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      // The first epoch starts now so it can be ended
+      await ouroboros.initialize(
+        creator.publicKey,
+        initialSupply,
+        new BN(await clusterTime(provider))
+      );
+      await ouroboros.setVotingMode(votingMode, voteCap);
+
+      const beneficiaries: Beneficiary[] = [];
+      for (let i = 0; i < amounts.length; i++) {
+        const account = await ouroboros.token.createAssociatedTokenAccount(
+          Keypair.generate().publicKey
+        );
+        beneficiaries.push(await ouroboros.createBeneficiary(account));
+      }
+      for (let i = 0; i < amounts.length; i++) {
+        const locker = await ouroboros.createLocker(
+          Keypair.generate().publicKey,
+          amounts[i],
+          new BN(604800)
+        );
+        // The old beneficiary is ignored on the first vote
+        await locker.castVote(beneficiaries[i], beneficiaries[0]);
+      }
+
+      // Votes only count from the next epoch
+      await waitForEpochEnd(ouroboros);
+      await beneficiaries[0].claimIncentives();
+      await waitForEpochEnd(ouroboros);
+      for (const beneficiary of beneficiaries) {
+        await beneficiary.claimIncentives();
+      }
+
+      return Promise.all(
+        beneficiaries.map((b) =>
+          ouroboros.program.account.beneficiary.fetch(b.address)
+        )
+      );
+    };
+
+    const totalWeight = (beneficiaries: { weight: number }[]) =>
+      beneficiaries.reduce((total, b) => total + b.weight, 0);
+
+    it("Fails to set the voting mode if not admin", async () => {
+      const ouroborosId = new BN(Math.round(Math.random() * 100000));
+      const ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(creator.publicKey, initialSupply, startDate);
+
+      const user = Keypair.generate();
+      await airdropUsers([user], provider);
+      const userOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(user), {}),
+        ouroborosId
+      );
+      await assertFail(userOuroboros.setVotingMode({ quadratic: {} }));
+    });
+
+    it("Counts every vote in linear mode", async () => {
+      const amounts = [2 * 10 ** 9, 10 ** 9, 10 ** 9].map((a) => new BN(a));
+      const beneficiaries = await vote({ linear: {} }, amounts);
+
+      expect(beneficiaries.map((b) => b.votes.toString())).to.deep.equal(
+        amounts.map((a) => a.toString())
+      );
+      expect(beneficiaries.map((b) => b.weight)).to.deep.equal([
+        5000, 2500, 2500,
+      ]);
+      expect(totalWeight(beneficiaries)).to.equal(10000);
+    });
+
+    it("Counts the square root of votes in quadratic mode", async () => {
+      const amounts = [16 * 10 ** 8, 4 * 10 ** 8, 4 * 10 ** 8].map(
+        (a) => new BN(a)
+      );
+      const beneficiaries = await vote({ quadratic: {} }, amounts);
+
+      expect(beneficiaries.map((b) => b.votes.toString())).to.deep.equal([
+        "40000",
+        "20000",
+        "20000",
+      ]);
+      expect(beneficiaries.map((b) => b.weight)).to.deep.equal([
+        5000, 2500, 2500,
+      ]);
+      expect(totalWeight(beneficiaries)).to.equal(10000);
+    });
+
+    it("Caps the votes of each locker in capped mode", async () => {
+      const voteCap = new BN(10 ** 9);
+      const amounts = [3 * 10 ** 9, 5 * 10 ** 8, 5 * 10 ** 8].map(
+        (a) => new BN(a)
+      );
+      const beneficiaries = await vote({ capped: {} }, amounts, voteCap);
+
+      expect(beneficiaries.map((b) => b.votes.toString())).to.deep.equal([
+        voteCap.toString(),
+        amounts[1].toString(),
+        amounts[2].toString(),
+      ]);
+      expect(beneficiaries.map((b) => b.weight)).to.deep.equal([
+        5000, 2500, 2500,
+      ]);
+      expect(totalWeight(beneficiaries)).to.equal(10000);
+    });
+
+    it("Counts existing votes under a new voting mode", async () => {
+      const amount = new BN(16 * 10 ** 8);
+      const ouroborosId = new BN(Math.round(Math.random() * 100000));
+      const ouroboros = new Ouroboros(
+        new Provider(provider.connection, new Wallet(creator), {}),
+        ouroborosId,
+        rewardPeriod,
+        expansionFactor,
+        timeMultiplier
+      );
+      await ouroboros.initialize(
+        creator.publicKey,
+        initialSupply,
+        new BN(await clusterTime(provider))
+      );
+      const beneficiary = await ouroboros.createBeneficiary(
+        await ouroboros.token.createAssociatedTokenAccount(
+          Keypair.generate().publicKey
+        )
+      );
+      const locker = await ouroboros.createLocker(
+        Keypair.generate().publicKey,
+        amount,
+        new BN(604800)
+      );
+      await locker.castVote(beneficiary, beneficiary);
+      await waitForEpochEnd(ouroboros);
+      await beneficiary.claimIncentives();
+
+      await ouroboros.setVotingMode({ quadratic: {} });
+      let l = await ouroboros.program.account.locker.fetch(
+        locker.addresses.locker
+      );
+      expect(l.effectiveVotes.toString()).to.equal(amount.toString());
+
+      // Any wallet can count the votes again
+      const user = Keypair.generate();
+      await airdropUsers([user], provider);
+      const userOuroboros = await Ouroboros.load(
+        new Provider(provider.connection, new Wallet(user), {}),
+        ouroborosId
+      );
+      await new Locker(userOuroboros, locker.id).updateVotes(beneficiary);
+
+      l = await ouroboros.program.account.locker.fetch(locker.addresses.locker);
+      expect(l.effectiveVotes.toString()).to.equal("40000");
+      const b = await ouroboros.program.account.beneficiary.fetch(
+        beneficiary.address
+      );
+      expect(b.votes.toString()).to.equal("40000");
+      const o = await ouroboros.program.account.ouroboros.fetch(
+        ouroboros.addresses.ouroboros
+      );
+      expect(o.castVotes.toString()).to.equal("40000");
+    });
+  });
@@ -13,7 +13,7 @@ import {
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { Pools } from "../../../target/types/pools";
-import { airdropUsers } from "../../helpers";
+import { airdropUsers, initializeConfig, deadline } from "../../helpers";
 import {
   AccountInfo,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -71,7 +71,7 @@ export const testAddLiquidity = (provider: Provider) =>
         initialSupply.toNumber()
       );
 
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -79,7 +79,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -88,7 +88,7 @@ export const testAddLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -96,7 +96,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -105,7 +105,7 @@ export const testAddLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -114,7 +114,7 @@ export const testAddLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [feesAAddress, feesABump] = await PublicKey.findProgramAddress(
+      const [feesAAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_a"),
           tokenA.publicKey.toBuffer(),
@@ -122,7 +122,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [feesBAddress, feesBBump] = await PublicKey.findProgramAddress(
+      const [feesBAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_b"),
           tokenA.publicKey.toBuffer(),
@@ -131,19 +131,9 @@ export const testAddLiquidity = (provider: Provider) =>
         program.programId
       );
 
-      const bumps = {
-        pair: pairBump,
-        authority: authorityBump,
-        mint: mintBump,
-        accountA: accountABump,
-        accountB: accountBBump,
-        feesA: feesABump,
-        feesB: feesBBump
-      };
-
       const stable = true;
 
-      await program.rpc.createPair(bumps, stable, {
+      await program.rpc.createPair(stable, {
         accounts: {
           pair: pairAddress,
           authority: authorityAddress,
@@ -164,7 +154,7 @@ export const testAddLiquidity = (provider: Provider) =>
     });
 
     it("Adds liquidity to a pair", async () => {
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -172,7 +162,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -181,7 +171,7 @@ export const testAddLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -189,7 +179,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [burnerAddress, burnerBump] = await PublicKey.findProgramAddress(
+      const [burnerAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("burner"),
           tokenA.publicKey.toBuffer(),
@@ -197,7 +187,7 @@ export const testAddLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -206,7 +196,7 @@ export const testAddLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -229,13 +219,14 @@ export const testAddLiquidity = (provider: Provider) =>
       );
 
       await program.rpc.addLiquidity(
-        burnerBump,
         desiredA,
         desiredB,
         minA,
         minB,
+        deadline(),
         {
           accounts: {
+            config: await initializeConfig(program, provider),
             pair: pairAddress,
             authority: authorityAddress,
             pairMint: mintAddress,
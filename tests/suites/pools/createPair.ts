@@ -92,7 +92,7 @@ export const testCreatePair = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -100,7 +100,7 @@ export const testCreatePair = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -109,7 +109,7 @@ export const testCreatePair = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -118,7 +118,7 @@ export const testCreatePair = (provider: Provider) =>
           ],
           program.programId
         );
-      const [feesAAddress, feesABump] = await PublicKey.findProgramAddress(
+      const [feesAAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_a"),
           tokenA.publicKey.toBuffer(),
@@ -126,7 +126,7 @@ export const testCreatePair = (provider: Provider) =>
         ],
         program.programId
       );
-      const [feesBAddress, feesBBump] = await PublicKey.findProgramAddress(
+      const [feesBAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_b"),
           tokenA.publicKey.toBuffer(),
@@ -134,19 +134,9 @@ export const testCreatePair = (provider: Provider) =>
         ],
         program.programId
       );
-
-      const bumps = {
-        pair: pairBump,
-        authority: authorityBump,
-        mint: mintBump,
-        accountA: accountABump,
-        accountB: accountBBump,
-        feesA: feesABump,
-        feesB: feesBBump,
-      };
       const stable = true;
 
-      await program.rpc.createPair(bumps, stable, {
+      await program.rpc.createPair(stable, {
         accounts: {
           pair: pairAddress,
           authority: authorityAddress,
@@ -172,5 +162,8 @@ export const testCreatePair = (provider: Provider) =>
       expect(p.stable).to.equal(stable);
       expect(p.pairMint.toString()).to.equal(mintAddress.toString());
       expect(p.authority.toString()).to.equal(authorityAddress.toString());
+      // Bumps are derived on-chain
+      expect(p.bumps.pair).to.equal(pairBump);
+      expect(p.bumps.authority).to.equal(authorityBump);
     });
   });
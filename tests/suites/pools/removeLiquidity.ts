@@ -13,7 +13,7 @@ import {
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { Pools } from "../../../target/types/pools";
-import { airdropUsers } from "../../helpers";
+import { airdropUsers, initializeConfig, deadline } from "../../helpers";
 import {
   AccountInfo,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -75,7 +75,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         initialSupply.toNumber()
       );
 
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -83,7 +83,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -92,7 +92,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -100,7 +100,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [burnerAddress, burnerBump] = await PublicKey.findProgramAddress(
+      const [burnerAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("burner"),
           tokenA.publicKey.toBuffer(),
@@ -108,7 +108,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -117,7 +117,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -126,7 +126,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [feesAAddress, feesABump] = await PublicKey.findProgramAddress(
+      const [feesAAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_a"),
           tokenA.publicKey.toBuffer(),
@@ -134,7 +134,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [feesBAddress, feesBBump] = await PublicKey.findProgramAddress(
+      const [feesBAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_b"),
           tokenA.publicKey.toBuffer(),
@@ -143,19 +143,9 @@ export const testRemoveLiquidity = (provider: Provider) =>
         program.programId
       );
 
-      const bumps = {
-        pair: pairBump,
-        authority: authorityBump,
-        mint: mintBump,
-        accountA: accountABump,
-        accountB: accountBBump,
-        feesA: feesABump,
-        feesB: feesBBump,
-      };
-
       const stable = true;
 
-      await program.rpc.createPair(bumps, stable, {
+      await program.rpc.createPair(stable, {
         accounts: {
           pair: pairAddress,
           authority: authorityAddress,
@@ -189,13 +179,14 @@ export const testRemoveLiquidity = (provider: Provider) =>
       );
 
       await program.rpc.addLiquidity(
-        burnerBump,
         desiredA,
         desiredB,
         minA,
         minB,
+        deadline(),
         {
           accounts: {
+            config: await initializeConfig(program, provider),
             pair: pairAddress,
             authority: authorityAddress,
             pairMint: mintAddress,
@@ -219,7 +210,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
     });
 
     it("Removes liquidity", async () => {
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -227,7 +218,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -236,7 +227,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -244,7 +235,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -253,7 +244,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -270,7 +261,7 @@ export const testRemoveLiquidity = (provider: Provider) =>
         creator.publicKey
       );
 
-      await program.rpc.removeLiquidity(lpAccount.amount, {
+      await program.rpc.removeLiquidity(lpAccount.amount, deadline(), {
         accounts: {
           pair: pairAddress,
           authority: authorityAddress,
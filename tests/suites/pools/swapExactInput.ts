@@ -13,7 +13,12 @@ import {
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { Pools } from "../../../target/types/pools";
-import { airdropUsers } from "../../helpers";
+import {
+  airdropUsers,
+  assertFail,
+  initializeConfig,
+  deadline,
+} from "../../helpers";
 import {
   AccountInfo,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -75,7 +80,7 @@ export const testSwapExactInput = (provider: Provider) =>
         initialSupply.toNumber()
       );
 
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -83,7 +88,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -92,7 +97,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -100,7 +105,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [burnerAddress, burnerBump] = await PublicKey.findProgramAddress(
+      const [burnerAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("burner"),
           tokenA.publicKey.toBuffer(),
@@ -108,7 +113,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -117,7 +122,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -126,7 +131,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [feesAAddress, feesABump] = await PublicKey.findProgramAddress(
+      const [feesAAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_a"),
           tokenA.publicKey.toBuffer(),
@@ -134,7 +139,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [feesBAddress, feesBBump] = await PublicKey.findProgramAddress(
+      const [feesBAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_b"),
           tokenA.publicKey.toBuffer(),
@@ -143,19 +148,9 @@ export const testSwapExactInput = (provider: Provider) =>
         program.programId
       );
 
-      const bumps = {
-        pair: pairBump,
-        authority: authorityBump,
-        mint: mintBump,
-        accountA: accountABump,
-        accountB: accountBBump,
-        feesA: feesABump,
-        feesB: feesBBump,
-      };
-
       const stable = false;
 
-      await program.rpc.createPair(bumps, stable, {
+      await program.rpc.createPair(stable, {
         accounts: {
           pair: pairAddress,
           authority: authorityAddress,
@@ -189,13 +184,14 @@ export const testSwapExactInput = (provider: Provider) =>
       );
 
       await program.rpc.addLiquidity(
-        burnerBump,
         desiredA,
         desiredB,
         minA,
         minB,
+        deadline(),
         {
           accounts: {
+            config: await initializeConfig(program, provider),
             pair: pairAddress,
             authority: authorityAddress,
             pairMint: mintAddress,
@@ -219,7 +215,7 @@ export const testSwapExactInput = (provider: Provider) =>
     });
 
     it("Swap", async () => {
-      const [pairAddress, pairBump] = await PublicKey.findProgramAddress(
+      const [pairAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("pair"),
           tokenA.publicKey.toBuffer(),
@@ -227,7 +223,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [authorityAddress, authorityBump] =
+      const [authorityAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("authority"),
@@ -236,7 +232,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [mintAddress, mintBump] = await PublicKey.findProgramAddress(
+      const [mintAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("mint"),
           tokenA.publicKey.toBuffer(),
@@ -244,7 +240,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [accountAAddress, accountABump] =
+      const [accountAAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_a"),
@@ -253,7 +249,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [accountBAddress, accountBBump] =
+      const [accountBAddress] =
         await PublicKey.findProgramAddress(
           [
             Buffer.from("account_b"),
@@ -262,7 +258,7 @@ export const testSwapExactInput = (provider: Provider) =>
           ],
           program.programId
         );
-      const [feesAAddress, feesABump] = await PublicKey.findProgramAddress(
+      const [feesAAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_a"),
           tokenA.publicKey.toBuffer(),
@@ -270,7 +266,7 @@ export const testSwapExactInput = (provider: Provider) =>
         ],
         program.programId
       );
-      const [feesBAddress, feesBBump] = await PublicKey.findProgramAddress(
+      const [feesBAddress] = await PublicKey.findProgramAddress(
         [
           Buffer.from("fees_b"),
           tokenA.publicKey.toBuffer(),
@@ -286,8 +282,9 @@ export const testSwapExactInput = (provider: Provider) =>
       const feesBalanceABefore = (await tokenA.getAccountInfo(feesAAddress)).amount;
       const feesBalanceBBefore = (await tokenB.getAccountInfo(feesBAddress)).amount;
 
-      await program.rpc.swapExactInput(new BN(0), input, new BN(0), new BN(0), {
+      await program.rpc.swapExactInput(new BN(0), input, new BN(0), new BN(0), deadline(), {
         accounts: {
+          config: await initializeConfig(program, provider),
           pair: pairAddress,
           authority: authorityAddress,
           pairMint: mintAddress,
@@ -319,4 +316,61 @@ export const testSwapExactInput = (provider: Provider) =>
       expect(feesBalanceA.toString()).to.equal(feesBalanceABefore.toString());
       expect(feesBalanceB.toString()).to.equal(feesBalanceBBefore.add(input.div(new BN(1000))).toString());
     });
+
+    const findAddress = async (seed: string) =>
+      (
+        await PublicKey.findProgramAddress(
+          [
+            Buffer.from(seed),
+            tokenA.publicKey.toBuffer(),
+            tokenB.publicKey.toBuffer(),
+          ],
+          program.programId
+        )
+      )[0];
+
+    const swapAccounts = async () => ({
+      config: await initializeConfig(program, provider),
+      pair: await findAddress("pair"),
+      authority: await findAddress("authority"),
+      pairMint: await findAddress("mint"),
+      mintA: tokenA.publicKey,
+      mintB: tokenB.publicKey,
+      pairAccountA: await findAddress("account_a"),
+      pairAccountB: await findAddress("account_b"),
+      feesAccountA: await findAddress("fees_a"),
+      feesAccountB: await findAddress("fees_b"),
+      swapper: creator.publicKey,
+      accountA: accountA.address,
+      accountB: accountB.address,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("Fails to swap below the minimum output", async () => {
+      const input = new BN(10 ** 8);
+
+      await assertFail(
+        program.rpc.swapExactInput(new BN(0), input, input, new BN(0), deadline(), {
+          accounts: await swapAccounts(),
+          signers: [creator],
+        }),
+        "SlippageExceeded"
+      );
+    });
+
+    it("Fails to swap after the deadline", async () => {
+      const input = new BN(10 ** 8);
+      const expired = new BN(Math.round(Date.now() / 1000) - 600);
+
+      await assertFail(
+        program.rpc.swapExactInput(new BN(0), input, new BN(0), new BN(0), expired, {
+          accounts: await swapAccounts(),
+          signers: [creator],
+        }),
+        "DeadlineExpired"
+      );
+    });
   });
@@ -1,6 +1,16 @@
+import { workspace } from "@project-serum/anchor";
 import { findProgramAddressSync } from "@project-serum/anchor/dist/cjs/utils/pubkey";
-import { PublicKey } from "@solana/web3.js";
-import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { AssetAccount, AssetBumps, Ouroboros } from ".";
 
 /**
@@ -70,6 +80,9 @@ export class Asset {
         rewardHeight,
         lastUpdate,
         lastSnapshotIndex,
+        buybackAmount,
+        priceCumulative,
+        observationTimestamp,
         bumps,
       } = await this.ouroboros.program.account.asset.fetch(
         this.addresses.asset
@@ -80,9 +93,67 @@ export class Asset {
         rewardHeight,
         lastUpdate,
         lastSnapshotIndex,
+        buybackAmount,
+        priceCumulative,
+        observationTimestamp,
         bumps: (bumps as AssetBumps)
       };
       return this;
     } catch (err) {}
   }
+
+  /**
+   * Swaps the buyback share of the asset for native tokens and burns them.
+   * The first call only opens the TWAP window, then one call per period is allowed.
+   *
+   * @param pair - The pair of the native token and the asset
+   */
+  async buyback(pair: PublicKey) {
+    const p = await workspace.Pools.account.pair.fetch(pair);
+    const find = (seed: string) =>
+      findProgramAddressSync(
+        [Buffer.from(seed), p.mintA.toBuffer(), p.mintB.toBuffer()],
+        workspace.Pools.programId
+      )[0];
+    const associated = (mint: PublicKey) =>
+      Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        mint,
+        this.ouroboros.addresses.authority,
+        true
+      );
+
+    await this.ouroboros.program.rpc.buyback({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: this.ouroboros.addresses.mint,
+        asset: this.addresses.asset,
+        assetAuthority: this.addresses.authority,
+        assetMint: this.mint,
+        assetAccount: this.addresses.account,
+        poolsConfig: findProgramAddressSync(
+          [Buffer.from("config")],
+          workspace.Pools.programId
+        )[0],
+        pair: pair,
+        pairAuthority: find("authority"),
+        pairMint: p.pairMint,
+        pairAccountA: find("account_a"),
+        pairAccountB: find("account_b"),
+        feesAccountA: find("fees_a"),
+        feesAccountB: find("fees_b"),
+        nativeAccount: await associated(this.ouroboros.addresses.mint),
+        swapAccount: await associated(this.mint),
+        cranker: this.ouroboros.provider.wallet.publicKey,
+        poolsProgram: workspace.Pools.programId,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  }
 }
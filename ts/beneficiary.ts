@@ -1,12 +1,24 @@
 import { findProgramAddressSync } from "@project-serum/anchor/dist/cjs/utils/pubkey";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import {
   PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
   SYSVAR_CLOCK_PUBKEY
 } from "@solana/web3.js";
-import { Ouroboros } from ".";
+import { BN } from "@project-serum/anchor";
+import {
+  BeneficiaryMetadata,
+  GAUGES_PROGRAM_ID,
+  Locker,
+  Ouroboros,
+  Recipient,
+  REGISTRY_PAGE_SIZE,
+} from ".";
 
 /**
  * A helper class to interact with an instance of a beneficiary
@@ -49,10 +61,18 @@ export class Beneficiary {
   ) {
     const beneficiary = new Beneficiary(ouroboros, account);
 
-    await ouroboros.program.rpc.createBeneficiary(beneficiary.bump, account, {
+    const o = await ouroboros.program.account.ouroboros.fetch(
+      ouroboros.addresses.ouroboros
+    );
+    const [registry] = ouroboros.findRegistryPage(
+      o.numBeneficiaries.div(new BN(REGISTRY_PAGE_SIZE))
+    );
+
+    await ouroboros.program.rpc.createBeneficiary(account, {
       accounts: {
         ouroboros: ouroboros.addresses.ouroboros,
         beneficiary: beneficiary.address,
+        registry: registry,
         creator: ouroboros.provider.wallet.publicKey,
         rent: SYSVAR_RENT_PUBKEY,
         systemProgram: SystemProgram.programId,
@@ -62,14 +82,203 @@ export class Beneficiary {
     return beneficiary;
   }
 
+  /**
+   * Sets the metadata of the beneficiary.
+   * The wallet must be the creator of the beneficiary or the admin.
+   *
+   * @param metadata - The description of the beneficiary
+   */
+  async setMetadata(metadata: BeneficiaryMetadata) {
+    const [metadataAddress] = this.findMetadata();
+
+    await this.ouroboros.program.rpc.setBeneficiaryMetadata(
+      metadata.name,
+      metadata.uri,
+      metadata.category,
+      {
+        accounts: {
+          ouroboros: this.ouroboros.addresses.ouroboros,
+          beneficiary: this.address,
+          metadata: metadataAddress,
+          pair: metadata.pair || SystemProgram.programId,
+          gauge: metadata.gauge || SystemProgram.programId,
+          signer: this.ouroboros.provider.wallet.publicKey,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return metadataAddress;
+  }
+
+  findMetadata() {
+    return findProgramAddressSync(
+      [
+        Buffer.from("beneficiary_metadata"),
+        this.ouroboros.id.toBuffer("le", 8),
+        this.address.toBuffer(),
+      ],
+      this.ouroboros.program.programId
+    );
+  }
+
+  /**
+   * Splits the incentives of the beneficiary among multiple accounts.
+   * The wallet must own the beneficiary account.
+   *
+   * @param recipients - The accounts and their shares (BP), or nothing to send incentives to the account
+   */
+  async setRecipients(recipients: Recipient[] = []) {
+    const [recipientsAddress] = this.findRecipients();
+
+    await this.ouroboros.program.rpc.setBeneficiaryRecipients(recipients, {
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        beneficiary: this.address,
+        recipients: recipientsAddress,
+        account: this.account,
+        owner: this.ouroboros.provider.wallet.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return recipientsAddress;
+  }
+
+  findRecipients() {
+    return findProgramAddressSync(
+      [
+        Buffer.from("beneficiary_recipients"),
+        this.ouroboros.id.toBuffer("le", 8),
+        this.address.toBuffer(),
+      ],
+      this.ouroboros.program.programId
+    );
+  }
+
+  /**
+   * Claims incentives, split among the recipients of the beneficiary if any
+   */
   async claimIncentives() {
+    const b = await this.ouroboros.program.account.beneficiary.fetch(
+      this.address
+    );
+    const remainingAccounts = [];
+    if (!b.recipients.equals(PublicKey.default)) {
+      const table =
+        await this.ouroboros.program.account.beneficiaryRecipients.fetch(
+          b.recipients
+        );
+      remainingAccounts.push(
+        { pubkey: b.recipients, isWritable: false, isSigner: false },
+        ...(table.recipients as Recipient[]).map((r) => ({
+          pubkey: r.account,
+          isWritable: true,
+          isSigner: false,
+        }))
+      );
+    }
+
     await this.ouroboros.program.rpc.claimIncentives({
+      remainingAccounts,
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: this.ouroboros.addresses.mint,
+        beneficiary: this.address,
+        account: this.account,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  /**
+   * Claims incentives and streams them to the stakers of the bound gauge.
+   * The beneficiary account must be the rewards account of the gauge.
+   *
+   * @param gauge - The gauge bound to the beneficiary
+   * @param pair - The pair of the gauge
+   */
+  async claimGaugeIncentives(gauge: PublicKey, pair: PublicKey) {
+    await this.ouroboros.program.rpc.claimGaugeIncentives({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: this.ouroboros.addresses.mint,
+        beneficiary: this.address,
+        account: this.account,
+        pair: pair,
+        gauge: gauge,
+        gaugesProgram: GAUGES_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  /**
+   * Locks the future incentives of the beneficiary in a locker.
+   * The wallet must own the beneficiary account and the locker receipt.
+   *
+   * @param locker - The locker receiving incentives, or nothing to disable compounding
+   */
+  async setCompoundingLocker(locker?: Locker) {
+    const receiptAccount = locker
+      ? await Token.getAssociatedTokenAddress(
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+          TOKEN_PROGRAM_ID,
+          locker.addresses.receipt,
+          this.ouroboros.provider.wallet.publicKey
+        )
+      : this.account;
+    await this.ouroboros.program.rpc.setCompoundingLocker(
+      locker ? locker.addresses.locker : PublicKey.default,
+      {
+        accounts: {
+          ouroboros: this.ouroboros.addresses.ouroboros,
+          beneficiary: this.address,
+          account: this.account,
+          compoundingLocker: locker
+            ? locker.addresses.locker
+            : this.ouroboros.addresses.ouroboros,
+          receiptAccount,
+          owner: this.ouroboros.provider.wallet.publicKey,
+        },
+      }
+    );
+  }
+
+  /**
+   * Claims incentives and locks them in the compounding locker
+   *
+   * @param locker - The compounding locker
+   * @param lockerBeneficiary - The beneficiary the locker voted for
+   */
+  async claimCompoundedIncentives(
+    locker: Locker,
+    lockerBeneficiary: Beneficiary
+  ) {
+    const receiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      locker.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.claimCompoundedIncentives({
       accounts: {
         ouroboros: this.ouroboros.addresses.ouroboros,
         authority: this.ouroboros.addresses.authority,
         mint: this.ouroboros.addresses.mint,
         beneficiary: this.address,
         account: this.account,
+        locker: locker.addresses.locker,
+        lockerAccount: locker.addresses.account,
+        receiptAccount: receiptAccount,
+        lockerBeneficiary: lockerBeneficiary.address,
         tokenProgram: TOKEN_PROGRAM_ID,
         clock: SYSVAR_CLOCK_PUBKEY,
       },
@@ -0,0 +1,211 @@
+import { BN } from "@project-serum/anchor";
+import { findProgramAddressSync } from "@project-serum/anchor/dist/cjs/utils/pubkey";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  DistributorBumps,
+  GenesisAllocation,
+  Locker,
+  Ouroboros,
+} from ".";
+
+/**
+ * A helper class to interact with a merkle distributor of genesis lockers
+ */
+export class Distributor {
+  ouroboros: Ouroboros;
+  id: PublicKey;
+  addresses: {
+    distributor: PublicKey;
+    account: PublicKey;
+  };
+  bumps: DistributorBumps;
+
+  constructor(ouroboros: Ouroboros, id: PublicKey) {
+    this.ouroboros = ouroboros;
+    this.id = id;
+
+    const [distributorAddress, distributorBump] = findProgramAddressSync(
+      [
+        Buffer.from("distributor"),
+        ouroboros.id.toBuffer("le", 8),
+        id.toBuffer(),
+      ],
+      ouroboros.program.programId
+    );
+    const [accountAddress, accountBump] = findProgramAddressSync(
+      [
+        Buffer.from("distributor_account"),
+        ouroboros.id.toBuffer("le", 8),
+        id.toBuffer(),
+      ],
+      ouroboros.program.programId
+    );
+
+    this.addresses = {
+      distributor: distributorAddress,
+      account: accountAddress,
+    };
+    this.bumps = {
+      distributor: distributorBump,
+      account: accountBump,
+    };
+  }
+
+  /**
+   * Serializes an allocation as a leaf of the merkle tree
+   *
+   * @param index - The index of the leaf
+   * @param allocation - The allocation
+   * @returns The leaf
+   */
+  static leaf(index: number, allocation: GenesisAllocation) {
+    return Buffer.from([
+      ...new BN(index).toArray("le", 8),
+      ...allocation.recipient.toBuffer(),
+      ...allocation.amount.toArray("le", 8),
+      ...allocation.period.toArray("le", 8),
+    ]);
+  }
+
+  /**
+   * Creates a distributor funded by the wallet
+   *
+   * @param ouroboros - The parent Ouroboros
+   * @param id - The identifier of the distributor
+   * @param root - The root of the merkle tree
+   * @param numLeaves - The number of leaves of the tree
+   * @param amount - The amount of tokens to distribute
+   * @param clawback - The date after which unclaimed tokens can be clawed back
+   * @returns The distributor
+   */
+  static async create(
+    ouroboros: Ouroboros,
+    id: PublicKey,
+    root: number[],
+    numLeaves: BN,
+    amount: BN,
+    clawback: BN
+  ) {
+    const distributor = new Distributor(ouroboros, id);
+
+    const adminAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      ouroboros.addresses.mint,
+      ouroboros.provider.wallet.publicKey
+    );
+
+    await ouroboros.program.rpc.createDistributor(
+      id,
+      root,
+      numLeaves,
+      amount,
+      clawback,
+      {
+        accounts: {
+          ouroboros: ouroboros.addresses.ouroboros,
+          authority: ouroboros.addresses.authority,
+          mint: ouroboros.addresses.mint,
+          distributor: distributor.addresses.distributor,
+          distributorAccount: distributor.addresses.account,
+          admin: ouroboros.provider.wallet.publicKey,
+          adminAccount: adminAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return distributor;
+  }
+
+  /**
+   * Claims an allocation as a locker owned by the wallet
+   *
+   * @param lockerId - The identifier of the locker created
+   * @param index - The index of the allocation
+   * @param allocation - The allocation
+   * @param proof - The merkle proof of the allocation
+   * @returns The locker
+   */
+  async claim(
+    lockerId: PublicKey,
+    index: number,
+    allocation: GenesisAllocation,
+    proof: number[][]
+  ) {
+    const locker = new Locker(this.ouroboros, lockerId);
+
+    const receiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      locker.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.claimGenesisLocker(
+      lockerId,
+      new BN(index),
+      allocation.amount,
+      allocation.period,
+      proof,
+      {
+        accounts: {
+          ouroboros: this.ouroboros.addresses.ouroboros,
+          authority: this.ouroboros.addresses.authority,
+          mint: this.ouroboros.addresses.mint,
+          distributor: this.addresses.distributor,
+          distributorAccount: this.addresses.account,
+          locker: locker.addresses.locker,
+          lockerAccount: locker.addresses.account,
+          recipient: this.ouroboros.provider.wallet.publicKey,
+          receipt: locker.addresses.receipt,
+          receiptAccount: receiptAccount,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return locker;
+  }
+
+  /**
+   * Sends unclaimed tokens back to the admin
+   */
+  async clawback() {
+    const adminAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.ouroboros.addresses.mint,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.clawback({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        distributor: this.addresses.distributor,
+        distributorAccount: this.addresses.account,
+        admin: this.ouroboros.provider.wallet.publicKey,
+        adminAccount: adminAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+}
@@ -2,4 +2,6 @@ export * from "./types"
 export * from "./ouroboros"
 export * from "./locker"
 export * from "./beneficiary"
-export * from "./asset"
\ No newline at end of file
+export * from "./asset"
+export * from "./distributor"
+export * from "./vesting"
\ No newline at end of file
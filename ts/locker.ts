@@ -75,13 +75,15 @@ export class Locker {
    * @param creator - The wallet creating the locker
    * @param amount - The amount of tokens to lock
    * @param duration - The locking duration
+   * @param soulbound - Is the receipt frozen in the wallet of the creator
    * @returns - The locker
    */
   static async create(
     ouroboros: Ouroboros,
     id: PublicKey,
     amount: BN,
-    duration: BN
+    duration: BN,
+    soulbound: boolean = false
   ) {
     const locker = new Locker(ouroboros, id);
 
@@ -104,10 +106,10 @@ export class Locker {
     );
 
     await ouroboros.program.rpc.createLocker(
-      locker.bumps,
       id,
       amount,
       duration,
+      soulbound,
       {
         accounts: {
           ouroboros: ouroboros.addresses.ouroboros,
@@ -131,6 +133,71 @@ export class Locker {
     return locker;
   }
 
+  /**
+   * Creates a locker of LP tokens of the native pair.
+   * Votes are granted for the native value of the LP tokens.
+   *
+   * @param ouroboros - The parent Ouroboros
+   * @param id - The identifier of the locker
+   * @param pair - The native pair
+   * @param amount - The amount of LP tokens to lock
+   * @param duration - The locking duration
+   * @returns - The locker
+   */
+  static async createLp(
+    ouroboros: Ouroboros,
+    id: PublicKey,
+    pair: PublicKey,
+    amount: BN,
+    duration: BN
+  ) {
+    const locker = new Locker(ouroboros, id);
+
+    const { pairMint, pairAccountA, pairAccountB } =
+      await ouroboros.pairReserves(pair);
+    const creatorAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      pairMint,
+      ouroboros.provider.wallet.publicKey
+    );
+    const receiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      locker.addresses.receipt,
+      ouroboros.provider.wallet.publicKey
+    );
+
+    await ouroboros.program.rpc.createLpLocker(
+      id,
+      amount,
+      duration,
+      {
+        accounts: {
+          ouroboros: ouroboros.addresses.ouroboros,
+          authority: ouroboros.addresses.authority,
+          pair: pair,
+          pairMint: pairMint,
+          pairAccountA: pairAccountA,
+          pairAccountB: pairAccountB,
+          locker: locker.addresses.locker,
+          lockerAccount: locker.addresses.account,
+          creator: ouroboros.provider.wallet.publicKey,
+          creatorAccount: creatorAccount,
+          receipt: locker.addresses.receipt,
+          receiptAccount: receiptAccount,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return locker;
+  }
+
   /**
    * Sets the vote of a locker to a given beneficiary
    *
@@ -157,6 +224,293 @@ export class Locker {
     });
   }
 
+  /**
+   * Keeps the locker at the maximum locking period, or starts its countdown
+   *
+   * @param permanent - Is the locker permanent
+   * @param beneficiary - The beneficiary the locker voted for
+   */
+  async setPermanentLock(permanent: boolean, beneficiary?: Beneficiary) {
+    const receiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.setPermanentLock(permanent, {
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        locker: this.addresses.locker,
+        beneficiary: beneficiary
+          ? beneficiary.address
+          : SystemProgram.programId,
+        owner: this.ouroboros.provider.wallet.publicKey,
+        receiptAccount: receiptAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  /**
+   * Counts the votes of the locker under the current voting mode.
+   * Any wallet can update a locker.
+   *
+   * @param beneficiary - The beneficiary the locker voted for
+   */
+  async updateVotes(beneficiary: Beneficiary) {
+    await this.ouroboros.program.rpc.updateLockerVotes({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        locker: this.addresses.locker,
+        beneficiary: beneficiary.address,
+      },
+    });
+  }
+
+  /**
+   * Escrows the receipt of the locker and offers it for sale
+   *
+   * @param priceMint - The mint in which the price is paid
+   * @param price - The price of the locker
+   */
+  async list(priceMint: PublicKey, price: BN) {
+    const [listing] = this.findListing();
+    const [escrow] = this.findEscrow();
+    const sellerReceiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.listLocker(price, {
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        locker: this.addresses.locker,
+        listing: listing,
+        receipt: this.addresses.receipt,
+        escrowAccount: escrow,
+        priceMint: priceMint,
+        seller: this.ouroboros.provider.wallet.publicKey,
+        sellerReceiptAccount: sellerReceiptAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return listing;
+  }
+
+  /**
+   * Buys the listed locker, paying from the associated account of the wallet
+   *
+   * @param expectedPrice - The price the buyer agrees to pay
+   * @param priceMint - The mint the buyer agrees to pay in
+   */
+  async buy(expectedPrice: BN, priceMint: PublicKey) {
+    const [listingAddress] = this.findListing();
+    const [escrow] = this.findEscrow();
+    const listing = await this.ouroboros.program.account.listing.fetch(
+      listingAddress
+    );
+    const o = await this.ouroboros.program.account.ouroboros.fetch(
+      this.ouroboros.addresses.ouroboros
+    );
+
+    const priceToken = new Token(
+      this.ouroboros.provider.connection,
+      listing.priceMint,
+      TOKEN_PROGRAM_ID,
+      this.ouroboros.provider.wallet as any
+    );
+    const receipt = new Token(
+      this.ouroboros.provider.connection,
+      this.addresses.receipt,
+      TOKEN_PROGRAM_ID,
+      this.ouroboros.provider.wallet as any
+    );
+    const buyer = this.ouroboros.provider.wallet.publicKey;
+
+    await this.ouroboros.program.rpc.buyLocker(expectedPrice, priceMint, {
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        locker: this.addresses.locker,
+        listing: listingAddress,
+        escrowAccount: escrow,
+        seller: listing.seller,
+        sellerAccount: (
+          await priceToken.getOrCreateAssociatedAccountInfo(listing.seller)
+        ).address,
+        treasuryAccount: (
+          await priceToken.getOrCreateAssociatedAccountInfo(o.treasury)
+        ).address,
+        buyer: buyer,
+        buyerAccount: (await priceToken.getOrCreateAssociatedAccountInfo(buyer))
+          .address,
+        buyerReceiptAccount: (
+          await receipt.getOrCreateAssociatedAccountInfo(buyer)
+        ).address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    });
+  }
+
+  /**
+   * Returns the receipt of the listed locker to the seller
+   */
+  async cancelListing() {
+    const [listing] = this.findListing();
+    const [escrow] = this.findEscrow();
+    const sellerReceiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    await this.ouroboros.program.rpc.cancelListing({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        locker: this.addresses.locker,
+        listing: listing,
+        escrowAccount: escrow,
+        seller: this.ouroboros.provider.wallet.publicKey,
+        sellerReceiptAccount: sellerReceiptAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+    });
+  }
+
+  findListing() {
+    return findProgramAddressSync(
+      [
+        Buffer.from("listing"),
+        this.ouroboros.id.toBuffer("le", 8),
+        this.id.toBuffer(),
+      ],
+      this.ouroboros.program.programId
+    );
+  }
+
+  findEscrow() {
+    return findProgramAddressSync(
+      [
+        Buffer.from("listing_account"),
+        this.ouroboros.id.toBuffer("le", 8),
+        this.id.toBuffer(),
+      ],
+      this.ouroboros.program.programId
+    );
+  }
+
+  /**
+   * Withdraws the tokens of an expired locker
+   *
+   * @param beneficiary - The beneficiary the locker voted for
+   */
+  async withdraw(beneficiary: Beneficiary) {
+    const l = await this.ouroboros.program.account.locker.fetch(
+      this.addresses.locker
+    );
+    const { receiptAccount, ownerAccount } = await this.withdrawAccounts(
+      l.mint
+    );
+
+    await this.ouroboros.program.rpc.withdraw({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: l.mint,
+        locker: this.addresses.locker,
+        lockerAccount: this.addresses.account,
+        beneficiary: beneficiary.address,
+        receipt: this.addresses.receipt,
+        receiptAccount: receiptAccount,
+        owner: this.ouroboros.provider.wallet.publicKey,
+        ownerAccount: ownerAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  }
+
+  /**
+   * Withdraws the tokens of a locker before its unlock date.
+   * The penalty is distributed to the remaining lockers.
+   *
+   * @param beneficiary - The beneficiary the locker voted for
+   */
+  async earlyWithdraw(beneficiary: Beneficiary) {
+    const { receiptAccount, ownerAccount } = await this.withdrawAccounts();
+
+    // The native asset is created by the first penalty if it does not exist yet
+    const asset = new Asset(this.ouroboros, this.ouroboros.addresses.mint);
+    await asset.fetch();
+    const snapshotIndex = asset.asset ? asset.asset.lastSnapshotIndex : new BN(0);
+    const [snapshotAddress] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from("snapshot"),
+        this.ouroboros.id.toBuffer("le", 8),
+        this.ouroboros.addresses.mint.toBuffer(),
+        snapshotIndex.toBuffer("le", 8),
+      ],
+      this.ouroboros.program.programId
+    );
+
+    await this.ouroboros.program.rpc.earlyWithdraw(snapshotIndex, {
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: this.ouroboros.addresses.mint,
+        locker: this.addresses.locker,
+        lockerAccount: this.addresses.account,
+        beneficiary: beneficiary.address,
+        receipt: this.addresses.receipt,
+        receiptAccount: receiptAccount,
+        asset: asset.addresses.asset,
+        assetAuthority: asset.addresses.authority,
+        currentSnapshot: snapshotAddress,
+        assetAccount: asset.addresses.account,
+        owner: this.ouroboros.provider.wallet.publicKey,
+        ownerAccount: ownerAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return { asset, snapshot: snapshotAddress };
+  }
+
+  private async withdrawAccounts(
+    mint: PublicKey = this.ouroboros.addresses.mint
+  ) {
+    const receiptAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.addresses.receipt,
+      this.ouroboros.provider.wallet.publicKey
+    );
+    const ownerAccount = await Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      mint,
+      this.ouroboros.provider.wallet.publicKey
+    );
+
+    return { receiptAccount, ownerAccount };
+  }
+
   async collectFees(
     asset: Asset,
     previousSnapshotIndex: number,
@@ -182,7 +536,7 @@ export class Locker {
       ],
       this.ouroboros.program.programId
     );
-    const [claimantAddress, claimantBump] = await PublicKey.findProgramAddress(
+    const [claimantAddress] = await PublicKey.findProgramAddress(
       [
         Buffer.from("claimant"),
         this.ouroboros.id.toBuffer("le", 8),
@@ -223,7 +577,7 @@ export class Locker {
       await this.ouroboros.program.account.snapshot.fetch(snapshotAddress)
     );
 
-    await this.ouroboros.program.rpc.collectFees(claimantBump, {
+    await this.ouroboros.program.rpc.collectFees({
       accounts: {
         ouroboros: this.ouroboros.addresses.ouroboros,
         locker: this.addresses.locker,
@@ -7,7 +7,13 @@ import {
   SYSVAR_RENT_PUBKEY,
   SYSVAR_CLOCK_PUBKEY,
 } from "@solana/web3.js";
-import { OuroborosBumps } from "./types";
+import {
+  EmissionCaps,
+  GAUGES_PROGRAM_ID,
+  OuroborosBumps,
+  REGISTRY_PAGE_SIZE,
+  VotingMode,
+} from "./types";
 import { Ouroboros as OuroborosType } from "../target/types/ouroboros";
 import OuroborosIdl from "../target/idl/ouroboros.json";
 import {
@@ -16,7 +22,7 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { Locker } from "./locker";
-import { Asset, Beneficiary } from ".";
+import { Asset, Beneficiary, Distributor, Vesting, VestingSchedule } from ".";
 
 /**
  * A helper class to interact with an instance of Ouroboros
@@ -29,6 +35,8 @@ export class Ouroboros {
   period: BN;
   expansionFactor: BN;
   timeMultiplier: BN;
+  earlyWithdrawPenalty: BN;
+  caps: EmissionCaps;
   token: Token;
   addresses: {
     ouroboros: PublicKey;
@@ -44,13 +52,17 @@ export class Ouroboros {
    * @param period - The period of rewards and snapshot
    * @param expansionFactor - The factor of expansion of the circulating supply
    * @param multiplier - The weekly multiplier of voting power. 10000 = double every week
+   * @param earlyWithdrawPenalty - The maximum penalty (BP) applied to early withdrawals
+   * @param caps - The limits on the weight of beneficiaries
    */
   constructor(
     provider: Provider,
     id: BN,
     period: BN,
     expansionFactor: BN,
-    multiplier: BN
+    multiplier: BN,
+    earlyWithdrawPenalty: BN = new BN(0),
+    caps: EmissionCaps = { maxWeight: 10000, minWeight: 0, redistribute: false }
   ) {
     this.provider = provider;
     this.program = new Program<OuroborosType>(
@@ -62,6 +74,8 @@ export class Ouroboros {
     this.period = period;
     this.timeMultiplier = multiplier;
     this.expansionFactor = expansionFactor;
+    this.earlyWithdrawPenalty = earlyWithdrawPenalty;
+    this.caps = caps;
 
     const [ouroborosAddress, ouroborosBump] = findProgramAddressSync(
       [Buffer.from("ouroboros"), this.id.toBuffer("le", 8)],
@@ -125,13 +139,14 @@ export class Ouroboros {
     );
 
     await this.program.rpc.initializeOuroboros(
-      this.bumps,
       this.id,
       supply,
       this.period,
       start,
       this.expansionFactor,
       this.timeMultiplier,
+      this.earlyWithdrawPenalty,
+      this.caps,
       {
         accounts: {
           ouroboros: this.addresses.ouroboros,
@@ -150,6 +165,43 @@ export class Ouroboros {
     return this;
   }
 
+  /**
+   * Initializes an Ouroboros emitting an existing token.
+   * The wallet must be the mint authority, which is handed to the Ouroboros authority.
+   * Mints with a freeze authority are rejected.
+   *
+   * @param mint - The existing mint
+   * @param start - The date of the first period as a timestamp
+   * @returns The Ouroboros
+   */
+  async attach(mint: PublicKey, start: BN) {
+    await this.program.rpc.attachOuroboros(
+      this.id,
+      {
+        period: this.period,
+        startDate: start,
+        expansionFactor: this.expansionFactor,
+        timeMultiplier: this.timeMultiplier,
+        earlyWithdrawPenalty: this.earlyWithdrawPenalty,
+        caps: this.caps,
+      },
+      {
+        accounts: {
+          ouroboros: this.addresses.ouroboros,
+          authority: this.addresses.authority,
+          mint: mint,
+          mintAuthority: this.provider.wallet.publicKey,
+          creator: this.provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+    this.useMint(mint);
+
+    return this;
+  }
+
   static async create(
     provider: Provider,
     id: BN,
@@ -189,17 +241,51 @@ export class Ouroboros {
     );
     const account = await program.account.ouroboros.fetch(ouroborosAddress);
 
-    return new Ouroboros(
+    const ouroboros = new Ouroboros(
       provider,
       id,
       account.period,
       account.expansionFactor,
-      account.timeMultiplier
+      account.timeMultiplier,
+      account.earlyWithdrawPenalty,
+      account.caps as EmissionCaps
     );
+    ouroboros.useMint(account.mint);
+
+    return ouroboros;
   }
 
-  async createLocker(lockerId: PublicKey, amount: BN, duration: BN) {
-    return Locker.create(this, lockerId, amount, duration);
+  /**
+   * Points the helper to the native mint of an attached Ouroboros
+   *
+   * @param mint - The existing mint
+   */
+  useMint(mint: PublicKey) {
+    this.addresses.mint = mint;
+    this.token = new Token(
+      this.provider.connection,
+      mint,
+      TOKEN_PROGRAM_ID,
+      this.provider.wallet as any
+    );
+  }
+
+  async createLocker(
+    lockerId: PublicKey,
+    amount: BN,
+    duration: BN,
+    soulbound: boolean = false
+  ) {
+    return Locker.create(this, lockerId, amount, duration, soulbound);
+  }
+
+  async createLpLocker(
+    lockerId: PublicKey,
+    pair: PublicKey,
+    amount: BN,
+    duration: BN
+  ) {
+    return Locker.createLp(this, lockerId, pair, amount, duration);
   }
 
   /**
@@ -212,6 +298,549 @@ export class Ouroboros {
     return Beneficiary.create(this, account);
   }
 
+  /**
+   * Create a merkle distributor of genesis lockers funded by the wallet
+   *
+   * @param id - The identifier of the distributor
+   * @param root - The root of the merkle tree of allocations
+   * @param numLeaves - The number of allocations
+   * @param amount - The total amount of tokens allocated
+   * @param clawback - The date after which unclaimed tokens can be clawed back
+   * @returns - The distributor
+   */
+  async createDistributor(
+    id: PublicKey,
+    root: number[],
+    numLeaves: BN,
+    amount: BN,
+    clawback: BN
+  ) {
+    return Distributor.create(this, id, root, numLeaves, amount, clawback);
+  }
+
+  /**
+   * Create a vesting of native tokens funded by the wallet
+   *
+   * @param id - The identifier of the vesting
+   * @param recipient - The wallet receiving vested tokens
+   * @param amount - The amount of tokens vesting
+   * @param schedule - The release of tokens
+   * @returns - The vesting
+   */
+  async createVesting(
+    id: PublicKey,
+    recipient: PublicKey,
+    amount: BN,
+    schedule: VestingSchedule
+  ) {
+    return Vesting.create(this, id, recipient, amount, schedule);
+  }
+
+  /**
+   * Transfers the admin rights to another wallet
+   *
+   * @param admin - The new admin
+   */
+  async setAdmin(admin: PublicKey) {
+    await this.program.rpc.setAdmin(admin, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Sets the pair whose LP tokens can be locked.
+   * The wallet must be the admin.
+   *
+   * @param pair - A pair containing the native token
+   */
+  async setNativePair(pair: PublicKey) {
+    const { pairAccountA, pairAccountB } = await this.pairReserves(pair);
+
+    await this.program.rpc.setNativePair({
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        pair: pair,
+        pairAccountA: pairAccountA,
+        pairAccountB: pairAccountB,
+        admin: this.provider.wallet.publicKey,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  /**
+   * Finds the accounts holding the reserves of a pair
+   *
+   * @param pair - The address of the pair
+   * @returns - The LP mint and the accounts holding tokens A and B
+   */
+  async pairReserves(pair: PublicKey) {
+    const p = await workspace.Pools.account.pair.fetch(pair);
+    const find = (seed: string) =>
+      findProgramAddressSync(
+        [Buffer.from(seed), p.mintA.toBuffer(), p.mintB.toBuffer()],
+        workspace.Pools.programId
+      )[0];
+
+    return {
+      pairMint: p.pairMint as PublicKey,
+      pairAccountA: find("account_a"),
+      pairAccountB: find("account_b"),
+    };
+  }
+
+  /**
+   * Sets the fee taken on locker sales.
+   * The wallet must be the admin.
+   *
+   * @param listingFee - The fee in BP
+   * @param treasury - The wallet receiving fees
+   */
+  async setListingFee(listingFee: number, treasury: PublicKey) {
+    await this.program.rpc.setListingFee(listingFee, treasury, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Sets how the votes of lockers count towards beneficiaries.
+   * Lockers keep their effective votes until they vote again.
+   * The wallet must be the admin.
+   *
+   * @param votingMode - Linear, quadratic or capped votes
+   * @param voteCap - The maximum effective votes of a locker in capped mode
+   */
+  async setVotingMode(votingMode: VotingMode, voteCap: BN = new BN(0)) {
+    await this.program.rpc.setVotingMode(votingMode, voteCap, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Sets the share of received assets bought back.
+   * The wallet must be the admin.
+   *
+   * @param buybackShare - The share of received assets in BP
+   * @param buybackSlippage - The maximum deviation from the TWAP in BP
+   */
+  async setBuyback(buybackShare: number, buybackSlippage: number) {
+    await this.program.rpc.setBuyback(buybackShare, buybackSlippage, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Sets the share of distributed incentives minted to the keeper.
+   * The wallet must be the admin.
+   *
+   * @param keeperReward - The share of distributed incentives in BP
+   */
+  async setKeeperReward(keeperReward: number) {
+    await this.program.rpc.setKeeperReward(keeperReward, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Sets the wallet allowed to pause features.
+   * The wallet must be the admin.
+   *
+   * @param guardian - The new guardian
+   */
+  async setGuardian(guardian: PublicKey) {
+    await this.program.rpc.setGuardian(guardian, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Pauses features of the ouroboros.
+   * The wallet must be the guardian or the admin.
+   *
+   * @param features - The bitmask of `OuroborosPause` features
+   */
+  async pause(features: number) {
+    await this.program.rpc.pause(new BN(features), {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        guardian: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Resumes paused features of the ouroboros.
+   * The wallet must be the admin.
+   *
+   * @param features - The bitmask of `OuroborosPause` features
+   */
+  async unpause(features: number) {
+    await this.program.rpc.unpause(new BN(features), {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Creates the treasury, which then receives protocol fees.
+   * The wallet must be the admin.
+   *
+   * @returns The address of the treasury
+   */
+  async createTreasury() {
+    const [treasury] = this.findTreasury();
+
+    await this.program.rpc.createTreasury({
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        treasury: treasury,
+        admin: this.provider.wallet.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return treasury;
+  }
+
+  findTreasury() {
+    return findProgramAddressSync(
+      [Buffer.from("treasury"), this.id.toBuffer("le", 8)],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Provides liquidity to a pair from the associated accounts of the treasury.
+   * The wallet must be the admin and the pair must already hold liquidity.
+   *
+   * @param pair - The pair receiving liquidity
+   * @param desiredAmountA - The amount of token A to deposit
+   * @param desiredAmountB - The amount of token B to deposit
+   * @param minAmountA - The minimum amount of token A deposited
+   * @param minAmountB - The minimum amount of token B deposited
+   */
+  async treasuryAddLiquidity(
+    pair: PublicKey,
+    desiredAmountA: BN,
+    desiredAmountB: BN,
+    minAmountA: BN = new BN(0),
+    minAmountB: BN = new BN(0)
+  ) {
+    const p = await workspace.Pools.account.pair.fetch(pair);
+    const accounts = await this.treasuryLiquidityAccounts(pair);
+    const [burner] = findPairAddress("burner", p.mintA, p.mintB);
+
+    await this.program.rpc.treasuryAddLiquidity(
+      desiredAmountA,
+      desiredAmountB,
+      minAmountA,
+      minAmountB,
+      {
+        accounts: {
+          ...accounts,
+          poolsConfig: findProgramAddressSync(
+            [Buffer.from("config")],
+            workspace.Pools.programId
+          )[0],
+          burnerAccount: burner,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        },
+      }
+    );
+  }
+
+  /**
+   * Withdraws liquidity of the treasury from a pair.
+   * The wallet must be the admin.
+   *
+   * @param pair - The pair providing liquidity
+   * @param liquidity - The amount of LP tokens burned
+   */
+  async treasuryRemoveLiquidity(pair: PublicKey, liquidity: BN) {
+    await this.program.rpc.treasuryRemoveLiquidity(liquidity, {
+      accounts: await this.treasuryLiquidityAccounts(pair),
+    });
+  }
+
+  /**
+   * Stakes LP tokens of the treasury in a gauge.
+   * The wallet must be the admin, and pays for the staker account of the treasury.
+   *
+   * @param gauge - The gauge receiving LP tokens
+   * @param amount - The amount of LP tokens staked
+   */
+  async treasuryStake(gauge: PublicKey, amount: BN) {
+    const { authority, ...accounts } = await this.treasuryGaugeAccounts(gauge);
+    await this.program.rpc.treasuryStake(amount, {
+      accounts: {
+        ...accounts,
+        gaugesConfig: findProgramAddressSync(
+          [Buffer.from("config")],
+          GAUGES_PROGRAM_ID
+        )[0],
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  }
+
+  /**
+   * Withdraws LP tokens of the treasury staked in a gauge.
+   * The wallet must be the admin.
+   *
+   * @param gauge - The gauge holding LP tokens
+   * @param amount - The amount of LP tokens withdrawn
+   */
+  async treasuryUnstake(gauge: PublicKey, amount: BN) {
+    const { authority, ...accounts } = await this.treasuryGaugeAccounts(gauge);
+    await this.program.rpc.treasuryUnstake(amount, {
+      accounts: { ...accounts, gaugeAuthority: authority },
+    });
+  }
+
+  private async treasuryGaugeAccounts(gauge: PublicKey) {
+    const g = await workspace.Gauges.account.gauge.fetch(gauge);
+    const p = await workspace.Pools.account.pair.fetch(g.pair);
+    const [treasury] = this.findTreasury();
+    const find = (seed: string, ...extra: PublicKey[]) =>
+      findProgramAddressSync(
+        [
+          Buffer.from(seed),
+          g.mintRewards.toBuffer(),
+          p.mintA.toBuffer(),
+          p.mintB.toBuffer(),
+          ...extra.map((key) => key.toBuffer()),
+        ],
+        GAUGES_PROGRAM_ID
+      )[0];
+
+    return {
+      ouroboros: this.addresses.ouroboros,
+      treasury: treasury,
+      pair: g.pair,
+      gauge: gauge,
+      authority: find("authority"),
+      gaugeLiquidityAccount: find("liquidity_account"),
+      staker: find("staker", treasury),
+      treasuryLiquidityAccount: await Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        p.pairMint,
+        treasury,
+        true
+      ),
+      admin: this.provider.wallet.publicKey,
+      gaugesProgram: GAUGES_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    };
+  }
+
+  private async treasuryLiquidityAccounts(pair: PublicKey) {
+    const p = await workspace.Pools.account.pair.fetch(pair);
+    const [treasury] = this.findTreasury();
+    const associated = (mint: PublicKey) =>
+      Token.getAssociatedTokenAddress(
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        mint,
+        treasury,
+        true
+      );
+
+    return {
+      ouroboros: this.addresses.ouroboros,
+      treasury: treasury,
+      pair: pair,
+      pairAuthority: findPairAddress("authority", p.mintA, p.mintB)[0],
+      pairMint: p.pairMint,
+      mintA: p.mintA,
+      mintB: p.mintB,
+      pairAccountA: findPairAddress("account_a", p.mintA, p.mintB)[0],
+      pairAccountB: findPairAddress("account_b", p.mintA, p.mintB)[0],
+      treasuryLiquidityAccount: await associated(p.pairMint),
+      treasuryAccountA: await associated(p.mintA),
+      treasuryAccountB: await associated(p.mintB),
+      admin: this.provider.wallet.publicKey,
+      poolsProgram: workspace.Pools.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  /**
+   * Proposes a mint to be accepted as an asset
+   *
+   * @param mint - The mint of the asset
+   */
+  async proposeMint(mint: PublicKey) {
+    const [allowedMint] = this.findAllowedMint(mint);
+
+    await this.program.rpc.proposeMint({
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        allowedMint: allowedMint,
+        mint: mint,
+        proposer: this.provider.wallet.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    return allowedMint;
+  }
+
+  /**
+   * Approves or rejects a proposed mint. The wallet must be the admin.
+   *
+   * @param mint - The mint of the asset
+   * @param approved - Can the ouroboros receive this asset
+   * @param minAmount - The minimum amount accepted
+   */
+  async setAllowedMint(mint: PublicKey, approved: boolean, minAmount: BN) {
+    const [allowedMint] = this.findAllowedMint(mint);
+
+    await this.program.rpc.setAllowedMint(approved, minAmount, {
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        allowedMint: allowedMint,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  findRegistryPage(index: BN) {
+    return findProgramAddressSync(
+      [Buffer.from("registry"), this.id.toBuffer("le", 8), index.toBuffer("le", 8)],
+      this.program.programId
+    );
+  }
+
+  /**
+   * Lists every beneficiary using the registry
+   *
+   * @returns - The addresses of the beneficiaries, in creation order
+   */
+  async getBeneficiaries() {
+    const o = await this.program.account.ouroboros.fetch(this.addresses.ouroboros);
+    const pages = o.numBeneficiaries
+      .add(new BN(REGISTRY_PAGE_SIZE - 1))
+      .div(new BN(REGISTRY_PAGE_SIZE))
+      .toNumber();
+
+    const beneficiaries: PublicKey[] = [];
+    for (let i = 0; i < pages; i++) {
+      const [page] = this.findRegistryPage(new BN(i));
+      const registry = await this.program.account.beneficiaryRegistry.fetch(page);
+      beneficiaries.push(...(registry.beneficiaries as PublicKey[]));
+    }
+
+    return beneficiaries;
+  }
+
+  /**
+   * Adds a beneficiary created before the registry existed.
+   * The wallet must be the admin.
+   *
+   * @param beneficiary - The unregistered beneficiary
+   */
+  async registerBeneficiary(beneficiary: Beneficiary) {
+    const o = await this.program.account.ouroboros.fetch(this.addresses.ouroboros);
+    const [registry] = this.findRegistryPage(
+      o.numBeneficiaries.div(new BN(REGISTRY_PAGE_SIZE))
+    );
+
+    await this.program.rpc.registerBeneficiary({
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        beneficiary: beneficiary.address,
+        registry: registry,
+        admin: this.provider.wallet.publicKey,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  }
+
+  /**
+   * Declares every beneficiary registered, which enables the distribution crank.
+   * The wallet must be the admin.
+   */
+  async completeRegistry() {
+    await this.program.rpc.completeRegistry({
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        admin: this.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Mints the incentives of a page of beneficiaries.
+   * Beneficiaries must follow the registry order, from the distribution cursor.
+   *
+   * @param beneficiaries - The beneficiaries to distribute to
+   * @param keeperAccount - The account receiving the keeper reward
+   * @param page - The registry page listing the beneficiaries
+   */
+  async distribute(
+    beneficiaries: Beneficiary[],
+    keeperAccount: PublicKey,
+    page: BN = new BN(0)
+  ) {
+    const [registry] = this.findRegistryPage(page);
+    const remainingAccounts = [];
+    for (const b of beneficiaries) {
+      remainingAccounts.push(
+        { pubkey: b.address, isWritable: true, isSigner: false },
+        { pubkey: b.account, isWritable: true, isSigner: false }
+      );
+    }
+
+    await this.program.rpc.distribute({
+      remainingAccounts,
+      accounts: {
+        ouroboros: this.addresses.ouroboros,
+        authority: this.addresses.authority,
+        mint: this.addresses.mint,
+        registry: registry,
+        keeper: this.provider.wallet.publicKey,
+        keeperAccount: keeperAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  findAllowedMint(mint: PublicKey) {
+    return findProgramAddressSync(
+      [Buffer.from("allowed_mint"), this.id.toBuffer("le", 8), mint.toBuffer()],
+      this.program.programId
+    );
+  }
+
   /**
    * Send tokens to the ouroboros and notifies it
    * @param mint - The mint of the asset
@@ -231,7 +860,7 @@ export class Ouroboros {
 
     const timestampIndex = options.timestampIndex ? options.timestampIndex : new BN(0)
 
-    const [snapshotAddress, snapshotBump] = await PublicKey.findProgramAddress(
+    const [snapshotAddress] = await PublicKey.findProgramAddress(
       [
         Buffer.from("snapshot"),
         this.id.toBuffer("le", 8),
@@ -242,13 +871,12 @@ export class Ouroboros {
     );
 
     await this.program.rpc.receiveAsset(
-      asset.bumps,
-      snapshotBump,
       timestampIndex,
       amount,
       {
         accounts: {
           ouroboros: this.addresses.ouroboros,
+          allowedMint: this.findAllowedMint(mint)[0],
           asset: asset.addresses.asset,
           authority: asset.addresses.authority,
           currentSnapshot: snapshotAddress,
@@ -279,3 +907,9 @@ export class Ouroboros {
     return await asset.fetch()
   }
 }
+
+const findPairAddress = (seed: string, mintA: PublicKey, mintB: PublicKey) =>
+  findProgramAddressSync(
+    [Buffer.from(seed), mintA.toBuffer(), mintB.toBuffer()],
+    workspace.Pools.programId
+  );
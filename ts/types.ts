@@ -9,6 +9,22 @@ export type OuroborosBumps = {
     mint: number
 }
 
+export type EmissionCaps = {
+    maxWeight: number,
+    minWeight: number,
+    redistribute: boolean
+}
+
+export type VotingMode =
+    | { linear: {} }
+    | { quadratic: {} }
+    | { capped: {} }
+
+export type Recipient = {
+    account: PublicKey,
+    share: number,
+}
+
 export type LockerBumps = {
     locker: number,
     receipt: number,
@@ -37,6 +53,75 @@ export interface AssetAccount {
     /// Index of the last snapshot
     lastSnapshotIndex: BN;
 
+    /// The amount of the asset waiting to be swapped and burned
+    buybackAmount: BN;
+
+    /// The cumulative price of the asset in native tokens at the last buyback
+    priceCumulative: BN;
+
+    /// The time of the last buyback, starting the TWAP window of the next one
+    observationTimestamp: BN;
+
     /// The bump used to generate PDAs
     bumps: AssetBumps;
-}
\ No newline at end of file
+}
+export type DistributorBumps = {
+    distributor: number,
+    account: number
+}
+
+export type GenesisAllocation = {
+    recipient: PublicKey,
+    amount: BN,
+    period: BN
+}
+
+export type VestingBumps = {
+    vesting: number,
+    account: number
+}
+
+export type VestingSchedule = {
+    startTimestamp: BN,
+    cliffTimestamp: BN,
+    endTimestamp: BN,
+    revocable: boolean
+}
+
+export type ListingBumps = {
+    listing: number,
+    escrow: number
+}
+
+export const REGISTRY_PAGE_SIZE = 100;
+
+/// Features of an ouroboros that its guardian can pause
+export const OuroborosPause = {
+    locks: 1,
+    claims: 1 << 1,
+}
+
+/// Features of the pools that their guardian can pause
+export const PoolsPause = {
+    swaps: 1,
+    liquidityAdds: 1 << 1,
+}
+
+/// Features of the gauges that their guardian can pause
+export const GaugesPause = {
+    deposits: 1,
+}
+
+export const MAX_LOCK_PERIOD = 4 * 365 * 86400;
+
+export const GAUGES_PROGRAM_ID = new PublicKey(
+    "EVHqCRXSRufttxNFQTwD1mBvmnfBqhwBkfKxUSvAPr3m"
+);
+
+export type BeneficiaryMetadata = {
+    name: string,
+    uri: string,
+    category: string,
+    pair?: PublicKey,
+    gauge?: PublicKey
+}
@@ -0,0 +1,278 @@
+import { BN } from "@project-serum/anchor";
+import { findProgramAddressSync } from "@project-serum/anchor/dist/cjs/utils/pubkey";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  Token,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  Beneficiary,
+  Locker,
+  Ouroboros,
+  VestingBumps,
+  VestingSchedule,
+} from ".";
+
+/**
+ * A helper class to interact with a vesting of native tokens
+ */
+export class Vesting {
+  ouroboros: Ouroboros;
+  id: PublicKey;
+  addresses: {
+    vesting: PublicKey;
+    account: PublicKey;
+  };
+  bumps: VestingBumps;
+
+  constructor(ouroboros: Ouroboros, id: PublicKey) {
+    this.ouroboros = ouroboros;
+    this.id = id;
+
+    const [vestingAddress, vestingBump] = findProgramAddressSync(
+      [Buffer.from("vesting"), ouroboros.id.toBuffer("le", 8), id.toBuffer()],
+      ouroboros.program.programId
+    );
+    const [accountAddress, accountBump] = findProgramAddressSync(
+      [
+        Buffer.from("vesting_account"),
+        ouroboros.id.toBuffer("le", 8),
+        id.toBuffer(),
+      ],
+      ouroboros.program.programId
+    );
+
+    this.addresses = {
+      vesting: vestingAddress,
+      account: accountAddress,
+    };
+    this.bumps = {
+      vesting: vestingBump,
+      account: accountBump,
+    };
+  }
+
+  /**
+   * Creates a vesting funded by the wallet
+   *
+   * @param ouroboros - The parent Ouroboros
+   * @param id - The identifier of the vesting
+   * @param recipient - The wallet receiving vested tokens
+   * @param amount - The amount of tokens vesting
+   * @param schedule - The release of tokens
+   * @returns The vesting
+   */
+  static async create(
+    ouroboros: Ouroboros,
+    id: PublicKey,
+    recipient: PublicKey,
+    amount: BN,
+    schedule: VestingSchedule
+  ) {
+    const vesting = new Vesting(ouroboros, id);
+
+    await ouroboros.program.rpc.createVesting(
+      id,
+      recipient,
+      amount,
+      schedule,
+      {
+        accounts: {
+          ouroboros: ouroboros.addresses.ouroboros,
+          authority: ouroboros.addresses.authority,
+          mint: ouroboros.addresses.mint,
+          vesting: vesting.addresses.vesting,
+          vestingAccount: vesting.addresses.account,
+          admin: ouroboros.provider.wallet.publicKey,
+          adminAccount: await vesting.walletAccount(),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return vesting;
+  }
+
+  /**
+   * Withdraws vested tokens to the wallet, which must be the recipient
+   */
+  async withdraw() {
+    await this.ouroboros.program.rpc.withdrawVested({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        mint: this.ouroboros.addresses.mint,
+        vesting: this.addresses.vesting,
+        vestingAccount: this.addresses.account,
+        recipient: this.ouroboros.provider.wallet.publicKey,
+        recipientAccount: await this.walletAccount(),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  }
+
+  /**
+   * Sends unvested tokens back to the wallet, which must be the admin.
+   * Unvested tokens locked in the locker of the vesting are clawed back as well.
+   */
+  async revoke() {
+    const vesting = await this.ouroboros.program.account.vesting.fetch(
+      this.addresses.vesting
+    );
+    // Unused accounts are replaced by the vesting when nothing is locked
+    let locker = this.addresses.vesting;
+    let lockerAccount = this.addresses.vesting;
+    let lockerBeneficiary = this.addresses.vesting;
+    if (!vesting.locker.equals(PublicKey.default)) {
+      const l = await this.ouroboros.program.account.locker.fetch(
+        vesting.locker
+      );
+      locker = vesting.locker;
+      lockerAccount = new Locker(this.ouroboros, l.id).addresses.account;
+      if (!l.beneficiary.equals(PublicKey.default)) {
+        lockerBeneficiary = l.beneficiary;
+      }
+    }
+
+    await this.ouroboros.program.rpc.revokeVesting({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        vesting: this.addresses.vesting,
+        vestingAccount: this.addresses.account,
+        locker,
+        lockerAccount,
+        lockerBeneficiary,
+        admin: this.ouroboros.provider.wallet.publicKey,
+        adminAccount: await this.walletAccount(),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  /**
+   * Locks unvested tokens in a locker voting for the recipient
+   *
+   * @param lockerId - The identifier of the locker created
+   * @param amount - The amount of tokens to lock
+   * @param duration - The locking duration
+   * @returns The locker
+   */
+  async lock(lockerId: PublicKey, amount: BN, duration: BN) {
+    const locker = new Locker(this.ouroboros, lockerId);
+
+    await this.ouroboros.program.rpc.lockVesting(
+      lockerId,
+      amount,
+      duration,
+      {
+        accounts: {
+          ouroboros: this.ouroboros.addresses.ouroboros,
+          authority: this.ouroboros.addresses.authority,
+          mint: this.ouroboros.addresses.mint,
+          vesting: this.addresses.vesting,
+          vestingAccount: this.addresses.account,
+          locker: locker.addresses.locker,
+          lockerAccount: locker.addresses.account,
+          receipt: locker.addresses.receipt,
+          receiptAccount: this.receiptAccount(locker),
+          recipient: this.ouroboros.provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          clock: SYSVAR_CLOCK_PUBKEY,
+          rent: SYSVAR_RENT_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        },
+      }
+    );
+
+    return locker;
+  }
+
+  /**
+   * Sets the vote of the vesting locker to a given beneficiary
+   *
+   * @param locker - The locker of the vesting
+   * @param beneficiary - The beneficiary of incentives
+   * @param oldBeneficiary - The old beneficiary of incentives
+   */
+  async castVote(
+    locker: Locker,
+    beneficiary: Beneficiary,
+    oldBeneficiary: Beneficiary
+  ) {
+    await this.ouroboros.program.rpc.castVestingVote({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        beneficiary: beneficiary.address,
+        oldBeneficiary: oldBeneficiary.address,
+        vesting: this.addresses.vesting,
+        locker: locker.addresses.locker,
+        recipient: this.ouroboros.provider.wallet.publicKey,
+      },
+    });
+  }
+
+  /**
+   * Returns the tokens of the expired vesting locker to the vesting
+   *
+   * @param locker - The locker of the vesting
+   * @param beneficiary - The beneficiary the locker voted for
+   */
+  async unlock(locker: Locker, beneficiary: Beneficiary) {
+    const vesting = await this.ouroboros.program.account.vesting.fetch(
+      this.addresses.vesting
+    );
+
+    await this.ouroboros.program.rpc.unlockVesting({
+      accounts: {
+        ouroboros: this.ouroboros.addresses.ouroboros,
+        authority: this.ouroboros.addresses.authority,
+        vesting: this.addresses.vesting,
+        vestingAccount: this.addresses.account,
+        locker: locker.addresses.locker,
+        lockerAccount: locker.addresses.account,
+        beneficiary: beneficiary.address,
+        receipt: locker.addresses.receipt,
+        receiptAccount: this.receiptAccount(locker),
+        recipient: vesting.recipient,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      },
+    });
+  }
+
+  private receiptAccount(locker: Locker) {
+    const [receiptAccount] = findProgramAddressSync(
+      [
+        Buffer.from("vesting_receipt"),
+        this.ouroboros.id.toBuffer("le", 8),
+        locker.id.toBuffer(),
+      ],
+      this.ouroboros.program.programId
+    );
+
+    return receiptAccount;
+  }
+
+  private async walletAccount() {
+    return Token.getAssociatedTokenAddress(
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      TOKEN_PROGRAM_ID,
+      this.ouroboros.addresses.mint,
+      this.ouroboros.provider.wallet.publicKey
+    );
+  }
+}